@@ -0,0 +1,25 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  8K
+    /* stands for an external SDRAM usable only after its controller is configured */
+    SDRAM       : ORIGIN = 0x20003000, LENGTH =  1K
+}
+
+SECTIONS
+{
+    .sdram_data : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __ssdram_data = .;
+        *(.sdram_data .sdram_data.*);
+        . = ALIGN(4);
+        __esdram_data = .;
+    } > SDRAM AT>CONSTS
+    __sisdram_data = LOADADDR(.sdram_data);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
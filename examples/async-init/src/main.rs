@@ -0,0 +1,127 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use core::future::Future;
+use core::pin::pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use linker_sections::{section, InitStatus, SectionInitFsm};
+use {defmt_rtt as _, panic_probe as _};
+
+const SDRAM_INITIAL_VALUE: u32 = 0xCAFE_BABE;
+
+/// Words copied per executor tick, small enough not to delay the other tasks.
+const INIT_BUDGET_WORDS: usize = 16;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".sdram_data")]
+static mut SDRAM_ARRAY: [u32; 256] = [SDRAM_INITIAL_VALUE; 256];
+
+/// Set once the SDRAM section is initialized.
+static SDRAM_READY: AtomicBool = AtomicBool::new(false);
+
+/// Stands for clocks and memory controller configuration making the SDRAM usable
+fn configure_sdram() {
+    defmt::info!("SDRAM configured");
+}
+
+/// Returns control to the executor once, as `yield_now` of async executors does.
+async fn yield_now() {
+    let mut yielded = false;
+
+    core::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Initializes the SDRAM section a few words per tick.
+async fn sdram_init(mut sdram: SectionInitFsm<1>) {
+    loop {
+        #[allow(unsafe_code)]
+        // SAFETY: The SDRAM is configured and nothing accesses the section until it is ready
+        let status = unsafe { sdram.poll(INIT_BUDGET_WORDS) };
+
+        match status {
+            InitStatus::InProgress { done, total } => {
+                defmt::debug!("SDRAM {}/{} bytes", done, total)
+            }
+            InitStatus::Done => break,
+        }
+
+        // Let the other tasks run between the chunks
+        yield_now().await;
+    }
+
+    SDRAM_READY.store(true, Ordering::Release);
+}
+
+/// Stands for an early task running while the SDRAM is initialized, returns its ticks.
+async fn heartbeat() -> usize {
+    let mut ticks = 0;
+
+    while !SDRAM_READY.load(Ordering::Acquire) {
+        ticks += 1;
+        yield_now().await;
+    }
+
+    ticks
+}
+
+/// Polls both tasks round-robin until both complete, stands for an async executor.
+fn run(init: impl Future<Output = ()>, other: impl Future<Output = usize>) -> usize {
+    let mut cx = Context::from_waker(Waker::noop());
+    let (mut init, mut other) = (pin!(init), pin!(other));
+    let (mut init_done, mut ticks) = (false, None);
+
+    while !init_done || ticks.is_none() {
+        if !init_done {
+            init_done = init.as_mut().poll(&mut cx).is_ready();
+        }
+        if ticks.is_none() {
+            if let Poll::Ready(done) = other.as_mut().poll(&mut cx) {
+                ticks = Some(done);
+            }
+        }
+    }
+
+    ticks.unwrap_or_default()
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    configure_sdram();
+
+    // Nothing is copied until the task polls the state machine
+    let sdram = SectionInitFsm::new([section!(sdram_data)]);
+    let ticks = run(sdram_init(sdram), heartbeat());
+
+    #[allow(unsafe_code)]
+    // SAFETY: The section is initialized and only the main accesses the static
+    let array = unsafe { core::ptr::addr_of!(SDRAM_ARRAY).read() };
+
+    // Check whether the section got initialized while the other task kept running
+    defmt::assert_eq!(array, [SDRAM_INITIAL_VALUE; 256]);
+    defmt::assert!(ticks > 0);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,181 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use core::future::Future;
+use core::mem::MaybeUninit;
+use core::pin::pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+
+use linker_sections::nor::AsyncReadNorFlash;
+use linker_sections::{section, InitStatus, SectionInitFsm};
+use {defmt_rtt as _, panic_probe as _};
+
+const ASSETS_LEN: usize = 256;
+const ASSETS_VALUE: u32 = 0xCAFE_BABE;
+
+/// Offset of the assets in the NOR flash image, e.g. as written by the production programmer.
+const ASSETS_OFFSET: u32 = 0x100;
+
+/// Chunks read per executor tick, small enough not to hold the shared SPI bus for long.
+const INIT_BUDGET_READS: usize = 2;
+
+/// Size of the stand-in NOR flash.
+const NOR_CAPACITY: usize = 2048;
+
+/// Builds the NOR flash content holding the assets at their offset.
+const fn nor_image() -> [u8; NOR_CAPACITY] {
+    let mut image = [0xFF; NOR_CAPACITY];
+    let value = ASSETS_VALUE.to_le_bytes();
+
+    let mut index = 0;
+    while index < ASSETS_LEN * 4 {
+        image[ASSETS_OFFSET as usize + index] = value[index % 4];
+        index += 1;
+    }
+
+    image
+}
+
+/// Content of the stand-in NOR flash.
+static NOR_IMAGE: [u8; NOR_CAPACITY] = nor_image();
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".nor_assets")]
+static mut NOR_ASSETS: MaybeUninit<[u32; ASSETS_LEN]> = MaybeUninit::uninit();
+
+/// Set once the assets section is initialized.
+static ASSETS_READY: AtomicBool = AtomicBool::new(false);
+
+/// Returns control to the executor once, as `yield_now` of async executors does.
+async fn yield_now() {
+    let mut yielded = false;
+
+    core::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Stands for an async SPI NOR flash driver on a shared bus, e.g. implementing
+/// `embedded_storage_async` traits.
+struct SpiNor;
+
+/// Error of [`SpiNor`] reads.
+#[derive(Debug, defmt::Format)]
+enum SpiNorError {
+    OutOfBounds,
+}
+
+impl AsyncReadNorFlash for SpiNor {
+    type Error = SpiNorError;
+    const READ_SIZE: usize = 1;
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        // stands for waiting for the bus and the DMA transfer of a READ command
+        yield_now().await;
+
+        let start = offset as usize;
+        let data = NOR_IMAGE
+            .get(start..start + bytes.len())
+            .ok_or(SpiNorError::OutOfBounds)?;
+        bytes.copy_from_slice(data);
+
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        NOR_CAPACITY
+    }
+}
+
+/// Initializes the assets section a few chunks per tick, as an Embassy task would.
+async fn assets_init(mut spi_nor: SpiNor, mut assets: SectionInitFsm<1>) {
+    loop {
+        #[allow(unsafe_code)]
+        // SAFETY: Nothing accesses the section until it is ready
+        let status =
+            unsafe { assets.poll_nor_async(&mut spi_nor, &[ASSETS_OFFSET], INIT_BUDGET_READS) }
+                .await;
+
+        match status {
+            Ok(InitStatus::InProgress { done, total }) => {
+                defmt::debug!("assets {}/{} bytes", done, total)
+            }
+            Ok(InitStatus::Done) => break,
+            Err(error) => defmt::panic!("NOR assets read failed: {}", error),
+        }
+
+        // Let the other tasks use the bus between the chunks
+        yield_now().await;
+    }
+
+    ASSETS_READY.store(true, Ordering::Release);
+}
+
+/// Stands for an early task running while the assets are read, returns its ticks.
+async fn heartbeat() -> usize {
+    let mut ticks = 0;
+
+    while !ASSETS_READY.load(Ordering::Acquire) {
+        ticks += 1;
+        yield_now().await;
+    }
+
+    ticks
+}
+
+/// Polls both tasks round-robin until both complete, stands for an async executor.
+fn run(init: impl Future<Output = ()>, other: impl Future<Output = usize>) -> usize {
+    let mut cx = Context::from_waker(Waker::noop());
+    let (mut init, mut other) = (pin!(init), pin!(other));
+    let (mut init_done, mut ticks) = (false, None);
+
+    while !init_done || ticks.is_none() {
+        if !init_done {
+            init_done = init.as_mut().poll(&mut cx).is_ready();
+        }
+        if ticks.is_none() {
+            if let Poll::Ready(done) = other.as_mut().poll(&mut cx) {
+                ticks = Some(done);
+            }
+        }
+    }
+
+    ticks.unwrap_or_default()
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // Nothing is read until the task polls the state machine, the section has no load address
+    let assets = SectionInitFsm::new([section!(nor_assets(__s, __e))]);
+    let ticks = run(assets_init(SpiNor, assets), heartbeat());
+
+    #[allow(unsafe_code)]
+    // SAFETY: The section is initialized and only the main accesses the static
+    let array = unsafe { core::ptr::addr_of!(NOR_ASSETS).read().assume_init() };
+
+    // Check whether the assets got read while the other task kept running
+    defmt::assert_eq!(array, [ASSETS_VALUE; ASSETS_LEN]);
+    defmt::assert!(ticks > 0);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
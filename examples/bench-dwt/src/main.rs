@@ -0,0 +1,73 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE_A: u32 = 0xDEAD_BEEF;
+const INITIAL_VALUE_B: u32 = 0xCAFE_BABE;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data_a")]
+static mut STATIC_ARRAY_A: [u32; 256] = [INITIAL_VALUE_A; 256];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data_b")]
+static mut STATIC_ARRAY_B: [u32; 16] = [INITIAL_VALUE_B; 16];
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The cycles spent by each section are recorded by the `bench-dwt` feature
+    init_sections!(custom_data_a, custom_data_b);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // The records outlive the `.data` and `.bss` initialization done after the pre-init
+    let report = linker_sections::bench::report();
+    for (name, cycles) in report.entries() {
+        defmt::info!("{} initialized in {} cycles", name, cycles);
+    }
+
+    // Check both sections got recorded in the order initialized, the bigger one taking longer
+    let entries = report.entries();
+    defmt::assert_eq!(entries.len(), 2);
+    defmt::assert_eq!(entries[0].0, "custom_data_a");
+    defmt::assert_eq!(entries[1].0, "custom_data_b");
+    defmt::assert!(entries[0].1 > entries[1].1);
+
+    // The records are cleared once reported
+    defmt::assert!(linker_sections::bench::report().entries().is_empty());
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAYs got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_A).read(),
+            [INITIAL_VALUE_A; 256]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_B).read(),
+            [INITIAL_VALUE_B; 16]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,42 @@
+use std::{env, path::PathBuf};
+
+include!("src/table.rs");
+
+fn main() {
+    println!("cargo:rustc-link-arg=--nmagic");
+    println!("cargo:rustc-link-arg=-Tlink.x");
+    println!("cargo:rustc-link-arg=-Tdefmt.x");
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let crate_name = env!("CARGO_PKG_NAME");
+
+    let mut map_file_path = PathBuf::from(&manifest_dir);
+    map_file_path.push(crate_name);
+    println!("cargo:rustc-link-arg=-Map={}.map", map_file_path.display());
+
+    let linker_search_path = PathBuf::from(&manifest_dir);
+    println!("cargo:rustc-link-search={}", linker_search_path.display());
+
+    // both sections hold the same image, each checked by its own algorithm
+    let image: Vec<u8> = table().iter().flat_map(|word| word.to_le_bytes()).collect();
+    let crc = linker_sections::crc32(&image);
+    let fletcher = linker_sections::fletcher32(&image);
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let script = format!(
+        "SECTIONS
+{{
+    .checksums : ALIGN(4)
+    {{
+        __crctable_crc = .;
+        LONG({crc:#010x});
+        __fletchertable_fletcher = .;
+        LONG({fletcher:#010x});
+    }} > FLASH
+}} INSERT AFTER .table_fletcher;
+"
+    );
+    std::fs::write(out_dir.join("checksums.x"), script).unwrap();
+    println!("cargo:rustc-link-search={}", out_dir.display());
+    println!("cargo:rustc-link-arg=-Tchecksums.x");
+}
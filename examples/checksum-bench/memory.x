@@ -0,0 +1,33 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    TABLE_RAM   : ORIGIN = 0x20002000, LENGTH =  8K
+}
+
+SECTIONS
+{
+    .table_crc : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __stable_crc = .;
+        *(.table_crc .table_crc.*);
+        . = ALIGN(4);
+        __etable_crc = .;
+    } > TABLE_RAM AT>FLASH
+    __sitable_crc = LOADADDR(.table_crc);
+
+    .table_fletcher : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __stable_fletcher = .;
+        *(.table_fletcher .table_fletcher.*);
+        . = ALIGN(4);
+        __etable_fletcher = .;
+    } > TABLE_RAM AT>FLASH
+    __sitable_fletcher = LOADADDR(.table_fletcher);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,81 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+mod table;
+
+use linker_sections::init_sections;
+use table::{table, table_word, TABLE_WORDS};
+use {defmt_rtt as _, panic_probe as _};
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".table_crc")]
+static mut TABLE_CRC: [u32; TABLE_WORDS] = table();
+
+#[allow(unsafe_code)]
+// SAFETY: Same as above
+#[unsafe(link_section = ".table_fletcher")]
+static mut TABLE_FLETCHER: [u32; TABLE_WORDS] = table();
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The sections are copied the same, so the sections differ by the checksum only, which is
+    // recorded on its own too, by DWT on cores having it, by SysTick on Cortex-M0/M0+
+    init_sections!(table_crc crc32_verify, table_fletcher verify(fletcher32));
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    let report = linker_sections::bench::report();
+    for (name, cycles) in report.entries() {
+        defmt::info!(
+            "{} initialized in {} cycles, {} per byte",
+            name,
+            cycles,
+            cycles / (TABLE_WORDS * 4) as u32
+        );
+    }
+
+    // Check the Fletcher-32 took less, unless the core has no cycle counter
+    let checksum = |suffix: &str| {
+        report
+            .entries()
+            .iter()
+            .find(|(name, _)| name.ends_with(suffix))
+            .map(|&(_, cycles)| cycles)
+    };
+    if let (Some(crc_cycles), Some(fletcher_cycles)) = (checksum(":crc32"), checksum(":fletcher32"))
+    {
+        defmt::assert!(fletcher_cycles < crc_cycles);
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing those static mut variables
+    let tables = unsafe {
+        [
+            &*core::ptr::addr_of!(TABLE_CRC),
+            &*core::ptr::addr_of!(TABLE_FLETCHER),
+        ]
+    };
+
+    // Check whether both tables got copied
+    for table in tables {
+        for (index, &word) in table.iter().enumerate() {
+            defmt::assert_eq!(word, table_word(index));
+        }
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
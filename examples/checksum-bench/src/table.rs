@@ -0,0 +1,22 @@
+// Table image shared by the build script computing its checksums and the firmware.
+
+/// Table length in words, each section holds a copy of it.
+pub const TABLE_WORDS: usize = 1024;
+
+/// Returns the table word at `index`, scrambled so no checksum shortcut applies.
+pub const fn table_word(index: usize) -> u32 {
+    (index as u32).wrapping_mul(0x9E37_79B9).rotate_left(13) ^ 0x2545_F491
+}
+
+/// Returns the whole table.
+pub const fn table() -> [u32; TABLE_WORDS] {
+    let mut table = [0; TABLE_WORDS];
+
+    let mut index = 0;
+    while index < TABLE_WORDS {
+        table[index] = table_word(index);
+        index += 1;
+    }
+
+    table
+}
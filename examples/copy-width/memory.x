@@ -0,0 +1,35 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    /* e.g. SRAM on a 16-bit external bus */
+    EXT_RAM     : ORIGIN = 0x20002000, LENGTH =  1K
+    /* e.g. peripheral buffer accepting byte accesses only */
+    FIFO_RAM    : ORIGIN = 0x20002400, LENGTH =  1K
+}
+
+SECTIONS
+{
+    .ext_data : ALIGN(2)
+    {
+        . = ALIGN(2);
+        __sext_data = .;
+        *(.ext_data .ext_data.*);
+        . = ALIGN(2);
+        __eext_data = .;
+    } > EXT_RAM AT>CONSTS
+    __siext_data = LOADADDR(.ext_data);
+
+    .fifo_data :
+    {
+        __sfifo_data = .;
+        *(.fifo_data .fifo_data.*);
+        __efifo_data = .;
+    } > FIFO_RAM AT>CONSTS
+    __sififo_data = LOADADDR(.fifo_data);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,48 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+const EXT_VALUE: u16 = 0xBEEF;
+const FIFO_VALUE: [u8; 5] = *b"hello";
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".ext_data")]
+static mut EXT_ARRAY: [u16; 7] = [EXT_VALUE; 7];
+
+#[allow(unsafe_code)]
+// SAFETY: Same as above
+#[unsafe(link_section = ".fifo_data")]
+static mut FIFO_BYTES: [u8; 5] = FIFO_VALUE;
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // `.ext_data` is copied by 16-bit accesses, `.fifo_data` by byte accesses
+    init_sections!(ext_data: u16, fifo_data: u8);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing those static mut variables
+    unsafe {
+        // Check whether the arrays got initialized
+        defmt::assert_eq!(core::ptr::addr_of!(EXT_ARRAY).read(), [EXT_VALUE; 7]);
+        defmt::assert_eq!(core::ptr::addr_of!(FIFO_BYTES).read(), FIFO_VALUE);
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
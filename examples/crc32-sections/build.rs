@@ -0,0 +1,40 @@
+use std::{env, path::PathBuf};
+
+include!("src/table.rs");
+
+fn main() {
+    println!("cargo:rustc-link-arg=--nmagic");
+    println!("cargo:rustc-link-arg=-Tlink.x");
+    println!("cargo:rustc-link-arg=-Tdefmt.x");
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let crate_name = env!("CARGO_PKG_NAME");
+
+    let mut map_file_path = PathBuf::from(&manifest_dir);
+    map_file_path.push(crate_name);
+    println!("cargo:rustc-link-arg=-Map={}.map", map_file_path.display());
+
+    let linker_search_path = PathBuf::from(&manifest_dir);
+    println!("cargo:rustc-link-search={}", linker_search_path.display());
+
+    // the CRC of the section image is placed in flash by a generated linker script, a post-link
+    // tool could patch a word reserved by `memory.x` instead
+    let image: Vec<u8> = table().iter().flat_map(|word| word.to_le_bytes()).collect();
+    let crc = linker_sections::crc32(&image);
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let script = format!(
+        "SECTIONS
+{{
+    .config_table_crc : ALIGN(4)
+    {{
+        __crcconfig_table = .;
+        LONG({crc:#010x});
+    }} > FLASH
+}} INSERT AFTER .config_table;
+"
+    );
+    std::fs::write(out_dir.join("crc.x"), script).unwrap();
+    println!("cargo:rustc-link-search={}", out_dir.display());
+    println!("cargo:rustc-link-arg=-Tcrc.x");
+}
@@ -0,0 +1,23 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    TABLE_RAM   : ORIGIN = 0x20002000, LENGTH =  2K
+}
+
+SECTIONS
+{
+    .config_table : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sconfig_table = .;
+        *(.config_table .config_table.*);
+        . = ALIGN(4);
+        __econfig_table = .;
+    } > TABLE_RAM AT>FLASH
+    __siconfig_table = LOADADDR(.config_table);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,44 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+mod table;
+
+use linker_sections::init_sections;
+use table::{table, TABLE_WORDS};
+use {defmt_rtt as _, panic_probe as _};
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".config_table")]
+static mut CONFIG_TABLE: [u32; TABLE_WORDS] = table();
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The CRC32 of the copied table is compared with `__crcconfig_table` computed by the build
+    // script, a mismatch is reported by a breakpoint, with the table address in r1
+    init_sections!(config_table crc32_verify);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    let config_table = unsafe { core::ptr::addr_of!(CONFIG_TABLE).read_volatile() };
+
+    // Check whether the table got copied
+    defmt::assert_eq!(config_table, table());
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
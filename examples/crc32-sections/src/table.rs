@@ -0,0 +1,18 @@
+// Configuration table image shared by the build script computing its CRC and the firmware.
+
+/// Configuration table length in words.
+pub const TABLE_WORDS: usize = 64;
+
+/// Returns the configuration table, a record id in the upper half of each word and its
+/// calibration value in the lower one.
+pub const fn table() -> [u32; TABLE_WORDS] {
+    let mut table = [0; TABLE_WORDS];
+
+    let mut index = 0;
+    while index < TABLE_WORDS {
+        table[index] = (index as u32 + 1) << 16 | (index as u32 * 37 + 11) & 0xFFFF;
+        index += 1;
+    }
+
+    table
+}
@@ -0,0 +1,29 @@
+use std::{env, fs, path::PathBuf};
+
+// the same section declarations are used by the firmware to initialize the sections
+#[path = "src/sections.rs"]
+mod sections;
+
+fn main() {
+    println!("cargo:rustc-link-arg=--nmagic");
+    println!("cargo:rustc-link-arg=-Tlink.x");
+    println!("cargo:rustc-link-arg=-Tdefmt.x");
+    println!("cargo:rustc-link-arg=-Tsections.x");
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let crate_name = env!("CARGO_PKG_NAME");
+
+    let mut map_file_path = PathBuf::from(&manifest_dir);
+    map_file_path.push(crate_name);
+    println!("cargo:rustc-link-arg=-Map={}.map", map_file_path.display());
+
+    let linker_search_path = PathBuf::from(&manifest_dir);
+    println!("cargo:rustc-link-search={}", linker_search_path.display());
+
+    // generate linker script from the section declarations
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let sections = [sections::CUSTOM_DATA_A_LD, sections::CUSTOM_DATA_B_LD].concat();
+    fs::write(out_dir.join("sections.x"), sections).unwrap();
+    println!("cargo:rustc-link-search={}", out_dir.display());
+    println!("cargo:rerun-if-changed=src/sections.rs");
+}
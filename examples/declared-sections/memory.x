@@ -0,0 +1,14 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    CUSTOM_RAM1 : ORIGIN = 0x20002000, LENGTH =  1K
+    CUSTOM_RAM2 : ORIGIN = 0x20002400, LENGTH =  1K
+}
+
+/* Custom sections are generated from `src/sections.rs` by `build.rs` */
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,63 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+mod sections;
+
+use linker_sections::in_section;
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE_A: u32 = 0xDEAD_BEEF;
+const INITIAL_VALUE_B: u32 = 0xCAFE_BABE;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[in_section("custom_data_a")]
+static mut STATIC_ARRAY_A: [u32; 256] = [INITIAL_VALUE_A; 256];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[in_section("custom_data_b")]
+static mut STATIC_ARRAY_B: [u32; 256] = [INITIAL_VALUE_B; 256];
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // SAFETY: Nothing accesses the sections before pre-init
+    unsafe {
+        sections::init_custom_data_a();
+        sections::init_custom_data_b();
+    }
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAYs got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_A).read(),
+            [INITIAL_VALUE_A; 256]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_B).read(),
+            [INITIAL_VALUE_B; 256]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,16 @@
+use linker_sections::declare_section;
+
+declare_section! {
+    name: custom_data_a,
+    vma: CUSTOM_RAM1,
+    lma: CONSTS,
+    insert: after .uninit,
+}
+
+declare_section! {
+    name: custom_data_b,
+    vma: CUSTOM_RAM2,
+    lma: CONSTS,
+    align: 8,
+    insert: after .custom_data_a,
+}
@@ -0,0 +1,36 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    CUSTOM_RAM  : ORIGIN = 0x20002000, LENGTH =  1K
+    /* stands for an external SDRAM usable only after its controller is configured */
+    SDRAM       : ORIGIN = 0x20002400, LENGTH =  1K
+}
+
+SECTIONS
+{
+    .custom_data : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_data = .;
+        *(.custom_data .custom_data.*);
+        . = ALIGN(4);
+        __ecustom_data = .;
+    } > CUSTOM_RAM AT>CONSTS
+    __sicustom_data = LOADADDR(.custom_data);
+
+    .sdram_data : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __ssdram_data = .;
+        *(.sdram_data .sdram_data.*);
+        . = ALIGN(4);
+        __esdram_data = .;
+    } > SDRAM AT>CONSTS
+    __sisdram_data = LOADADDR(.sdram_data);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,88 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{defer_sections_init, init_sections, InitProgress};
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
+const SDRAM_INITIAL_VALUE: u32 = 0xCAFE_BABE;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut STATIC_ARRAY: [u32; 256] = [INITIAL_VALUE; 256];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".sdram_data")]
+static mut SDRAM_ARRAY: [u32; 256] = [SDRAM_INITIAL_VALUE; 256];
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // Internal memory is ready, so the section could be initialized right away
+    init_sections!(custom_data);
+}
+
+/// Copy granularity of the SDRAM initialization progress.
+const PROGRESS_CHUNK: usize = 256;
+
+/// Stands for a boot splash progress bar.
+fn show_progress(progress: InitProgress) {
+    defmt::info!(
+        "SDRAM init: section {} {}/{} bytes, overall {}/{} bytes",
+        progress.section,
+        progress.section_done,
+        progress.section_total,
+        progress.done,
+        progress.total
+    );
+}
+
+/// Stands for clocks and memory controller configuration making the SDRAM usable
+fn configure_sdram() {
+    defmt::info!("SDRAM configured");
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // Nothing is copied yet, the SDRAM is not configured
+    let sdram = defer_sections_init!(sdram_data);
+
+    configure_sdram();
+
+    #[allow(unsafe_code)]
+    // SAFETY: The SDRAM is configured and nothing accesses the section yet
+    unsafe {
+        sdram.run_with_progress(PROGRESS_CHUNK, show_progress);
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAYs got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY).read(),
+            [INITIAL_VALUE; 256]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(SDRAM_ARRAY).read(),
+            [SDRAM_INITIAL_VALUE; 256]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -6,6 +6,7 @@ MEMORY
     RAM         : ORIGIN = 0x20001000, LENGTH =  4K
     CUSTOM_RAM1 : ORIGIN = 0x20002000, LENGTH =  1K
     CUSTOM_RAM2 : ORIGIN = 0x20002400, LENGTH =  1K
+    CUSTOM_RAM3 : ORIGIN = 0x20002800, LENGTH =  1K
 }
 
 SECTIONS
@@ -23,12 +24,21 @@ SECTIONS
     .custom_data_b : ALIGN(4)
     {
         . = ALIGN(4);
-        __scustom_data_b = .;
+        __start_custom_data_b = .;
         *(.custom_data_b .custom_data_b.*);
         . = ALIGN(4);
-        __ecustom_data_b = .;
+        __stop_custom_data_b = .;
     } > CUSTOM_RAM2 AT>CONSTS
-    __sicustom_data_b = LOADADDR(.custom_data_b);
+    __load_custom_data_b = LOADADDR(.custom_data_b);
+
+    .custom_bss (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_bss = .;
+        *(.custom_bss .custom_bss.*);
+        . = ALIGN(4);
+        __ecustom_bss = .;
+    } > CUSTOM_RAM3
 } INSERT AFTER .uninit;
 
 _stack_start = ORIGIN(STACK) + LENGTH(STACK);
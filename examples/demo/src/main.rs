@@ -2,7 +2,8 @@
 #![no_main]
 #![deny(unsafe_code)]
 
-use linker_sections::init_sections;
+use core::mem::MaybeUninit;
+use linker_sections::{in_section, pre_init_sections, zero_sections};
 use {defmt_rtt as _, panic_probe as _};
 
 const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
@@ -12,7 +13,7 @@ const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
 // - Using static mut just to force compiler not to optimize it out in
 //   this simple example
 // - linker section gets initialized because of using `linker_sections`
-#[unsafe(link_section = ".custom_data_a")]
+#[in_section("custom_data_a")]
 static mut STATIC_ARRAY_A: u32 = INITIAL_VALUE;
 
 #[allow(unsafe_code)]
@@ -20,24 +21,43 @@ static mut STATIC_ARRAY_A: u32 = INITIAL_VALUE;
 // - Using static mut just to force compiler not to optimize it out in
 //   this simple example
 // - linker section gets initialized because of using `linker_sections`
-#[unsafe(link_section = ".custom_data_b")]
+#[in_section("custom_data_b")]
 static mut STATIC_ARRAY_B: [u32; 256] = [INITIAL_VALUE; 256];
 
-#[cortex_m_rt::pre_init]
-unsafe fn pre_init() {
-    init_sections!(custom_data_a, custom_data_b);
-}
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section is NOLOAD and gets zeroed because of using `linker_sections`
+#[in_section("custom_bss")]
+static mut STATIC_ARRAY_C: MaybeUninit<[u32; 256]> = MaybeUninit::uninit();
+
+// `custom_data_a` uses default `__s`, `__e` and `__si` symbol prefixes, while `custom_data_b`
+// uses custom ones
+pre_init_sections!(custom_data_a, custom_data_b(__start_, __stop_, __load_));
 
 #[cortex_m_rt::entry]
 fn main() -> ! {
     defmt::info!("main started");
 
+    // NOLOAD section is zeroed before its first use
+    zero_sections!(custom_bss);
+
     #[allow(unsafe_code)]
     // SAFETY: This is the only place accessing that static mut variable
     unsafe {
         // Check whether ARRAYs got initialized
-        defmt::assert_eq!(STATIC_ARRAY_A, INITIAL_VALUE);
-        defmt::assert_eq!(STATIC_ARRAY_B, [INITIAL_VALUE; 256]);
+        defmt::assert_eq!(core::ptr::addr_of!(STATIC_ARRAY_A).read(), INITIAL_VALUE);
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_B).read(),
+            [INITIAL_VALUE; 256]
+        );
+
+        // Check whether ARRAY got zeroed
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_C).read().assume_init(),
+            [0; 256]
+        );
     }
 
     // We have not paniced on assert
@@ -0,0 +1,35 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 34K
+    CONSTS      : ORIGIN = 0x08008800, LENGTH = 14K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH = 12K
+    /* stands for an external SDRAM usable only once the clocks are configured */
+    SDRAM       : ORIGIN = 0x20004000, LENGTH = 16K
+}
+
+SECTIONS
+{
+    .sdram_data : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __ssdram_data = .;
+        *(.sdram_data .sdram_data.*);
+        . = ALIGN(4);
+        __esdram_data = .;
+    } > SDRAM AT>CONSTS
+    __sisdram_data = LOADADDR(.sdram_data);
+
+    .sdram_tables : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __ssdram_tables = .;
+        *(.sdram_tables .sdram_tables.*);
+        . = ALIGN(4);
+        __esdram_tables = .;
+    } > SDRAM AT>CONSTS
+    __sisdram_tables = LOADADDR(.sdram_tables);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
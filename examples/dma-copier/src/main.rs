@@ -0,0 +1,166 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{defer_sections_init, init_sections_with_copier, SectionCopier};
+use {defmt_rtt as _, panic_probe as _};
+
+const SDRAM_VALUE: u32 = 0xA5A5_5A5A;
+const SDRAM_WORDS: usize = 2048;
+const TABLE: [u32; 8] = [1, 2, 3, 5, 8, 13, 21, 34];
+
+/// RCC AHB1 peripheral clock enable register of STM32F4.
+const RCC_AHB1ENR: *mut u32 = 0x4002_3830 as *mut u32;
+
+/// DMA2 clock enable bit.
+const RCC_AHB1ENR_DMA2EN: u32 = 1 << 22;
+
+/// DMA2 of STM32F4, the only one capable of memory-to-memory transfers, stream 0 is used.
+const DMA2: usize = 0x4002_6400;
+const DMA2_LISR: *const u32 = DMA2 as *const u32;
+const DMA2_LIFCR: *mut u32 = (DMA2 + 0x08) as *mut u32;
+const DMA2_S0CR: *mut u32 = (DMA2 + 0x10) as *mut u32;
+const DMA2_S0NDTR: *mut u32 = (DMA2 + 0x14) as *mut u32;
+const DMA2_S0PAR: *mut u32 = (DMA2 + 0x18) as *mut u32;
+const DMA2_S0M0AR: *mut u32 = (DMA2 + 0x1C) as *mut u32;
+const DMA2_S0FCR: *mut u32 = (DMA2 + 0x24) as *mut u32;
+
+/// Stream 0 transfer complete and transfer error flags.
+const DMA_LISR_TCIF0: u32 = 1 << 5;
+const DMA_LISR_TEIF0: u32 = 1 << 3;
+
+/// All the stream 0 flags.
+const DMA_LIFCR_STREAM0: u32 = 0b11_1101;
+
+/// Word sized data, incremented source (peripheral port) and destination (memory port) addresses
+/// and memory-to-memory direction.
+const DMA_SXCR_M2M_WORDS: u32 = (0b10 << 13) | (0b10 << 11) | (1 << 10) | (1 << 9) | (0b10 << 6);
+
+/// Stream enable bit.
+const DMA_SXCR_EN: u32 = 1;
+
+/// FIFO enabled, as required by memory-to-memory transfers, with full threshold.
+const DMA_SXFCR_FIFO_FULL: u32 = (1 << 2) | 0b11;
+
+/// Maximum number of data items of a single transfer.
+const DMA_MAX_ITEMS: usize = 0xFFFF;
+
+/// Section copier by DMA2 stream 0 memory-to-memory transfers.
+///
+/// The copy returns once the last transfer is started, so [`Dma2Copier::wait`] shall be called
+/// before the sections are accessed.
+struct Dma2Copier {
+    busy: bool,
+}
+
+impl Dma2Copier {
+    fn new() -> Self {
+        #[allow(unsafe_code)]
+        // SAFETY: Nothing else accesses the RCC in this example
+        unsafe {
+            RCC_AHB1ENR.write_volatile(RCC_AHB1ENR.read_volatile() | RCC_AHB1ENR_DMA2EN);
+        }
+
+        Self { busy: false }
+    }
+
+    /// Waits for the transfer in progress, if any.
+    fn wait(&mut self) {
+        if !self.busy {
+            return;
+        }
+
+        #[allow(unsafe_code)]
+        // SAFETY: Only the copier accesses the DMA2 stream 0 flags
+        unsafe {
+            while DMA2_LISR.read_volatile() & DMA_LISR_TCIF0 == 0 {
+                defmt::assert!(DMA2_LISR.read_volatile() & DMA_LISR_TEIF0 == 0);
+            }
+            DMA2_LIFCR.write_volatile(DMA_LIFCR_STREAM0);
+        }
+
+        self.busy = false;
+    }
+}
+
+#[allow(unsafe_code)]
+// SAFETY: The copier is given word aligned addresses and a length in whole words, the DMA
+// transfers the words only
+impl SectionCopier for Dma2Copier {
+    unsafe fn copy(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+        let mut copied = 0;
+
+        while copied < len {
+            let items = ((len - copied) / 4).min(DMA_MAX_ITEMS);
+
+            // the stream is reconfigured only once the previous transfer is complete
+            self.wait();
+
+            // SAFETY: Only the copier accesses the DMA2 stream 0
+            unsafe {
+                DMA2_S0PAR.write_volatile(src.add(copied) as u32);
+                DMA2_S0M0AR.write_volatile(dst.add(copied) as u32);
+                DMA2_S0NDTR.write_volatile(items as u32);
+                DMA2_S0FCR.write_volatile(DMA_SXFCR_FIFO_FULL);
+                DMA2_S0CR.write_volatile(DMA_SXCR_M2M_WORDS);
+                DMA2_S0CR.write_volatile(DMA_SXCR_M2M_WORDS | DMA_SXCR_EN);
+            }
+
+            self.busy = true;
+            copied += items * 4;
+        }
+    }
+}
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".sdram_data")]
+static mut SDRAM_ARRAY: [u32; SDRAM_WORDS] = [SDRAM_VALUE; SDRAM_WORDS];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".sdram_tables")]
+static mut SDRAM_TABLE: [u32; 8] = TABLE;
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // The DMA needs its clock, so the sections are initialized in main
+    let mut dma = Dma2Copier::new();
+    let sdram = defer_sections_init!(sdram_data);
+
+    #[allow(unsafe_code)]
+    // SAFETY: The section is not accessed until the DMA transfer is complete
+    unsafe {
+        sdram.run_with(&mut dma)
+    };
+
+    // The CPU is free to configure other peripherals while the DMA copies the section
+    defmt::info!("sdram_data copy started");
+    dma.wait();
+
+    init_sections_with_copier!(&mut dma; sdram_tables);
+    dma.wait();
+
+    #[allow(unsafe_code)]
+    // SAFETY: Only the main accesses the statics once the DMA transfers are complete
+    let (array, table) = unsafe { (SDRAM_ARRAY, SDRAM_TABLE) };
+
+    // Check whether the sections got initialized
+    defmt::assert!(array.iter().all(|&word| word == SDRAM_VALUE));
+    defmt::assert_eq!(table, TABLE);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
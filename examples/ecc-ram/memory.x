@@ -0,0 +1,35 @@
+MEMORY
+{
+    /* STM32H743, the RAM is DTCM, the sections below are placed in the AXI SRAM */
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 62K
+    CONSTS      : ORIGIN = 0x0800F800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH = 16K
+    /* AXI SRAM protected by ECC over 64-bit words */
+    AXI_SRAM    : ORIGIN = 0x24000000, LENGTH = 512K
+}
+
+SECTIONS
+{
+    .axi_data : ALIGN(8)
+    {
+        . = ALIGN(8);
+        __saxi_data = .;
+        *(.axi_data .axi_data.*);
+        . = ALIGN(8);
+        __eaxi_data = .;
+    } > AXI_SRAM AT>CONSTS
+    __siaxi_data = LOADADDR(.axi_data);
+
+    .axi_bss (NOLOAD) : ALIGN(8)
+    {
+        . = ALIGN(8);
+        __saxi_bss = .;
+        *(.axi_bss .axi_bss.*);
+        . = ALIGN(8);
+        __eaxi_bss = .;
+    } > AXI_SRAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
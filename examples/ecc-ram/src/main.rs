@@ -0,0 +1,77 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{init_sections, zero_sections};
+use {defmt_rtt as _, panic_probe as _};
+
+const AXI_VALUE: u64 = 0xA5A5_5A5A_0123_4567;
+const AXI_WORDS: usize = 64;
+
+/// RAMECC1 monitor 1 (AXI SRAM) control register of STM32H7.
+const RAMECC1_M1CR: *mut u32 = 0x5200_9020 as *mut u32;
+
+/// RAMECC1 monitor 1 (AXI SRAM) status register of STM32H7.
+const RAMECC1_M1SR: *mut u32 = 0x5200_9024 as *mut u32;
+
+/// ECC error latching enable bit, the errors are recorded in the status register.
+const RAMECC_MCR_ECCELEN: u32 = 1 << 5;
+
+/// Single error detected and corrected, double error detected and double error on byte write.
+const RAMECC_MSR_ERRORS: u32 = 0b111;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".axi_data")]
+static mut AXI_ARRAY: [u64; AXI_WORDS] = [AXI_VALUE; AXI_WORDS];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets zeroed because of using `linker_sections`
+#[unsafe(link_section = ".axi_bss")]
+static mut AXI_ZEROED: [u64; AXI_WORDS] = [0; AXI_WORDS];
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    #[allow(unsafe_code)]
+    // SAFETY: Nothing else accesses the RAMECC before the main
+    unsafe {
+        RAMECC1_M1CR.write_volatile(RAMECC1_M1CR.read_volatile() | RAMECC_MCR_ECCELEN);
+    }
+
+    // the AXI SRAM is written by whole 64-bit ECC words only and never read before
+    init_sections!(axi_data: ecc64);
+    zero_sections!(axi_bss: ecc64);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: Only the main accesses the statics
+    let (array, zeroed) = unsafe { (AXI_ARRAY, AXI_ZEROED) };
+
+    // Check whether the sections got initialized
+    defmt::assert!(array.iter().all(|&word| word == AXI_VALUE));
+    defmt::assert!(zeroed.iter().all(|&word| word == 0));
+
+    #[allow(unsafe_code)]
+    // SAFETY: The status register is only read
+    let errors = unsafe { RAMECC1_M1SR.read_volatile() } & RAMECC_MSR_ERRORS;
+
+    // Check no ECC error was latched neither by the initialization nor by the reads
+    defmt::assert_eq!(errors, 0);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
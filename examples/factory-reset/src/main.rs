@@ -0,0 +1,100 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{define_sections_init, reinit_section};
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut STATIC_ARRAY: [u32; 256] = [INITIAL_VALUE; 256];
+
+const CALIBRATION: [u32; 16] = [0x0C0F_FEE0; 16];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".calibration")]
+static mut CALIBRATION_TABLE: [u32; 16] = CALIBRATION;
+
+define_sections_init!(
+    /// Restores default values of all variables placed in `.custom_data` and `.calibration`
+    fn restore_defaults;
+    custom_data, calibration
+);
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // SAFETY: Nothing accesses the section before pre-init
+    unsafe { restore_defaults() };
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAY got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY).read(),
+            [INITIAL_VALUE; 256]
+        );
+
+        // Change ARRAY at runtime
+        core::ptr::addr_of_mut!(STATIC_ARRAY).write([0; 256]);
+        defmt::assert_eq!(core::ptr::addr_of!(STATIC_ARRAY).read(), [0; 256]);
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: There is no reference to the section memory alive
+    unsafe {
+        restore_defaults();
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAY got restored
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY).read(),
+            [INITIAL_VALUE; 256]
+        );
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether calibration got initialized and change it at runtime
+        defmt::assert_eq!(core::ptr::addr_of!(CALIBRATION_TABLE).read(), CALIBRATION);
+        core::ptr::addr_of_mut!(CALIBRATION_TABLE).write([0; 16]);
+    }
+
+    // Restore factory calibration, e.g. on a command received at runtime
+    let restored = reinit_section!(calibration);
+    defmt::assert_eq!(restored, core::mem::size_of_val(&CALIBRATION));
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether calibration got restored
+        defmt::assert_eq!(core::ptr::addr_of!(CALIBRATION_TABLE).read(), CALIBRATION);
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,34 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    CUSTOM_RAM  : ORIGIN = 0x20002000, LENGTH =  2K
+    NOINIT_RAM  : ORIGIN = 0x20002800, LENGTH =  2K
+}
+
+SECTIONS
+{
+    /* The start and end symbols are swapped by mistake, the section end precedes its start */
+    .custom_data : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __ecustom_data = .;
+        *(.custom_data .custom_data.*);
+        . = ALIGN(4);
+        __scustom_data = .;
+    } > CUSTOM_RAM AT>FLASH
+    __sicustom_data = LOADADDR(.custom_data);
+
+    .failure_record (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sfailure_record = .;
+        . += 8;
+        . = ALIGN(4);
+        __efailure_record = .;
+    } > NOINIT_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,95 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{init_sections, section, set_failure_handler, InitError};
+use {defmt_rtt as _, panic_probe as _};
+
+/// Marks the failure record as valid, the noinit memory content is undefined at power-up
+const MAGIC: u32 = 0xFA11_C0DE;
+
+/// Error code of a section whose end precedes its start
+const REVERSED: u32 = InitError::Reversed { start: 0, end: 0 }.code();
+
+/// Failure of the previous boot, kept in the noinit section across the reset
+#[repr(C)]
+struct FailureRecord {
+    magic: u32,
+    code: u32,
+}
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut STATIC_ARRAY: [u32; 16] = [0xDEAD_BEEF; 16];
+
+set_failure_handler!(record_and_reset);
+
+/// Returns the failure record in the noinit section, usable in pre-init as no static is involved.
+fn failure_record() -> *mut FailureRecord {
+    section!(failure_record(__s, __e)).start().cast()
+}
+
+/// Records `error` into the noinit section and resets, called in pre-init before any static is
+/// initialized, so it neither logs nor panics.
+fn record_and_reset(error: &InitError) -> ! {
+    let record = FailureRecord {
+        magic: MAGIC,
+        code: error.code(),
+    };
+
+    #[allow(unsafe_code)]
+    // SAFETY: The noinit section is reserved for the failure record, nothing else accesses it
+    unsafe {
+        failure_record().write_volatile(record)
+    };
+
+    cortex_m::peripheral::SCB::sys_reset();
+}
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // SAFETY: The noinit section is reserved for the failure record
+    let recorded = unsafe { failure_record().read_volatile().magic == MAGIC };
+
+    // Boot with the section left uninitialized once its failure is recorded, to report it
+    if !recorded {
+        init_sections!(custom_data);
+    }
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: The noinit section is reserved for the failure record
+    let record = unsafe { failure_record().read_volatile() };
+
+    // Check whether the swapped section symbols got reported before the reset
+    defmt::assert_eq!(record.magic, MAGIC);
+    defmt::assert_eq!(record.code, REVERSED);
+    defmt::info!("previous boot failed with error code {}", record.code);
+
+    #[allow(unsafe_code)]
+    // SAFETY: The noinit section is reserved for the failure record
+    unsafe {
+        // Clear the record, so the next boot tries to initialize the section again
+        failure_record().write_volatile(FailureRecord { magic: 0, code: 0 });
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    let _ = unsafe { core::ptr::addr_of!(STATIC_ARRAY).read() };
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,25 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH = 18K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    /* stands for a large memory mirrored from flash, e.g. an external SDRAM */
+    MIRROR_RAM  : ORIGIN = 0x20002000, LENGTH = 16K
+}
+
+SECTIONS
+{
+    .mirror_data : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __smirror_data = .;
+        *(.mirror_data .mirror_data.*);
+        . = ALIGN(4);
+        __emirror_data = .;
+    } > MIRROR_RAM AT>CONSTS
+    __simirror_data = LOADADDR(.mirror_data);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
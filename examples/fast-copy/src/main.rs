@@ -0,0 +1,53 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use cortex_m::peripheral::DWT;
+use linker_sections::init_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+const MIRROR_VALUE: u32 = 0xA5A5_5A5A;
+const MIRROR_WORDS: usize = 4096;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".mirror_data")]
+static mut MIRROR_ARRAY: [u32; MIRROR_WORDS] = [MIRROR_VALUE; MIRROR_WORDS];
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    let mut peripherals = cortex_m::Peripherals::take().unwrap();
+    peripherals.DCB.enable_trace();
+    peripherals.DWT.enable_cycle_counter();
+
+    // Nothing accesses the section before, so it could be initialized here and measured
+    let start = DWT::cycle_count();
+    init_sections!(mirror_data);
+    let cycles = DWT::cycle_count().wrapping_sub(start);
+
+    defmt::info!(
+        "{=usize} bytes copied in {=u32} cycles, fast-copy {=bool}",
+        MIRROR_WORDS * 4,
+        cycles,
+        cfg!(feature = "fast-copy")
+    );
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    let mirror = unsafe { &*core::ptr::addr_of!(MIRROR_ARRAY) };
+
+    // Check whether the array got initialized
+    defmt::assert!(mirror.iter().all(|&word| word == MIRROR_VALUE));
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
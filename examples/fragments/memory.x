@@ -0,0 +1,35 @@
+MEMORY
+{
+    /* STM32F429, the load data of the section are split across the two flash banks */
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 1022K
+    BANK1_END   : ORIGIN = 0x080FF800, LENGTH =    2K
+    BANK2_START : ORIGIN = 0x08100000, LENGTH =    2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =    4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =    4K
+    CUSTOM_RAM  : ORIGIN = 0x20002000, LENGTH =    4K
+}
+
+SECTIONS
+{
+    .custom_data0 : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_data = .;
+        *(.custom_data0 .custom_data0.*);
+        . = ALIGN(4);
+    } > CUSTOM_RAM AT>BANK1_END
+    __sicustom_data0 = LOADADDR(.custom_data0);
+    __eicustom_data0 = LOADADDR(.custom_data0) + SIZEOF(.custom_data0);
+
+    .custom_data1 : ALIGN(4)
+    {
+        *(.custom_data1 .custom_data1.*);
+        . = ALIGN(4);
+        __ecustom_data = .;
+    } > CUSTOM_RAM AT>BANK2_START
+    __sicustom_data1 = LOADADDR(.custom_data1);
+    __eicustom_data1 = LOADADDR(.custom_data1) + SIZEOF(.custom_data1);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
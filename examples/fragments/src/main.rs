@@ -0,0 +1,51 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_sections_fragments;
+use {defmt_rtt as _, panic_probe as _};
+
+const HEAD_VALUE: u32 = 0xDEAD_BEEF;
+const TAIL_VALUE: u32 = 0xCAFE_F00D;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data0")]
+static mut HEAD_ARRAY: [u32; 256] = [HEAD_VALUE; 256];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data1")]
+static mut TAIL_ARRAY: [u32; 256] = [TAIL_VALUE; 256];
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The load data at the end of bank 1 and at the start of bank 2 fill the section in order
+    init_sections_fragments!(custom_data[__sicustom_data0..__eicustom_data0, __sicustom_data1..__eicustom_data1]);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: Only the main accesses the statics
+    let (head, tail) = unsafe { (HEAD_ARRAY, TAIL_ARRAY) };
+
+    // Check whether both fragments got copied
+    defmt::assert!(head.iter().all(|&word| word == HEAD_VALUE));
+    defmt::assert!(tail.iter().all(|&word| word == TAIL_VALUE));
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,34 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    RAM2        : ORIGIN = 0x20002000, LENGTH =  2K
+}
+
+/* Sections taken over from a GCC linker script as they are */
+SECTIONS
+{
+    .ram2_data :
+    {
+        . = ALIGN(4);
+        __ram2_data_start__ = .;
+        *(.ram2_data .ram2_data.*)
+        . = ALIGN(4);
+        __ram2_data_end__ = .;
+    } > RAM2 AT > CONSTS
+    __ram2_data_load_start__ = LOADADDR(.ram2_data);
+
+    .ram2_bss (NOLOAD) :
+    {
+        . = ALIGN(4);
+        __ram2_bss_start__ = .;
+        *(.ram2_bss .ram2_bss.*)
+        . = ALIGN(4);
+        __ram2_bss_end__ = .;
+    } > RAM2
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,58 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use core::mem::MaybeUninit;
+use linker_sections::init_sections_gnu;
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".ram2_data")]
+static mut STATIC_ARRAY: [u32; 128] = [INITIAL_VALUE; 128];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets zeroed because of using `linker_sections`
+#[unsafe(link_section = ".ram2_bss")]
+static mut ZEROED_ARRAY: MaybeUninit<[u32; 128]> = MaybeUninit::uninit();
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // `__ram2_data_start__`, `__ram2_data_end__` and `__ram2_data_load_start__` for `.ram2_data`,
+    // `__ram2_bss_start__` and `__ram2_bss_end__` for `.ram2_bss`
+    init_sections_gnu!(ram2_data, zero ram2_bss);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing those static mut variables
+    unsafe {
+        // Check whether ARRAYs got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY).read(),
+            [INITIAL_VALUE; 128]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(ZEROED_ARRAY).read().assume_init(),
+            [0; 128]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
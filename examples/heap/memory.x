@@ -0,0 +1,22 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 64K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    HEAP_RAM    : ORIGIN = 0x20002000, LENGTH =  8K
+}
+
+SECTIONS
+{
+    .heap_region (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sheap_region = .;
+        . += LENGTH(HEAP_RAM);
+        . = ALIGN(4);
+        __eheap_region = .;
+    } > HEAP_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
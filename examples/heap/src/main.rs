@@ -0,0 +1,38 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use embedded_alloc::LlffHeap;
+use linker_sections::{heap_section, init_heap};
+use {defmt_rtt as _, panic_probe as _};
+
+#[global_allocator]
+static HEAP: LlffHeap = LlffHeap::empty();
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // The heap section is not initialized, it is handed over to the allocator as it is
+    defmt::assert!(init_heap!(HEAP, heap_region));
+
+    let (start, size) = heap_section!(heap_region);
+    defmt::info!("heap at 0x{:08X}, {} bytes", start, size);
+
+    let values: Vec<u32> = (0..256).collect();
+
+    // Check whether the vector got allocated in the heap section
+    let address = values.as_ptr() as usize;
+    defmt::assert!((start..start + size).contains(&address));
+    defmt::assert_eq!(values.iter().sum::<u32>(), 32640);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
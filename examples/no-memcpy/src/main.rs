@@ -0,0 +1,100 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use linker_sections::init_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_BYTES: [u8; 23] = *b"initialized before main";
+
+/// Marks the sections being initialized, any `memcpy` called meanwhile is a failure.
+const PRE_INIT_MAGIC: u32 = 0x5EC7_1017;
+
+#[allow(unsafe_code)]
+// SAFETY: Lives in `.uninit`, so it is usable during `pre_init` already, it is written before
+// being read
+#[unsafe(link_section = ".uninit.no_memcpy")]
+static PRE_INIT: AtomicU32 = AtomicU32::new(0);
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut CUSTOM_BYTES: [u8; 23] = INITIAL_BYTES;
+
+/// Stands for a vendor `memcpy` relying on initialized RAM, halts if called during `pre_init`.
+///
+/// # Safety
+///
+/// Same as for `memcpy`.
+#[allow(unsafe_code)]
+unsafe fn poisoned_memcpy(dst: *mut u8, src: *const u8, len: usize) -> *mut u8 {
+    if PRE_INIT.load(Ordering::Relaxed) == PRE_INIT_MAGIC {
+        cortex_m::asm::udf();
+    }
+
+    // volatile accesses, so the loop is not lowered to a `memcpy` call
+    for i in 0..len {
+        // SAFETY: The caller guarantees `memcpy` requirements
+        unsafe { dst.add(i).write_volatile(src.add(i).read_volatile()) };
+    }
+
+    dst
+}
+
+#[allow(unsafe_code)]
+// SAFETY: Replaces the `compiler_builtins` weak symbols with the same signature
+mod poisoned {
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn memcpy(dst: *mut u8, src: *const u8, len: usize) -> *mut u8 {
+        unsafe { super::poisoned_memcpy(dst, src, len) }
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn __aeabi_memcpy(dst: *mut u8, src: *const u8, len: usize) {
+        unsafe { super::poisoned_memcpy(dst, src, len) };
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn __aeabi_memcpy4(dst: *mut u8, src: *const u8, len: usize) {
+        unsafe { super::poisoned_memcpy(dst, src, len) };
+    }
+
+    #[unsafe(no_mangle)]
+    unsafe extern "C" fn __aeabi_memcpy8(dst: *mut u8, src: *const u8, len: usize) {
+        unsafe { super::poisoned_memcpy(dst, src, len) };
+    }
+}
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    PRE_INIT.store(PRE_INIT_MAGIC, Ordering::Relaxed);
+
+    // `no-memcpy` feature makes the copy never call `memcpy`
+    init_sections!(custom_data);
+
+    PRE_INIT.store(0, Ordering::Relaxed);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether the bytes got initialized
+        defmt::assert_eq!(core::ptr::addr_of!(CUSTOM_BYTES).read(), INITIAL_BYTES);
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
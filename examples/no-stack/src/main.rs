@@ -0,0 +1,75 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::define_sections_init_no_stack;
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
+
+/// Stands for the stack memory not usable yet, any push faults there, reserved on STM32F407.
+const INVALID_STACK_POINTER: u32 = 0x3000_0000;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut STATIC_ARRAY: [u32; 16] = [INITIAL_VALUE; 16];
+
+#[allow(unsafe_code)]
+// SAFETY: Written by `__pre_init` only, `.uninit` memory is usable before the RAM initialization
+#[unsafe(link_section = ".uninit.no_stack")]
+static mut SAVED_REGISTERS: [u32; 2] = [0; 2];
+
+define_sections_init_no_stack!(fn init_sections_stack_free; custom_data);
+
+#[allow(unsafe_code)]
+// SAFETY: The stack pointer and the return address are restored before the return
+#[unsafe(naked)]
+#[unsafe(no_mangle)]
+unsafe extern "C" fn __pre_init() {
+    core::arch::naked_asm!(
+        // the stack pointer and the return address are saved outside of the stack
+        "ldr r0, ={saved}",
+        "mov r1, sp",
+        "str r1, [r0]",
+        "mov r1, lr",
+        "str r1, [r0, #4]",
+        // any stack access faults from now on
+        "ldr r0, ={invalid}",
+        "mov sp, r0",
+        "bl {init}",
+        "ldr r0, ={saved}",
+        "ldr r1, [r0]",
+        "mov sp, r1",
+        "ldr r1, [r0, #4]",
+        "bx r1",
+        saved = sym SAVED_REGISTERS,
+        invalid = const INVALID_STACK_POINTER,
+        init = sym init_sections_stack_free,
+    );
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAY got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY).read(),
+            [INITIAL_VALUE; 16]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
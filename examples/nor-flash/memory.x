@@ -0,0 +1,24 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    ASSETS_RAM  : ORIGIN = 0x20002000, LENGTH =  1K
+}
+
+SECTIONS
+{
+    /* the load data is in the external NOR flash, so there is no __sinor_assets symbol */
+    .nor_assets (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __snor_assets = .;
+        *(.nor_assets .nor_assets.*);
+        . = ALIGN(4);
+        __enor_assets = .;
+    } > ASSETS_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
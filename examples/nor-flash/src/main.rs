@@ -0,0 +1,113 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use core::mem::MaybeUninit;
+use linker_sections::{init_sections_from_nor, nor::ReadNorFlash};
+use {defmt_rtt as _, panic_probe as _};
+
+const ASSETS_LEN: usize = 256;
+const ASSETS_VALUE: u32 = 0xCAFE_BABE;
+
+/// Offset of the assets in the NOR flash image, e.g. as written by the production programmer.
+const ASSETS_OFFSET: u32 = 0x100;
+
+/// Size of the stand-in NOR flash.
+const NOR_CAPACITY: usize = 2048;
+
+/// Builds the NOR flash content holding the assets at their offset.
+const fn nor_image() -> [u8; NOR_CAPACITY] {
+    let mut image = [0xFF; NOR_CAPACITY];
+    let value = ASSETS_VALUE.to_le_bytes();
+
+    let mut index = 0;
+    while index < ASSETS_LEN * 4 {
+        image[ASSETS_OFFSET as usize + index] = value[index % 4];
+        index += 1;
+    }
+
+    image
+}
+
+/// Content of the stand-in NOR flash.
+static NOR_IMAGE: [u8; NOR_CAPACITY] = nor_image();
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".nor_assets")]
+static mut NOR_ASSETS: MaybeUninit<[u32; ASSETS_LEN]> = MaybeUninit::uninit();
+
+/// Stands for a SPI NOR flash driver, e.g. implementing `embedded_storage` traits.
+struct SpiNor {
+    ready: bool,
+}
+
+/// Error of [`SpiNor`] reads.
+#[derive(Debug, defmt::Format)]
+enum SpiNorError {
+    NotReady,
+    OutOfBounds,
+}
+
+impl SpiNor {
+    /// Stands for the SPI peripheral and the flash wake-up configuration.
+    fn new() -> Self {
+        defmt::info!("SPI NOR configured");
+
+        Self { ready: true }
+    }
+}
+
+impl ReadNorFlash for SpiNor {
+    type Error = SpiNorError;
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        if !self.ready {
+            return Err(SpiNorError::NotReady);
+        }
+
+        // stands for a READ command transaction
+        let start = offset as usize;
+        let data = NOR_IMAGE
+            .get(start..start + bytes.len())
+            .ok_or(SpiNorError::OutOfBounds)?;
+        bytes.copy_from_slice(data);
+
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        NOR_CAPACITY
+    }
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // The load data is read through the driver, so the section is initialized once it is up
+    let mut spi_nor = SpiNor::new();
+
+    // Nothing accesses the section yet
+    if let Err(error) = init_sections_from_nor!(&mut spi_nor; nor_assets at ASSETS_OFFSET) {
+        defmt::panic!("NOR assets read failed: {}", error);
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable, initialized above
+    let assets = unsafe { core::ptr::addr_of!(NOR_ASSETS).read().assume_init() };
+
+    // Check whether the assets got read
+    defmt::assert_eq!(assets, [ASSETS_VALUE; ASSETS_LEN]);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{init_sections, section};
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut STATIC_ARRAY: [u32; 256] = [INITIAL_VALUE; 256];
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // `.sram4_data` is not defined in the linker script, so it is skipped
+    init_sections!(custom_data, optional sram4_data);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // Missing optional section resolves to an empty one
+    defmt::assert!(section!(optional sram4_data).is_empty());
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAY got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY).read(),
+            [INITIAL_VALUE; 256]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
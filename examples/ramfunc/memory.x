@@ -0,0 +1,25 @@
+MEMORY
+{
+    /* STM32H743, the RAM is DTCM, the code section is placed in the cached AXI SRAM */
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 62K
+    CONSTS      : ORIGIN = 0x0800F800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH = 16K
+    AXI_SRAM    : ORIGIN = 0x24000000, LENGTH = 512K
+}
+
+SECTIONS
+{
+    .ramfunc : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sramfunc = .;
+        *(.ramfunc .ramfunc.*);
+        . = ALIGN(4);
+        __eramfunc = .;
+    } > AXI_SRAM AT>CONSTS
+    __siramfunc = LOADADDR(.ramfunc);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
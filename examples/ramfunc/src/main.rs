@@ -0,0 +1,56 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+const VALUES: [u32; 8] = [1, 2, 3, 5, 8, 13, 21, 34];
+const VALUES_SUM: u32 = 87;
+
+/// AXI SRAM of STM32H7 the `.ramfunc` section is placed in.
+const AXI_SRAM: core::ops::Range<usize> = 0x2400_0000..0x2408_0000;
+
+/// Sums the values, runs from the AXI SRAM.
+#[allow(unsafe_code)]
+// SAFETY: The section is initialized before the function is called
+#[unsafe(link_section = ".ramfunc")]
+#[inline(never)]
+fn ram_sum(values: &[u32]) -> u32 {
+    let mut sum: u32 = 0;
+    let mut index = 0;
+
+    // a plain loop, so no iterator adapters are called from the flash in debug builds
+    while index < values.len() {
+        sum = sum.wrapping_add(values[index]);
+        index += 1;
+    }
+
+    sum
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // The caches are enabled before the code section is copied, e.g. as done by a bootloader
+    let mut peripherals = defmt::unwrap!(cortex_m::Peripherals::take());
+    peripherals.SCB.enable_icache();
+    peripherals.SCB.enable_dcache(&mut peripherals.CPUID);
+
+    // The copied instructions are written back from the data cache and the instruction cache
+    // lines are invalidated, so the function could be called right away
+    init_sections!(ramfunc clean_dcache code);
+
+    // Check the function runs from the RAM and the fetched instructions are the copied ones
+    let address = ram_sum as *const () as usize;
+    defmt::assert!(AXI_SRAM.contains(&address));
+    defmt::assert_eq!(ram_sum(&VALUES), VALUES_SUM);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,22 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    NOINIT_RAM  : ORIGIN = 0x20002000, LENGTH =  2K
+}
+
+SECTIONS
+{
+    .reboot_counter (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sreboot_counter = .;
+        . += 8;
+        . = ALIGN(4);
+        __ereboot_counter = .;
+    } > NOINIT_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
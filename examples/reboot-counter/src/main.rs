@@ -0,0 +1,46 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::declare_noinit_section;
+use {defmt_rtt as _, panic_probe as _};
+
+/// Marks the counter as valid, the noinit memory content is undefined at power-up
+const MAGIC: u32 = 0xB007_C0DE;
+
+#[repr(C)]
+struct RebootCounter {
+    magic: u32,
+    count: u32,
+}
+
+declare_noinit_section!(reboot_counter: RebootCounter);
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    let counter = reboot_counter().unwrap();
+
+    #[allow(unsafe_code)]
+    // SAFETY: Any bit pattern is a valid `RebootCounter`
+    let counter = unsafe { counter.assume_init_mut() };
+
+    if counter.magic != MAGIC {
+        defmt::info!("power-up, counter reset");
+        *counter = RebootCounter {
+            magic: MAGIC,
+            count: 0,
+        };
+    }
+
+    counter.count = counter.count.wrapping_add(1);
+    defmt::info!("boot number {}", counter.count);
+
+    // The memory is handed out only once
+    defmt::assert!(reboot_counter().is_none());
+
+    // Let the counter survive a software reset
+    cortex_m::asm::delay(16_000_000);
+    cortex_m::peripheral::SCB::sys_reset();
+}
@@ -0,0 +1,70 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_registered_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+mod module_a {
+    use linker_sections::register_section;
+
+    pub const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
+
+    register_section!(custom_data_a);
+
+    #[allow(unsafe_code)]
+    // SAFETY:
+    // - Using static mut just to force compiler not to optimize it out in
+    //   this simple example
+    // - linker section gets initialized because of using `linker_sections`
+    #[unsafe(link_section = ".custom_data_a")]
+    pub static mut STATIC_ARRAY: [u32; 256] = [INITIAL_VALUE; 256];
+}
+
+mod module_b {
+    use linker_sections::register_section;
+
+    pub const INITIAL_VALUE: u32 = 0xCAFE_BABE;
+
+    register_section!(custom_data_b);
+
+    #[allow(unsafe_code)]
+    // SAFETY:
+    // - Using static mut just to force compiler not to optimize it out in
+    //   this simple example
+    // - linker section gets initialized because of using `linker_sections`
+    #[unsafe(link_section = ".custom_data_b")]
+    pub static mut STATIC_ARRAY: [u32; 256] = [INITIAL_VALUE; 256];
+}
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // Initializes all sections registered in modules
+    init_registered_sections!();
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    unsafe {
+        // Check whether ARRAYs got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(module_a::STATIC_ARRAY).read(),
+            [module_a::INITIAL_VALUE; 256]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(module_b::STATIC_ARRAY).read(),
+            [module_b::INITIAL_VALUE; 256]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,26 @@
+use std::{env, path::PathBuf};
+
+include!("src/table.rs");
+
+fn main() {
+    println!("cargo:rustc-link-arg=--nmagic");
+    println!("cargo:rustc-link-arg=-Tlink.x");
+    println!("cargo:rustc-link-arg=-Tdefmt.x");
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let crate_name = env!("CARGO_PKG_NAME");
+
+    let mut map_file_path = PathBuf::from(&manifest_dir);
+    map_file_path.push(crate_name);
+    println!("cargo:rustc-link-arg=-Map={}.map", map_file_path.display());
+
+    let linker_search_path = PathBuf::from(&manifest_dir);
+    println!("cargo:rustc-link-search={}", linker_search_path.display());
+
+    // the section load data is the encoded table, included by the firmware
+    let table: Vec<u8> = (0..TABLE_LEN).map(table_byte).collect();
+    let blob = linker_sections::rle::encode_blob(&table);
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("config_table.rle"), blob).unwrap();
+}
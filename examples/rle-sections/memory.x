@@ -0,0 +1,30 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    TABLE_RAM   : ORIGIN = 0x20002000, LENGTH =  8K
+}
+
+SECTIONS
+{
+    /* the encoded blob is the load data, the only part of the table taking flash */
+    .config_table_rle : ALIGN(4)
+    {
+        __siconfig_table = .;
+        KEEP(*(.config_table_rle .config_table_rle.*));
+    } > CONSTS
+
+    .config_table (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sconfig_table = .;
+        *(.config_table .config_table.*);
+        . = ALIGN(4);
+        __econfig_table = .;
+    } > TABLE_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,65 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+mod table;
+
+use core::mem::MaybeUninit;
+use linker_sections::init_sections;
+use table::{table_byte, TABLE_LEN};
+use {defmt_rtt as _, panic_probe as _};
+
+/// Length of the encoded table blob made by the build script.
+const BLOB_LEN: usize = include_bytes!(concat!(env!("OUT_DIR"), "/config_table.rle")).len();
+
+#[allow(unsafe_code)]
+// SAFETY: The blob is only read as the section load data
+#[unsafe(link_section = ".config_table_rle")]
+#[used]
+static CONFIG_TABLE_RLE: [u8; BLOB_LEN] =
+    *include_bytes!(concat!(env!("OUT_DIR"), "/config_table.rle"));
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".config_table")]
+static mut CONFIG_TABLE: MaybeUninit<[u8; TABLE_LEN]> = MaybeUninit::uninit();
+
+fn table_corrupted(_error: linker_sections::rle::Error) {
+    defmt::panic!("config table blob corrupted");
+}
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The table is decoded from the blob, bound-checked against the section end
+    init_sections!(config_table compressed(rle, table_corrupted));
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+    defmt::info!(
+        "config table takes {} bytes of flash instead of {}",
+        BLOB_LEN,
+        TABLE_LEN
+    );
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable, initialized in pre-init
+    let table = unsafe { &*core::ptr::addr_of!(CONFIG_TABLE).cast::<[u8; TABLE_LEN]>() };
+
+    // Check whether the table got decoded
+    for (index, &byte) in table.iter().enumerate() {
+        defmt::assert_eq!(byte, table_byte(index));
+    }
+    defmt::assert!(BLOB_LEN < TABLE_LEN / 8);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
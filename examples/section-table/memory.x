@@ -0,0 +1,69 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 30K
+    CONSTS      : ORIGIN = 0x08007800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    CUSTOM_RAM  : ORIGIN = 0x20002000, LENGTH =  2K
+}
+
+SECTIONS
+{
+    .custom_data_a : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_data_a = .;
+        *(.custom_data_a .custom_data_a.*);
+        . = ALIGN(4);
+        __ecustom_data_a = .;
+    } > CUSTOM_RAM AT>CONSTS
+    __sicustom_data_a = LOADADDR(.custom_data_a);
+
+    .custom_data_b : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_data_b = .;
+        *(.custom_data_b .custom_data_b.*);
+        . = ALIGN(4);
+        __ecustom_data_b = .;
+    } > CUSTOM_RAM AT>CONSTS
+    __sicustom_data_b = LOADADDR(.custom_data_b);
+
+    .custom_data_c : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_data_c = .;
+        *(.custom_data_c .custom_data_c.*);
+        . = ALIGN(4);
+        __ecustom_data_c = .;
+    } > CUSTOM_RAM AT>CONSTS
+    __sicustom_data_c = LOADADDR(.custom_data_c);
+
+    .custom_data_d : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scustom_data_d = .;
+        *(.custom_data_d .custom_data_d.*);
+        . = ALIGN(4);
+        __ecustom_data_d = .;
+    } > CUSTOM_RAM AT>CONSTS
+    __sicustom_data_d = LOADADDR(.custom_data_d);
+} INSERT AFTER .uninit;
+
+/* Table of {VMA start, VMA end, LMA start} triples walked by `init_section_table!` */
+SECTIONS
+{
+    .section_init_table : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __ssection_init_table = .;
+        LONG(__scustom_data_a) LONG(__ecustom_data_a) LONG(__sicustom_data_a)
+        LONG(__scustom_data_b) LONG(__ecustom_data_b) LONG(__sicustom_data_b)
+        LONG(__scustom_data_c) LONG(__ecustom_data_c) LONG(__sicustom_data_c)
+        LONG(__scustom_data_d) LONG(__ecustom_data_d) LONG(__sicustom_data_d)
+        __esection_init_table = .;
+    } > FLASH
+} INSERT AFTER .rodata;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,86 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_section_table;
+use {defmt_rtt as _, panic_probe as _};
+
+const INITIAL_VALUE_A: u32 = 0xDEAD_BEEF;
+const INITIAL_VALUE_B: u32 = 0xCAFE_BABE;
+const INITIAL_VALUE_C: u32 = 0x0BAD_F00D;
+const INITIAL_VALUE_D: u32 = 0xFEED_FACE;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data_a")]
+static mut STATIC_ARRAY_A: [u32; 64] = [INITIAL_VALUE_A; 64];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data_b")]
+static mut STATIC_ARRAY_B: [u32; 64] = [INITIAL_VALUE_B; 64];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data_c")]
+static mut STATIC_ARRAY_C: [u32; 64] = [INITIAL_VALUE_C; 64];
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data_d")]
+static mut STATIC_ARRAY_D: [u32; 64] = [INITIAL_VALUE_D; 64];
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // All sections listed in the table get initialized by a single loop. With just four sections
+    // it takes 84 bytes of flash more than
+    // `init_sections!(custom_data_a, custom_data_b, custom_data_c, custom_data_d)` in release
+    // build, the table pays off from about a dozen sections.
+    init_section_table!(__ssection_init_table, __esection_init_table);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing those static mut variables
+    unsafe {
+        // Check whether ARRAYs got initialized
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_A).read(),
+            [INITIAL_VALUE_A; 64]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_B).read(),
+            [INITIAL_VALUE_B; 64]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_C).read(),
+            [INITIAL_VALUE_C; 64]
+        );
+        defmt::assert_eq!(
+            core::ptr::addr_of!(STATIC_ARRAY_D).read(),
+            [INITIAL_VALUE_D; 64]
+        );
+    }
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
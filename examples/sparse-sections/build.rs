@@ -0,0 +1,31 @@
+use std::{env, path::PathBuf};
+
+include!("src/state.rs");
+
+fn main() {
+    println!("cargo:rustc-link-arg=--nmagic");
+    println!("cargo:rustc-link-arg=-Tlink.x");
+    println!("cargo:rustc-link-arg=-Tdefmt.x");
+
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let crate_name = env!("CARGO_PKG_NAME");
+
+    let mut map_file_path = PathBuf::from(&manifest_dir);
+    map_file_path.push(crate_name);
+    println!("cargo:rustc-link-arg=-Map={}.map", map_file_path.display());
+
+    let linker_search_path = PathBuf::from(&manifest_dir);
+    println!("cargo:rustc-link-search={}", linker_search_path.display());
+
+    // the section load data is the table of the nonzero words, included by the firmware
+    let state: Vec<u8> = (0..STATE_WORDS)
+        .flat_map(|index| state_word(index).to_le_bytes())
+        .collect();
+    let table = linker_sections::sparse::encode_blob(
+        &state,
+        linker_sections::sparse::DEFAULT_THRESHOLD_PERCENT,
+    );
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("controller_state.sparse"), table).unwrap();
+}
@@ -0,0 +1,30 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 28K
+    CONSTS      : ORIGIN = 0x08007000, LENGTH =  4K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    STATE_RAM   : ORIGIN = 0x20002000, LENGTH = 16K
+}
+
+SECTIONS
+{
+    /* the sparse table is the load data, the only part of the state taking flash */
+    .controller_state_sparse : ALIGN(4)
+    {
+        __sicontroller_state = .;
+        KEEP(*(.controller_state_sparse .controller_state_sparse.*));
+    } > CONSTS
+
+    .controller_state (NOLOAD) : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __scontroller_state = .;
+        *(.controller_state .controller_state.*);
+        . = ALIGN(4);
+        __econtroller_state = .;
+    } > STATE_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,67 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+mod state;
+
+use core::mem::MaybeUninit;
+use linker_sections::init_sections;
+use state::{state_word, STATE_WORDS};
+use {defmt_rtt as _, panic_probe as _};
+
+/// Length of the sparse table made by the build script.
+const TABLE_LEN: usize = include_bytes!(concat!(env!("OUT_DIR"), "/controller_state.sparse")).len();
+
+/// Sparse table aligned to the word it is read by.
+#[repr(C, align(4))]
+struct SparseTable([u8; TABLE_LEN]);
+
+#[allow(unsafe_code)]
+// SAFETY: The table is only read as the section load data
+#[unsafe(link_section = ".controller_state_sparse")]
+#[used]
+static CONTROLLER_STATE_SPARSE: SparseTable = SparseTable(*include_bytes!(concat!(
+    env!("OUT_DIR"),
+    "/controller_state.sparse"
+)));
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".controller_state")]
+static mut CONTROLLER_STATE: MaybeUninit<[u32; STATE_WORDS]> = MaybeUninit::uninit();
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The state is zeroed and its nonzero words are written from the table
+    init_sections!(controller_state sparse);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+    defmt::info!(
+        "controller state takes {} bytes of flash instead of {}",
+        TABLE_LEN,
+        STATE_WORDS * 4
+    );
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable, initialized in pre-init
+    let state = unsafe { &*core::ptr::addr_of!(CONTROLLER_STATE).cast::<[u32; STATE_WORDS]>() };
+
+    // Check whether the state got initialized
+    for (index, &word) in state.iter().enumerate() {
+        defmt::assert_eq!(word, state_word(index));
+    }
+    defmt::assert!(TABLE_LEN < STATE_WORDS * 4 / 8);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
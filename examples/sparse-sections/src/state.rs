@@ -0,0 +1,16 @@
+// Controller state defaults shared by the build script encoding them and the firmware checking
+// them.
+
+/// Controller state length in words, 16 KiB.
+pub const STATE_WORDS: usize = 4096;
+
+/// Controller channel length in words, the state holds 205 channels.
+const CHANNEL_WORDS: usize = 20;
+
+/// Returns the state word at `index`, the channels are zeroed except for their gain.
+pub const fn state_word(index: usize) -> u32 {
+    match index % CHANNEL_WORDS {
+        0 => 0x0001_0000 | (index / CHANNEL_WORDS) as u32,
+        _ => 0,
+    }
+}
@@ -0,0 +1,9 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+}
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,66 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{paint_stack, stack_watermark};
+use {defmt_rtt as _, panic_probe as _};
+
+const PATTERN: u32 = 0xCCCC_CCCC;
+
+#[allow(unsafe_code)]
+unsafe extern "C" {
+    static _stack_start: u32;
+    static _stack_end: u32;
+}
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // `cortex-m-rt` stack grows down from `_stack_start` to `_stack_end`
+    paint_stack!(_stack_end, _stack_start, PATTERN);
+}
+
+/// Returns peak stack usage in bytes since boot
+fn stack_usage() -> usize {
+    #[allow(unsafe_code)]
+    // SAFETY: The stack region is given by `cortex-m-rt` symbols and it is readable
+    unsafe {
+        stack_watermark(
+            core::ptr::addr_of!(_stack_end),
+            core::ptr::addr_of!(_stack_start),
+            PATTERN,
+        )
+    }
+}
+
+/// Consumes some stack, so the usage grows
+#[inline(never)]
+fn recurse(depth: u32) -> u32 {
+    let buffer = core::hint::black_box([depth; 16]);
+
+    if depth == 0 {
+        buffer[0]
+    } else {
+        recurse(depth - 1) + buffer[15]
+    }
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started, stack usage {} bytes", stack_usage());
+
+    let before = stack_usage();
+    defmt::info!("sum {}", recurse(8));
+    let after = stack_usage();
+    defmt::info!("stack usage {} bytes after recursion", after);
+
+    // Check whether the recursion got measured
+    defmt::assert!(after > before);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -2,32 +2,17 @@
 #![no_main]
 #![deny(unsafe_code)]
 
-use linker_sections::init_sections;
+use linker_sections::{provide_pre_init_sections, section_static};
 use static_cell::StaticCell;
 use {defmt_rtt as _, panic_probe as _};
 
 const INITIAL_VALUE: u32 = 0xDEAD_BEEF;
 
-#[allow(unsafe_code)]
-// SAFETY:
-// - Using static mut just to force compiler not to optimize it out in
-//   this simple example
-// - linker section gets initialized because of using `linker_sections`
-#[unsafe(link_section = ".custom_data")]
-static STATIC_ARRAY_A: StaticCell<u32> = StaticCell::new();
-
-#[allow(unsafe_code)]
-// SAFETY:
-// - Using static mut just to force compiler not to optimize it out in
-//   this simple example
-// - linker section gets initialized because of using `linker_sections`
-#[unsafe(link_section = ".custom_data")]
-static STATIC_ARRAY_B: StaticCell<[u32; 256]> = StaticCell::new();
-
-#[cortex_m_rt::pre_init]
-unsafe fn pre_init() {
-    init_sections!(custom_data);
-}
+// The `StaticCell` state is valid once `.custom_data` gets initialized by `linker_sections`
+section_static!(".custom_data", STATIC_ARRAY_A: StaticCell<u32> = StaticCell::new());
+section_static!(".custom_data", STATIC_ARRAY_B: StaticCell<[u32; 256]> = StaticCell::new());
+
+provide_pre_init_sections!(custom_data);
 
 #[cortex_m_rt::entry]
 fn main() -> ! {
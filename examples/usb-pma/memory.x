@@ -0,0 +1,26 @@
+MEMORY
+{
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 62K
+    CONSTS      : ORIGIN = 0x0800F800, LENGTH =  2K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH = 16K
+    /* STM32F1 USB packet memory, 512 bytes seen by the CPU as halfwords in 32-bit slots */
+    USB_PMA     : ORIGIN = 0x40006000, LENGTH =  1K
+}
+
+SECTIONS
+{
+    .usb_pma : ALIGN(4)
+    {
+        __susb_pma = .;
+        KEEP(*(.usb_pma .usb_pma.*));
+        . = ALIGN(2);
+        /* the halfwords are spread into 32-bit slots, so the section takes twice its data */
+        . += . - __susb_pma;
+        __eusb_pma = .;
+    } > USB_PMA AT>CONSTS
+    __siusb_pma = LOADADDR(.usb_pma);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,61 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_sections;
+use {defmt_rtt as _, panic_probe as _};
+
+/// Buffer descriptor table, i.e. address and count of transmission and reception buffer of
+/// endpoint 0, as consecutive halfwords.
+const BTABLE: [u16; 4] = [0x0040, 0x0000, 0x0080, 0x8400];
+
+/// RCC APB1 peripheral clock enable register of STM32F1.
+const RCC_APB1ENR: *mut u32 = 0x4002_101C as *mut u32;
+
+/// USB peripheral clock enable bit, the packet memory is not accessible without the clock.
+const RCC_APB1ENR_USBEN: u32 = 1 << 23;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - The static is never accessed directly, the packet memory is read by 16-bit accesses to
+//   32-bit slots below
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".usb_pma")]
+#[used]
+static mut USB_BTABLE: [u16; 4] = BTABLE;
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    #[allow(unsafe_code)]
+    // SAFETY: Nothing else accesses the RCC before the main
+    unsafe {
+        RCC_APB1ENR.write_volatile(RCC_APB1ENR.read_volatile() | RCC_APB1ENR_USBEN);
+    }
+
+    // each halfword of `.usb_pma` is written by 16-bit access into its own 32-bit slot
+    init_sections!(usb_pma: pma stride 2);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: The packet memory is accessed by 16-bit reads within the section only
+    let btable: [u16; 4] = core::array::from_fn(|i| unsafe {
+        core::ptr::addr_of!(USB_BTABLE)
+            .cast::<u16>()
+            .add(i * 2)
+            .read_volatile()
+    });
+
+    // Check whether the table got initialized in the packet memory layout
+    defmt::assert_eq!(btable, BTABLE);
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
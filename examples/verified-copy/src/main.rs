@@ -0,0 +1,47 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{init_sections, VerifiedFlag};
+use {defmt_rtt as _, panic_probe as _};
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".custom_data")]
+static mut STATIC_ARRAY: [u32; 16] = [0xDEAD_BEEF; 16];
+
+/// Set once the section matched its load data, placed in `.uninit`, so the runtime does not
+/// overwrite it after pre-init
+#[allow(unsafe_code)]
+#[unsafe(link_section = ".uninit.verified")]
+static CUSTOM_DATA_VERIFIED: VerifiedFlag = VerifiedFlag::new();
+
+#[allow(unsafe_code)]
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // A mismatch is reported by a breakpoint, with its address in r1
+    init_sections!(custom_data verify(CUSTOM_DATA_VERIFIED));
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    // Check whether the section got compared with its load data
+    defmt::assert!(CUSTOM_DATA_VERIFIED.is_verified());
+
+    #[allow(unsafe_code)]
+    // SAFETY: This is the only place accessing that static mut variable
+    let array = unsafe { core::ptr::addr_of!(STATIC_ARRAY).read_volatile() };
+    defmt::assert!(array.iter().all(|&word| word == 0xDEAD_BEEF));
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
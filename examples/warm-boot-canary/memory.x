@@ -0,0 +1,31 @@
+MEMORY
+{
+    /* STM32F401, the mirror RAM standing in for an external SDRAM */
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 96K
+    CONSTS      : ORIGIN = 0x08018000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    MIRROR_RAM  : ORIGIN = 0x20002000, LENGTH = 32K
+}
+
+SECTIONS
+{
+    .asset_mirror : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sasset_mirror = .;
+        *(.asset_mirror .asset_mirror.*);
+        . = ALIGN(4);
+        __easset_mirror = .;
+    } > MIRROR_RAM AT>CONSTS
+    __siasset_mirror = LOADADDR(.asset_mirror);
+
+    /* the canary cell at __easset_mirror, neither copied nor initialized by the runtime */
+    .asset_mirror_canary (NOLOAD) : ALIGN(4)
+    {
+        . += 4;
+    } > MIRROR_RAM
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,89 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::{clear_canary, init_sections};
+use {defmt_rtt as _, panic_probe as _};
+
+const ASSET_VALUE: u32 = 0x5A5A_A5A5;
+const ASSET_WORDS: usize = 7936;
+
+/// Written into the mirror by the cold boot, read back by the warm one.
+const BOOT_MARK: u32 = 0xB007_C0DE;
+
+/// RCC clock control and status register of STM32F4, its reset flags and the flag removal bit.
+const RCC_CSR: *mut u32 = 0x4002_3874 as *mut u32;
+const RCC_CSR_RMVF: u32 = 1 << 24;
+const RCC_CSR_BORRSTF: u32 = 1 << 25;
+const RCC_CSR_PORRSTF: u32 = 1 << 27;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".asset_mirror")]
+static mut ASSET_MIRROR: [u32; ASSET_WORDS] = [ASSET_VALUE; ASSET_WORDS];
+
+/// Returns `true` if the core was reset by the power-on or the brown-out, the flags are kept
+/// until removed by the main.
+fn cold_boot() -> bool {
+    #[allow(unsafe_code)]
+    // SAFETY: The status register is only read
+    let csr = unsafe { RCC_CSR.read_volatile() };
+
+    csr & (RCC_CSR_PORRSTF | RCC_CSR_BORRSTF) != 0
+}
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    // The canary cell holds anything at power-up, so the mirror is not trusted then
+    if cold_boot() {
+        clear_canary!(asset_mirror);
+    }
+
+    // The mirror is copied on the cold boot only, kept over the watchdog or software resets
+    init_sections!(asset_mirror canary);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    defmt::info!("main started");
+
+    let cold = cold_boot();
+
+    #[allow(unsafe_code)]
+    // SAFETY: The reset flags are removed once read, the other bits are written back unchanged
+    unsafe {
+        RCC_CSR.write_volatile(RCC_CSR.read_volatile() | RCC_CSR_RMVF);
+    }
+
+    #[allow(unsafe_code)]
+    // SAFETY: Only the main accesses the static
+    let mirror = unsafe { &mut *core::ptr::addr_of_mut!(ASSET_MIRROR) };
+
+    if cold {
+        defmt::info!("cold boot, mirror copied");
+
+        // Check whether the section got initialized
+        defmt::assert!(mirror.iter().all(|&word| word == ASSET_VALUE));
+
+        // Mark the mirror, the mark survives the reset unless the copy runs again
+        mirror[0] = BOOT_MARK;
+        cortex_m::asm::delay(16_000_000);
+        cortex_m::peripheral::SCB::sys_reset();
+    }
+
+    defmt::info!("warm boot, mirror kept");
+
+    // Check the mirror got kept as left by the cold boot
+    defmt::assert_eq!(mirror[0], BOOT_MARK);
+    defmt::assert!(mirror[1..].iter().all(|&word| word == ASSET_VALUE));
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // End in an infinite loop
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,25 @@
+MEMORY
+{
+    /* STM32F401 */
+    FLASH       : ORIGIN = 0x08000000, LENGTH = 96K
+    CONSTS      : ORIGIN = 0x08018000, LENGTH = 32K
+    STACK       : ORIGIN = 0x20000000, LENGTH =  4K
+    RAM         : ORIGIN = 0x20001000, LENGTH =  4K
+    ASSETS_RAM  : ORIGIN = 0x20002000, LENGTH = 32K
+}
+
+SECTIONS
+{
+    .assets : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __sassets = .;
+        *(.assets .assets.*);
+        . = ALIGN(4);
+        __eassets = .;
+    } > ASSETS_RAM AT>CONSTS
+    __siassets = LOADADDR(.assets);
+} INSERT AFTER .uninit;
+
+_stack_start = ORIGIN(STACK) + LENGTH(STACK);
+_stack_end = ORIGIN(STACK);
@@ -0,0 +1,91 @@
+#![no_std]
+#![no_main]
+#![deny(unsafe_code)]
+
+use linker_sections::init_sections_with_feed;
+use {defmt_rtt as _, panic_probe as _};
+
+const ASSET_VALUE: u32 = 0x5A5A_A5A5;
+const ASSET_WORDS: usize = 8192;
+
+/// IWDG registers of STM32F4.
+const IWDG_KR: *mut u32 = 0x4000_3000 as *mut u32;
+const IWDG_PR: *mut u32 = 0x4000_3004 as *mut u32;
+const IWDG_RLR: *mut u32 = 0x4000_3008 as *mut u32;
+const IWDG_SR: *const u32 = 0x4000_300C as *const u32;
+
+/// IWDG keys starting the watchdog, unlocking its registers and reloading its counter.
+const IWDG_KEY_START: u32 = 0xCCCC;
+const IWDG_KEY_UNLOCK: u32 = 0x5555;
+const IWDG_KEY_RELOAD: u32 = 0xAAAA;
+
+/// Prescaler of 4 and reload of 7 make the timeout of 1 ms with 32 kHz LSI, much shorter than
+/// the copy of the whole section.
+const IWDG_PRESCALER_4: u32 = 0;
+const IWDG_RELOAD: u32 = 7;
+
+/// RCC clock control and status register of STM32F4, its IWDG reset flag.
+const RCC_CSR: *const u32 = 0x4002_3874 as *const u32;
+const RCC_CSR_IWDGRSTF: u32 = 1 << 29;
+
+#[allow(unsafe_code)]
+// SAFETY:
+// - Using static mut just to force compiler not to optimize it out in
+//   this simple example
+// - linker section gets initialized because of using `linker_sections`
+#[unsafe(link_section = ".assets")]
+static mut ASSETS: [u32; ASSET_WORDS] = [ASSET_VALUE; ASSET_WORDS];
+
+/// Reloads the watchdog counter.
+fn feed_iwdg() {
+    #[allow(unsafe_code)]
+    // SAFETY: The key register is write-only and the reload key has no other effect
+    unsafe {
+        IWDG_KR.write_volatile(IWDG_KEY_RELOAD);
+    }
+}
+
+#[cortex_m_rt::pre_init]
+unsafe fn pre_init() {
+    #[allow(unsafe_code)]
+    // SAFETY: Nothing else accesses the IWDG before the main
+    unsafe {
+        // Once started, the watchdog could not be stopped until reset, as if enabled by option bytes
+        IWDG_KR.write_volatile(IWDG_KEY_START);
+        IWDG_KR.write_volatile(IWDG_KEY_UNLOCK);
+        IWDG_PR.write_volatile(IWDG_PRESCALER_4);
+        IWDG_RLR.write_volatile(IWDG_RELOAD);
+        while IWDG_SR.read_volatile() != 0 {}
+    }
+
+    // The watchdog is fed every 256 words, well within its timeout
+    init_sections_with_feed!(every 256 words with feed_iwdg; assets);
+}
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    feed_iwdg();
+    defmt::info!("main started");
+
+    #[allow(unsafe_code)]
+    // SAFETY: The status register is only read
+    let watchdog_reset = unsafe { RCC_CSR.read_volatile() } & RCC_CSR_IWDGRSTF != 0;
+
+    // Check the watchdog has not reset the core during the copy
+    defmt::assert!(!watchdog_reset);
+
+    #[allow(unsafe_code)]
+    // SAFETY: Only the main accesses the static
+    let assets = unsafe { &*core::ptr::addr_of!(ASSETS) };
+
+    // Check whether the section got initialized
+    defmt::assert!(assets.iter().all(|&word| word == ASSET_VALUE));
+
+    // We have not paniced on assert
+    defmt::info!("asserts ok");
+
+    // The watchdog could not be stopped, so it is fed forever
+    loop {
+        feed_iwdg();
+    }
+}
@@ -0,0 +1,275 @@
+//! Procedural macros for the `linker-sections` crate. Use them through `linker-sections` with
+//! `macros` feature enabled rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Ident, ItemStatic, LitInt, LitStr, Token,
+};
+
+/// Places the static into given linker section.
+///
+/// The section name is given without the leading dot, as it is passed to
+/// `linker_sections::init_sections!`.
+///
+/// ```
+/// #[in_section("custom_data")]
+/// static mut STATIC_VARIABLE: u32 = INITIAL_VALUE;
+/// ```
+///
+/// expands to
+///
+/// ```
+/// #[unsafe(link_section = ".custom_data")]
+/// static mut STATIC_VARIABLE: u32 = INITIAL_VALUE;
+/// ```
+///
+/// If the static shall be kept even if it is not referenced, add `keep`, which adds `#[used]`
+/// attribute.
+///
+/// ```
+/// #[in_section("custom_data", keep)]
+/// static mut STATIC_VARIABLE: u32 = INITIAL_VALUE;
+/// ```
+#[proc_macro_attribute]
+pub fn in_section(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as InSectionArgs);
+    let item = parse_macro_input!(input as ItemStatic);
+
+    let link_section = format!(".{}", args.section.value());
+    let used = args.keep.then(|| quote!(#[used]));
+
+    quote!(
+        #[unsafe(link_section = #link_section)]
+        #used
+        #item
+    )
+    .into()
+}
+
+/// Declares linker section, generating both its initialization function and its linker script
+/// fragment.
+///
+/// ```
+/// declare_section! {
+///     name: custom_data,
+///     vma: DATA,
+///     lma: FLASH,
+///     align: 4,
+///     insert: before .uninit,
+/// }
+/// ```
+///
+/// expands to
+///
+/// ```
+/// pub const CUSTOM_DATA_LD: &str = "...";
+///
+/// pub unsafe fn init_custom_data() {
+///     linker_sections::init_sections!(custom_data);
+/// }
+/// ```
+///
+/// where `CUSTOM_DATA_LD` contains the linker script fragment defining section `.custom_data` with
+/// `__scustom_data`, `__ecustom_data` and `__sicustom_data` symbols as expected by
+/// `init_sections!`. The `align` defaults to 4 and the `insert` is optional.
+#[proc_macro]
+pub fn declare_section(input: TokenStream) -> TokenStream {
+    let section = parse_macro_input!(input as SectionDeclaration);
+
+    let name = &section.name;
+    let ld_const = Ident::new(
+        &format!("{}_LD", name.to_string().to_uppercase()),
+        name.span(),
+    );
+    let init_fn = Ident::new(&format!("init_{name}"), name.span());
+    let ld = section.linker_script();
+    let ld_doc = format!("Linker script fragment of `.{name}` section");
+    let init_doc = format!("Initializes `.{name}` section memory");
+
+    quote!(
+        #[doc = #ld_doc]
+        #[allow(dead_code)]
+        pub const #ld_const: &str = #ld;
+
+        #[doc = #init_doc]
+        #[allow(dead_code)]
+        pub unsafe fn #init_fn() {
+            ::linker_sections::init_sections!(#name);
+        }
+    )
+    .into()
+}
+
+struct SectionDeclaration {
+    name: Ident,
+    vma: Ident,
+    lma: Ident,
+    align: u32,
+    insert: Option<(Ident, Ident)>,
+}
+
+impl SectionDeclaration {
+    /// Returns linker script fragment with the symbol layout expected by `init_sections!`.
+    fn linker_script(&self) -> String {
+        let Self {
+            name,
+            vma,
+            lma,
+            align,
+            insert,
+        } = self;
+
+        let mut ld = format!(
+            "SECTIONS\n\
+             {{\n\
+             \x20   .{name} : ALIGN({align})\n\
+             \x20   {{\n\
+             \x20       . = ALIGN({align});\n\
+             \x20       __s{name} = .;\n\
+             \x20       *(.{name} .{name}.*);\n\
+             \x20       . = ALIGN({align});\n\
+             \x20       __e{name} = .;\n\
+             \x20   }} > {vma} AT>{lma}\n\
+             \x20   __si{name} = LOADADDR(.{name});\n\
+             }}"
+        );
+
+        if let Some((position, section)) = insert {
+            ld += &format!(" INSERT {} .{section}", position.to_string().to_uppercase());
+        }
+
+        ld + ";\n"
+    }
+}
+
+impl Parse for SectionDeclaration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut name = None;
+        let mut vma = None;
+        let mut lma = None;
+        let mut align = None;
+        let mut insert = None;
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+
+            let duplicate = match key.to_string().as_str() {
+                "name" => name.replace(input.parse::<Ident>()?).is_some(),
+                "vma" => vma.replace(input.parse::<Ident>()?).is_some(),
+                "lma" => lma.replace(input.parse::<Ident>()?).is_some(),
+                "align" => {
+                    let value: LitInt = input.parse()?;
+                    let parsed = value.base10_parse::<u32>()?;
+                    if !parsed.is_power_of_two() {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "alignment shall be a power of two",
+                        ));
+                    }
+                    align.replace(parsed).is_some()
+                }
+                "insert" => {
+                    let position: Ident = input.parse()?;
+                    if position != "before" && position != "after" {
+                        return Err(syn::Error::new(
+                            position.span(),
+                            "expected `before` or `after`",
+                        ));
+                    }
+                    input.parse::<Token![.]>()?;
+                    insert
+                        .replace((position, input.parse::<Ident>()?))
+                        .is_some()
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unexpected key `{key}`"),
+                    ))
+                }
+            };
+
+            if duplicate {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("duplicate key `{key}`"),
+                ));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let missing = |key| syn::Error::new(input.span(), format!("missing key `{key}`"));
+
+        Ok(Self {
+            name: name.ok_or_else(|| missing("name"))?,
+            vma: vma.ok_or_else(|| missing("vma"))?,
+            lma: lma.ok_or_else(|| missing("lma"))?,
+            align: align.unwrap_or(4),
+            insert,
+        })
+    }
+}
+
+struct InSectionArgs {
+    section: LitStr,
+    keep: bool,
+}
+
+impl Parse for InSectionArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let section: LitStr = input.parse()?;
+        validate_section_name(&section)?;
+
+        let mut keep = false;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let flag: Ident = input.parse()?;
+            if flag != "keep" || keep {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    format!("unexpected argument `{flag}`"),
+                ));
+            }
+            keep = true;
+        }
+
+        Ok(Self { section, keep })
+    }
+}
+
+/// Checks the section name is a valid identifier, so it could be passed to `init_sections!`.
+fn validate_section_name(section: &LitStr) -> syn::Result<()> {
+    let name = section.value();
+
+    if let Some(stripped) = name.strip_prefix('.') {
+        return Err(syn::Error::new(
+            section.span(),
+            format!("section name shall be given without the leading dot, use \"{stripped}\""),
+        ));
+    }
+
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric());
+
+    if !valid {
+        return Err(syn::Error::new(
+            section.span(),
+            format!("section name `{name}` is not a valid identifier"),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use std::{env, fs, path::PathBuf};
+
+fn main() {
+    // ship the linker script fragment, so the dependents could pass it to the linker by name
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::copy(
+        "linker_sections_table.x",
+        out_dir.join("linker_sections_table.x"),
+    )
+    .unwrap();
+
+    println!("cargo:rustc-link-search={}", out_dir.display());
+
+    // each check is compiled in with its `assert-*` feature, enabled by `asserts` feature too, or
+    // in the debug builds only with `asserts-debug-only` feature, the code tests `cfg(assert_*)`
+    // for the check and `cfg(asserts)` for any of them
+    println!("cargo:rustc-check-cfg=cfg(asserts)");
+    let debug = env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_some();
+    let debug_only = debug && env::var_os("CARGO_FEATURE_ASSERTS_DEBUG_ONLY").is_some();
+    let mut asserts = false;
+
+    for check in ["align", "bounds", "order", "overlap"] {
+        println!("cargo:rustc-check-cfg=cfg(assert_{check})");
+
+        let feature = format!("CARGO_FEATURE_ASSERT_{}", check.to_uppercase());
+        if debug_only || env::var_os(feature).is_some() {
+            println!("cargo:rustc-cfg=assert_{check}");
+            asserts = true;
+        }
+    }
+
+    if asserts {
+        println!("cargo:rustc-cfg=asserts");
+    }
+
+    // the double init guard is compiled in the debug builds only
+    println!("cargo:rustc-check-cfg=cfg(double_init_guard)");
+    if debug && env::var_os("CARGO_FEATURE_DEBUG_DOUBLE_INIT_GUARD").is_some() {
+        println!("cargo:rustc-cfg=double_init_guard");
+    }
+    println!("cargo:rerun-if-changed=linker_sections_table.x");
+    println!("cargo:rerun-if-changed=build.rs");
+}
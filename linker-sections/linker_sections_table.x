@@ -0,0 +1,12 @@
+/* Table of sections registered by `linker_sections::register_section!` */
+SECTIONS
+{
+    .linker_sections_table : ALIGN(4)
+    {
+        . = ALIGN(4);
+        __slinker_sections_table = .;
+        KEEP(*(.linker_sections_table .linker_sections_table.*));
+        . = ALIGN(4);
+        __elinker_sections_table = .;
+    } > FLASH
+} INSERT AFTER .rodata;
@@ -0,0 +1,308 @@
+//! Section initialization times measured by the DWT cycle counter (`bench-dwt` feature) or
+//! SysTick (`bench-systick` feature).
+//!
+//! The cycles spent initializing each section by [`init_sections`](crate::init_sections) are
+//! recorded and could be read after boot by [`report`].
+//!
+//! ```
+//! for (name, cycles) in linker_sections::bench::report().entries() {
+//!     defmt::info!("{} initialized in {} cycles", name, cycles);
+//! }
+//! ```
+//!
+//! The sections are usually initialized in pre-init, where neither `.data` nor `.bss` is
+//! initialized yet and both get initialized afterwards, so the records are kept in plain statics
+//! placed in `.uninit` section, which `cortex-m-rt` never initializes. The records are valid since
+//! the first section initialized after the previous [`report`], at most [`CAPACITY`] sections are
+//! recorded, the following ones are dropped.
+//!
+//! The checksum of a section marked `crc32_verify` or `verify(fletcher32)` is recorded by an
+//! entry of its own named `<section>:crc32` or `<section>:fletcher32`, ahead of the section
+//! entry which still includes it, so both checksums could be compared on the same build:
+//!
+//! ```
+//! for (name, cycles) in linker_sections::bench::report().entries() {
+//!     if name.ends_with(":crc32") || name.ends_with(":fletcher32") {
+//!         defmt::info!("{} checksum computed in {} cycles", name, cycles);
+//!     }
+//! }
+//! ```
+//!
+//! The DWT cycle counter is enabled by the first section initialized, if the core has one. On
+//! cores without DWT cycle counter, e.g. Cortex-M0, nothing is recorded by `bench-dwt` feature.
+//!
+//! The `bench-systick` feature measures by SysTick instead, on any Cortex-M core. The SysTick is
+//! programmed with the greatest reload and clocked by the processor clock before each section
+//! and disabled with cleared registers right after, so the application is free to program it
+//! afterwards. A section is not measured if the SysTick is already running, e.g. reinitialized
+//! by the application. The measurement error bounds are:
+//!  - Both backends count processor clock cycles including a few cycles of the counter register
+//!    accesses, SysTick ones somewhat more as it is programmed for each section.
+//!  - The SysTick wraps every 2^24 cycles and only a single wrap is told by its `COUNTFLAG`, so
+//!    the cycles are exact up to 2^24 - 1, while a longer section is reported short by a
+//!    multiple of 2^24.
+//!
+//! If both features are enabled, the DWT is used on cores having it.
+
+use core::mem::MaybeUninit;
+
+/// Maximum number of sections recorded.
+pub const CAPACITY: usize = 16;
+
+/// Tells the storage holds records made since the last [`report`].
+const MAGIC: u32 = 0x4245_4E43;
+
+/// Records kept across the `.data` and `.bss` initialization.
+struct Storage {
+    magic: u32,
+    len: usize,
+    entries: [(&'static str, u32); CAPACITY],
+}
+
+#[unsafe(link_section = ".uninit.linker_sections_bench")]
+static mut STORAGE: MaybeUninit<Storage> = MaybeUninit::uninit();
+
+/// Section initialization cycle counts returned by [`report`].
+#[derive(Clone, Copy, Debug)]
+pub struct Report {
+    entries: [(&'static str, u32); CAPACITY],
+    len: usize,
+}
+
+impl Report {
+    /// Returns section names and their initialization cycle counts in the order initialized.
+    pub fn entries(&self) -> &[(&'static str, u32)] {
+        &self.entries[..self.len]
+    }
+}
+
+/// Returns the records made since the previous call and clears them.
+///
+/// It shall not be called concurrently with a section initialization, e.g. from an interrupt
+/// handler.
+pub fn report() -> Report {
+    let storage = unsafe { (*core::ptr::addr_of_mut!(STORAGE)).as_mut_ptr() };
+    let mut report = Report {
+        entries: [("", 0); CAPACITY],
+        len: 0,
+    };
+
+    // SAFETY: Only the sections initialization and this function access the storage, the
+    // entries are read only once the magic tells they were written
+    unsafe {
+        if core::ptr::addr_of!((*storage).magic).read_volatile() == MAGIC {
+            report.len = (*storage).len.min(CAPACITY);
+            report.entries = (*storage).entries;
+            core::ptr::addr_of_mut!((*storage).magic).write_volatile(0);
+        }
+    }
+
+    report
+}
+
+/// Records `cycles` spent initializing section `name`.
+pub(crate) fn push(name: &'static str, cycles: u32) {
+    let storage = unsafe { (*core::ptr::addr_of_mut!(STORAGE)).as_mut_ptr() };
+
+    // SAFETY: The sections are initialized by a single context, the storage is reset before the
+    // first record after a report
+    unsafe {
+        if core::ptr::addr_of!((*storage).magic).read_volatile() != MAGIC {
+            (*storage).len = 0;
+            core::ptr::addr_of_mut!((*storage).magic).write_volatile(MAGIC);
+        }
+
+        let len = (*storage).len;
+        if len < CAPACITY {
+            (*storage).entries[len] = (name, cycles);
+            (*storage).len = len + 1;
+        }
+    }
+}
+
+/// Returns the cycle counter enabling it first, `None` if nothing is measured.
+#[inline(always)]
+pub(crate) fn start() -> Option<u32> {
+    backend::start()
+}
+
+/// Records the cycles spent initializing section `name` since `start`.
+#[inline(always)]
+pub(crate) fn record(name: &'static str, start: Option<u32>) {
+    if let Some(start) = start {
+        push(name, backend::elapsed(start));
+    }
+}
+
+/// Returns the cycle counter within the section measured since `start`, `None` if it is not
+/// measured.
+#[inline(always)]
+pub(crate) fn split(start: Option<u32>) -> Option<u32> {
+    start.map(|_| backend::now())
+}
+
+/// Records the cycles spent by the step `name` of a section since `split`, the counter is kept
+/// running for the section.
+#[inline(always)]
+pub(crate) fn record_split(name: &'static str, split: Option<u32>) {
+    if let Some(split) = split {
+        push(name, backend::since(split));
+    }
+}
+
+// ARMv6-M is the only ARM profile without compare-and-swap, so it is told by the atomics
+#[cfg(all(feature = "bench-dwt", target_arch = "arm", target_has_atomic = "32"))]
+use dwt as backend;
+
+#[cfg(all(
+    feature = "bench-systick",
+    target_arch = "arm",
+    not(all(feature = "bench-dwt", target_has_atomic = "32"))
+))]
+use systick as backend;
+
+/// DWT cycle counter of ARMv7-M and ARMv8-M mainline cores.
+#[cfg(all(feature = "bench-dwt", target_arch = "arm", target_has_atomic = "32"))]
+mod dwt {
+    const DEMCR: *mut u32 = 0xE000_EDFC as *mut u32;
+    const DEMCR_TRCENA: u32 = 1 << 24;
+    const DWT_CTRL: *mut u32 = 0xE000_1000 as *mut u32;
+    const DWT_CTRL_CYCCNTENA: u32 = 1;
+    const DWT_CTRL_NOCYCCNT: u32 = 1 << 25;
+    const DWT_CYCCNT: *const u32 = 0xE000_1004 as *const u32;
+
+    #[inline(always)]
+    pub(super) fn start() -> Option<u32> {
+        // SAFETY: The debug registers are accessed by a single context during the initialization
+        unsafe {
+            DEMCR.write_volatile(DEMCR.read_volatile() | DEMCR_TRCENA);
+
+            let ctrl = DWT_CTRL.read_volatile();
+            if ctrl & DWT_CTRL_NOCYCCNT != 0 {
+                return None;
+            }
+            if ctrl & DWT_CTRL_CYCCNTENA == 0 {
+                DWT_CTRL.write_volatile(ctrl | DWT_CTRL_CYCCNTENA);
+            }
+
+            Some(DWT_CYCCNT.read_volatile())
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn elapsed(start: u32) -> u32 {
+        // SAFETY: The counter register is only read
+        unsafe { DWT_CYCCNT.read_volatile() }.wrapping_sub(start)
+    }
+
+    #[inline(always)]
+    pub(super) fn now() -> u32 {
+        // SAFETY: The counter register is only read
+        unsafe { DWT_CYCCNT.read_volatile() }
+    }
+
+    #[inline(always)]
+    pub(super) fn since(split: u32) -> u32 {
+        now().wrapping_sub(split)
+    }
+}
+
+/// SysTick counting down the processor clock, available on all Cortex-M cores.
+#[cfg(all(
+    feature = "bench-systick",
+    target_arch = "arm",
+    not(all(feature = "bench-dwt", target_has_atomic = "32"))
+))]
+mod systick {
+    const SYST_CSR: *mut u32 = 0xE000_E010 as *mut u32;
+    const SYST_CSR_ENABLE: u32 = 1;
+    const SYST_CSR_CLKSOURCE: u32 = 1 << 2;
+    const SYST_CSR_COUNTFLAG: u32 = 1 << 16;
+    const SYST_RVR: *mut u32 = 0xE000_E014 as *mut u32;
+    const SYST_CVR: *mut u32 = 0xE000_E018 as *mut u32;
+
+    /// The greatest 24-bit reload, the counter wraps every `RELOAD + 1` cycles.
+    const RELOAD: u32 = 0x00FF_FFFF;
+
+    #[inline(always)]
+    pub(super) fn start() -> Option<u32> {
+        // SAFETY: The SysTick is accessed by a single context during the initialization
+        unsafe {
+            // a running SysTick belongs to the application, it is not measured by
+            if SYST_CSR.read_volatile() & SYST_CSR_ENABLE != 0 {
+                return None;
+            }
+
+            // writing the current value clears both the counter and the COUNTFLAG
+            SYST_RVR.write_volatile(RELOAD);
+            SYST_CVR.write_volatile(0);
+            SYST_CSR.write_volatile(SYST_CSR_CLKSOURCE | SYST_CSR_ENABLE);
+
+            // the reload is loaded by the first tick after enabled
+            loop {
+                let current = SYST_CVR.read_volatile();
+                if current != 0 {
+                    return Some(current);
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn elapsed(start: u32) -> u32 {
+        // SAFETY: The SysTick is accessed by a single context during the initialization
+        unsafe {
+            // the counter is stopped first, so it cannot wrap between the reads
+            SYST_CSR.write_volatile(SYST_CSR_CLKSOURCE);
+            let end = SYST_CVR.read_volatile();
+            let wrapped = SYST_CSR.read_volatile() & SYST_CSR_COUNTFLAG != 0;
+
+            // left disabled with cleared counter, free to be programmed by the application
+            SYST_CSR.write_volatile(0);
+            SYST_RVR.write_volatile(0);
+            SYST_CVR.write_volatile(0);
+
+            let wraps = if wrapped { RELOAD + 1 } else { 0 };
+            wraps.wrapping_add(start).wrapping_sub(end)
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn now() -> u32 {
+        // SAFETY: The current value register is only read, leaving the COUNTFLAG to `elapsed`
+        unsafe { SYST_CVR.read_volatile() }
+    }
+
+    #[inline(always)]
+    pub(super) fn since(split: u32) -> u32 {
+        // counting down modulo 2^24, a single wrap is accounted for by the mask
+        split.wrapping_sub(now()) & RELOAD
+    }
+}
+
+/// Nothing measured, e.g. on hosts or Cortex-M0 with `bench-dwt` feature only.
+#[cfg(not(any(
+    all(feature = "bench-dwt", target_arch = "arm", target_has_atomic = "32"),
+    all(feature = "bench-systick", target_arch = "arm")
+)))]
+mod backend {
+    #[inline(always)]
+    pub(super) fn start() -> Option<u32> {
+        None
+    }
+
+    #[inline(always)]
+    pub(super) fn elapsed(start: u32) -> u32 {
+        start
+    }
+
+    #[inline(always)]
+    pub(super) fn now() -> u32 {
+        0
+    }
+
+    #[inline(always)]
+    pub(super) fn since(split: u32) -> u32 {
+        split
+    }
+}
@@ -0,0 +1,290 @@
+//! Heatshrink compressed section data (`compress-heatshrink` feature).
+//!
+//! A section marked `compressed(heatshrink)` is initialized by decoding its load data, a blob
+//! made of a header and a heatshrink (LZSS) bit stream, as produced by the heatshrink encoder:
+//!
+//! | offset | size | content                                   |
+//! |--------|------|-------------------------------------------|
+//! | 0      | 4    | decoded length, little endian `u32`       |
+//! | 4      | 4    | encoded stream length, little endian `u32`|
+//! | 8      | 1    | window size as a power of two, 4 to 15    |
+//! | 9      | 1    | lookahead size as a power of two, 3 to 14 |
+//! | 10     | 2    | reserved, zero                            |
+//! | 12     |      | encoded stream                            |
+//!
+//! The window and lookahead sizes the stream was encoded with travel in the header, so a stream
+//! is always decoded with its encoder's settings and a header of other settings, or no header at
+//! all, fails with [`Error::InvalidParameters`] instead of producing garbage. The decoded length
+//! must equal the section length.
+//!
+//! The stream is decoded straight into the section memory in a single pass, the backward
+//! references are resolved from the bytes already decoded there, so no window buffer is needed.
+//! The decoding never reads past the stream nor writes past the section, a malformed blob is
+//! reported by [`Error`] to the handler given by `compressed(heatshrink, <handler>)`, or it panics
+//! if no handler is given.
+//!
+//! Compared to the [`lz4`](crate::lz4) mode, the decoder is smaller and the ratio is similar on
+//! small images, or a bit better with a large window, but the stream is decoded bit by bit, so
+//! the decoding is several times slower per byte than LZ4 copying whole literal runs and matches.
+//! Prefer heatshrink to keep a single codec in flash when it is used by the firmware anyway, or
+//! for small sections, and LZ4 when the boot time matters.
+//!
+//! The blob is produced on the host by `encode_blob`, available with `std` feature, e.g. by a
+//! build script, the same way as described for the `lz4` module:
+//!
+//! ```
+//! let blob = linker_sections::heatshrink::encode_blob(&image, 8, 4);
+//! std::fs::write(out_dir.join("image.hs"), blob).unwrap();
+//! ```
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Blob header size in bytes.
+pub const HEADER_LEN: usize = 12;
+
+/// Malformed heatshrink blob or stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The blob ends in the middle of its header or its stream.
+    Truncated,
+    /// The window or lookahead size is out of range or the reserved header bytes are not zero.
+    InvalidParameters,
+    /// A backward reference refers to data before the decoded start.
+    InvalidOffset,
+    /// The decoded data does not fit the destination.
+    OutputOverrun,
+    /// The decoded length differs from the section length or the data decoded.
+    LengthMismatch,
+}
+
+/// Window and lookahead sizes as powers of two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    /// Window size as a power of two, 4 to 15.
+    pub window_sz2: u8,
+    /// Lookahead size as a power of two, 3 to `window_sz2 - 1`.
+    pub lookahead_sz2: u8,
+}
+
+impl Parameters {
+    /// Returns the parameters if they are supported by heatshrink.
+    pub fn new(window_sz2: u8, lookahead_sz2: u8) -> Result<Self, Error> {
+        if !(4..=15).contains(&window_sz2) || !(3..window_sz2).contains(&lookahead_sz2) {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(Self {
+            window_sz2,
+            lookahead_sz2,
+        })
+    }
+}
+
+/// Reads bits of a stream, most significant bit first.
+struct BitReader<'a> {
+    stream: &'a [u8],
+    bit: usize,
+}
+
+impl BitReader<'_> {
+    /// Returns next `count` bits, at most 16, or `None` if the stream ends before.
+    fn read(&mut self, count: usize) -> Option<usize> {
+        if count > self.stream.len() * 8 - self.bit {
+            return None;
+        }
+
+        let mut value = 0;
+        for _ in 0..count {
+            let byte = self.stream[self.bit / 8];
+            value = (value << 1) | usize::from((byte >> (7 - self.bit % 8)) & 1);
+            self.bit += 1;
+        }
+
+        Some(value)
+    }
+}
+
+/// Decodes heatshrink `stream` encoded with `parameters` into `dst`, returns the number of bytes
+/// decoded.
+///
+/// The stream ends where its bits do not form a whole literal or backward reference, i.e. at
+/// the zero padding of its last byte. Neither the stream is read past its end nor `dst` written
+/// past its end, whatever the stream holds.
+pub fn decode(dst: &mut [u8], stream: &[u8], parameters: Parameters) -> Result<usize, Error> {
+    let window = usize::from(parameters.window_sz2);
+    let lookahead = usize::from(parameters.lookahead_sz2);
+    let mut reader = BitReader { stream, bit: 0 };
+    let mut output = 0;
+
+    while let Some(tag) = reader.read(1) {
+        if tag == 1 {
+            let Some(byte) = reader.read(8) else { break };
+            *dst.get_mut(output).ok_or(Error::OutputOverrun)? = byte as u8;
+            output += 1;
+            continue;
+        }
+
+        let Some(index) = reader.read(window) else {
+            break;
+        };
+        let Some(count) = reader.read(lookahead) else {
+            break;
+        };
+        let (offset, count) = (index + 1, count + 1);
+
+        if offset > output {
+            return Err(Error::InvalidOffset);
+        }
+        if count > dst.len() - output {
+            return Err(Error::OutputOverrun);
+        }
+
+        // byte by byte, the reference may overlap the bytes it produces
+        let end = output + count;
+        while output < end {
+            dst[output] = dst[output - offset];
+            output += 1;
+        }
+    }
+
+    Ok(output)
+}
+
+/// Decodes a blob of `encode_blob` into `dst`, its decoded length must equal the `dst` length.
+pub fn decode_blob(dst: &mut [u8], blob: &[u8]) -> Result<(), Error> {
+    let (decoded, stream, parameters) = blob_header(blob)?;
+    let stream_end = HEADER_LEN.checked_add(stream).ok_or(Error::Truncated)?;
+    let stream = blob.get(HEADER_LEN..stream_end).ok_or(Error::Truncated)?;
+
+    if decoded != dst.len() || decode(dst, stream, parameters)? != dst.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    Ok(())
+}
+
+/// Returns the decoded and the encoded stream length and the parameters held by `blob` header.
+pub(crate) fn blob_header(blob: &[u8]) -> Result<(usize, usize, Parameters), Error> {
+    let header = blob.get(..HEADER_LEN).ok_or(Error::Truncated)?;
+    let word = |offset: usize| {
+        u32::from_le_bytes([
+            header[offset],
+            header[offset + 1],
+            header[offset + 2],
+            header[offset + 3],
+        ]) as usize
+    };
+
+    if header[10] != 0 || header[11] != 0 {
+        return Err(Error::InvalidParameters);
+    }
+    let parameters = Parameters::new(header[8], header[9])?;
+
+    Ok((word(0), word(4), parameters))
+}
+
+/// Returns the greatest encoded stream length of `len` bytes, all of them literals.
+pub const fn stream_bound(len: usize) -> usize {
+    (len * 9).div_ceil(8)
+}
+
+/// Writes bits of a stream, most significant bit first.
+#[cfg(feature = "std")]
+struct BitWriter {
+    stream: Vec<u8>,
+    bit: usize,
+}
+
+#[cfg(feature = "std")]
+impl BitWriter {
+    /// Appends the lowest `count` bits of `value`.
+    fn write(&mut self, value: usize, count: usize) {
+        for shift in (0..count).rev() {
+            if self.bit.is_multiple_of(8) {
+                self.stream.push(0);
+            }
+            let bit = ((value >> shift) & 1) as u8;
+            *self.stream.last_mut().unwrap() |= bit << (7 - self.bit % 8);
+            self.bit += 1;
+        }
+    }
+}
+
+/// Encodes `data` into heatshrink stream of given window and lookahead sizes.
+///
+/// It is a plain greedy encoder searching the whole window, meant for the host, e.g. a build
+/// script. The stream is compatible with the heatshrink decoder of the same parameters.
+///
+/// # Panics
+///
+/// Panics if the parameters are not supported by heatshrink, see [`Parameters::new`].
+#[cfg(feature = "std")]
+pub fn encode(data: &[u8], window_sz2: u8, lookahead_sz2: u8) -> Vec<u8> {
+    let parameters = Parameters::new(window_sz2, lookahead_sz2).expect("invalid parameters");
+    let window = 1 << parameters.window_sz2;
+    let max_count = 1 << parameters.lookahead_sz2;
+
+    // a reference shorter than this takes more bits than the literals
+    let reference_bits = 1 + usize::from(window_sz2) + usize::from(lookahead_sz2);
+    let min_count = reference_bits / 9 + 1;
+
+    let mut writer = BitWriter {
+        stream: Vec::new(),
+        bit: 0,
+    };
+    let mut position = 0;
+
+    while position < data.len() {
+        let longest = max_count.min(data.len() - position);
+        let (offset, count) = (position.saturating_sub(window)..position)
+            .map(|candidate| {
+                let count = (0..longest)
+                    .take_while(|&index| data[candidate + index] == data[position + index])
+                    .count();
+                (position - candidate, count)
+            })
+            .max_by_key(|&(offset, count)| (count, usize::MAX - offset))
+            .unwrap_or((0, 0));
+
+        if count >= min_count {
+            writer.write(0, 1);
+            writer.write(offset - 1, usize::from(window_sz2));
+            writer.write(count - 1, usize::from(lookahead_sz2));
+            position += count;
+        } else {
+            writer.write(1, 1);
+            writer.write(usize::from(data[position]), 8);
+            position += 1;
+        }
+    }
+
+    writer.stream
+}
+
+/// Encodes `data` into a blob of header and heatshrink stream of given window and lookahead
+/// sizes.
+///
+/// # Panics
+///
+/// Panics if the parameters are not supported by heatshrink or `data` is 4 GiB long or longer.
+#[cfg(feature = "std")]
+pub fn encode_blob(data: &[u8], window_sz2: u8, lookahead_sz2: u8) -> Vec<u8> {
+    let stream = encode(data, window_sz2, lookahead_sz2);
+    let len = |len: usize| u32::try_from(len).expect("data too long").to_le_bytes();
+
+    let mut blob = Vec::with_capacity(HEADER_LEN + stream.len());
+    blob.extend(len(data.len()));
+    blob.extend(len(stream.len()));
+    blob.extend([window_sz2, lookahead_sz2, 0, 0]);
+    blob.extend(stream);
+
+    blob
+}
+
+/// Default handler of `compressed(heatshrink)` sections given no handler.
+#[doc(hidden)]
+pub fn failed(error: Error) {
+    let _ = error;
+    panic!("malformed heatshrink section data");
+}
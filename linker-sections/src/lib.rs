@@ -15,6 +15,54 @@
 //!     init_sections!(buffers, sram2, sram3);
 //!     ```
 //!
+//!  - [`pre_init_sections`]
+//!
+//!     Same as [`init_sections`], but defines `#[cortex_m_rt::pre_init]` function (`cortex-m-rt` feature).
+//!
+//!     ```
+//!     pre_init_sections!(buffers, sram2, sram3);
+//!     ```
+//!
+//!  - [`init_sections_once`]
+//!
+//!     Same as [`init_sections`], but initializes the sections at most once since power-up.
+//!
+//!     ```
+//!     let initialized = init_sections_once!(buffers, sram2, sram3);
+//!     ```
+//!
+//!  - [`provide_pre_init_sections`]
+//!
+//!     Same as [`init_sections`], but defines `__pre_init` function (`provide-pre-init` feature).
+//!
+//!     ```
+//!     provide_pre_init_sections!(buffers, sram2, sram3);
+//!     ```
+//!
+//!  - [`init_sections_if`]
+//!
+//!     Same as [`init_sections`], but each section is initialized only if its predicate holds.
+//!
+//!     ```
+//!     init_sections_if!(retained if cold_boot(), buffers if true);
+//!     ```
+//!
+//!  - [`define_sections_init`]
+//!
+//!     Same as [`init_sections`], but only defines a named function to be called later.
+//!
+//!     ```
+//!     define_sections_init!(fn restore_defaults; buffers, sram2, sram3);
+//!     ```
+//!
+//!  - [`define_sections_init_no_stack`]
+//!
+//!     Same as [`define_sections_init`], but the function uses no stack (`no-stack` feature).
+//!
+//!     ```
+//!     define_sections_init_no_stack!(fn init_stack_free; buffers, sram2, sram3);
+//!     ```
+//!
 //!  - [`init_sections_with_prefixes`]
 //!
 //!     Use if you want to specify your section boundary symbols manually.
@@ -27,6 +75,281 @@
 //!     );
 //!     ```
 //!
+//!  - [`init_sections_with_suffixes`]
+//!
+//!     Use if your section boundary symbols are named after the section followed by a suffix.
+//!
+//!     ```
+//!     init_sections_with_suffixes!(buffers(_start, _end, _load), sram2(_start, _end, _load));
+//!     ```
+//!
+//!  - [`init_sections_with_symbols`]
+//!
+//!     Use if your section boundary symbols do not follow any prefix convention.
+//!
+//!     ```
+//!     init_sections_with_symbols!(
+//!         buffers(_buffers_begin, _buffers_end, _buffers_flash),
+//!         sram2(_sram2_begin, _sram2_end, _sram2_flash)
+//!     );
+//!     ```
+//!
+//!  - [`init_sections_gnu`]
+//!
+//!     Use if your section boundary symbols follow GCC naming, e.g. `__data_start__`.
+//!
+//!     ```
+//!     init_sections_gnu!(cmsis data, ram2_data, zero bss);
+//!     ```
+//!
+//!  - [`init_section_partial`]
+//!
+//!     Use if only the beginning of your section given by a runtime length shall be initialized.
+//!
+//!     ```
+//!     init_section_partial!(config, config_valid_len());
+//!     ```
+//!
+//!  - [`init_sections_with_len`]
+//!
+//!     Use if your section is defined by its length symbol, e.g. `SIZEOF(.section)`, instead of end.
+//!
+//!     ```
+//!     init_sections_with_len!(buffers(__s, __l, __si), sram2(__s, __l, __si));
+//!     ```
+//!
+//!  - [`init_sections_fragments`]
+//!
+//!     Use if the load data of your section are split into multiple fragments, e.g. across flash banks.
+//!
+//!     ```
+//!     init_sections_fragments!(buffers[__sibuffers0..__eibuffers0, __sibuffers1..__eibuffers1]);
+//!     ```
+//!
+//!  - [`init_sections_raw`]
+//!
+//!     Use if your section boundary symbols are not valid rust identifiers.
+//!
+//!     ```
+//!     init_sections_raw!("__s.ccm.data", "__e.ccm.data", "__si.ccm.data");
+//!     ```
+//!
+//!  - [`init_region!`]
+//!
+//!     Use if your memory region is given by fixed addresses instead of linker symbols.
+//!
+//!     ```
+//!     init_region!(0x2000_4000, 0x2000_8000, 0x0801_0000);
+//!     ```
+//!
+//!  - [`register_section`] and [`init_registered_sections`]
+//!
+//!     Use if you want to register your sections independently in different modules.
+//!
+//!     ```
+//!     register_section!(buffers);
+//!     register_section!(sram2(__s, __e, __si));
+//!
+//!     init_registered_sections!();
+//!     ```
+//!
+//!  - [`defer_sections_init`]
+//!
+//!     Use if your section memory is not ready in pre-init, e.g. an external SDRAM.
+//!
+//!     ```
+//!     let sdram = defer_sections_init!(sdram_data, sdram_heap);
+//!     unsafe { sdram.run() };
+//!     ```
+//!
+//!  - [`init_sections_with_copier`]
+//!
+//!     Use if your sections shall be copied by DMA after the clocks are configured.
+//!
+//!     ```
+//!     init_sections_with_copier!(&mut dma; sdram_data, sdram_heap);
+//!     ```
+//!
+//!  - [`init_sections_with_feed`]
+//!
+//!     Use if a watchdog which cannot be paused shall be fed while your large sections are copied.
+//!
+//!     ```
+//!     init_sections_with_feed!(every 4096 words with feed_iwdg; sdram_assets);
+//!     ```
+//!
+//!  - [`try_init_sections`]
+//!
+//!     Use if a malformed section shall be recorded for a later report instead of failing in pre-init.
+//!
+//!     ```
+//!     try_init_sections!(INIT_ERROR; custom_data, buffers);
+//!     ```
+//!
+//!  - [`init_sections_from_nor`]
+//!
+//!     Use if your section load data is in an external NOR flash (`nor-flash` feature).
+//!
+//!     ```
+//!     init_sections_from_nor!(&mut spi_nor; sdram_assets at 0x10_0000)?;
+//!     ```
+//!
+//!  - [`set_flash_bounds`]
+//!
+//!     Use if your section load data shall be checked to lie within the flash (`memory-bounds` feature).
+//!
+//!     ```
+//!     set_flash_bounds!(0x0800_0000, 0x0810_0000);
+//!     ```
+//!
+//!  - [`set_ram_bounds`]
+//!
+//!     Use if your section memory shall be checked to lie within the RAM banks (`memory-bounds` feature).
+//!
+//!     ```
+//!     set_ram_bounds!(0x2000_0000, 0x2002_0000; 0x1000_0000, 0x1001_0000);
+//!     ```
+//!
+//!  - [`set_failure_handler`]
+//!
+//!     Use if a malformed section shall be reported by your handler instead of a breakpoint (`failure-handler` feature).
+//!
+//!     ```
+//!     set_failure_handler!(record_and_reset);
+//!     ```
+//!
+//!  - [`relocate_sections`]
+//!
+//!     Use if your sections are moved within RAM holding their load image, e.g. in a bootloader.
+//!
+//!     ```
+//!     relocate_sections!(data, fast_data);
+//!     ```
+//!
+//!  - [`section`]
+//!
+//!     Use if you need the section bounds later, e.g. for checksumming or wiping the section.
+//!
+//!     ```
+//!     let buffers = section!(buffers);
+//!     unsafe { buffers.init() };
+//!     ```
+//!
+//!  - [`reinit_section`]
+//!
+//!     Use if you want to restore default values of your section at runtime.
+//!
+//!     ```
+//!     let restored_bytes = reinit_section!(calibration);
+//!     ```
+//!
+//!  - [`init_section_table`]
+//!
+//!     Use if your linker script emits a table of section boundaries, saves flash for many sections.
+//!
+//!     ```
+//!     init_section_table!(__ssection_init_table, __esection_init_table);
+//!     ```
+//!
+//!  - [`init_cmsis_tables`]
+//!
+//!     Use if your linker script emits CMSIS copy and zero tables, e.g. `__copy_table_start__`.
+//!
+//!     ```
+//!     init_cmsis_tables!();
+//!     ```
+//!
+//!  - [`zero_sections`]
+//!
+//!     Use this macro if your `NOLOAD` section only needs to be zeroed and is defined using symbols
+//!     - `__s<section>` for section VMA start,
+//!     - `__e<section>` for section VMA end.
+//!
+//!     ```
+//!     zero_sections!(fast_bss, dma_bss);
+//!     ```
+//!
+//!  - [`zero_sections_with_prefixes`]
+//!
+//!     Use if you want to specify boundary symbols of your zeroed section manually.
+//!
+//!     ```
+//!     zero_sections_with_prefixes!(bss2(__s, __e), dma_bss(__s, __e));
+//!     ```
+//!
+//!  - [`fill_sections`]
+//!
+//!     Use this macro to fill your sections with a pattern, e.g. to catch use-before-init bugs.
+//!
+//!     ```
+//!     fill_sections!(0xA5A5_A5A5; buffers, scratch);
+//!     ```
+//!
+//!  - [`paint_stack`] and [`stack_watermark`]
+//!
+//!     Use to fill your stack with a pattern at boot and measure peak stack usage later.
+//!
+//!     ```
+//!     paint_stack!(_stack_end, _stack_start, 0xCCCC_CCCC);
+//!     ```
+//!
+//!  - [`keep_section_symbols`]
+//!
+//!     Use if your section shall survive `--gc-sections` even if nothing references it.
+//!
+//!     ```
+//!     keep_section_symbols!(custom_data, lookup_tables);
+//!     ```
+//!
+//!  - [`declare_noinit_section`]
+//!
+//!     Use if your section shall never be touched at boot, e.g. crash logs or reboot counters.
+//!
+//!     ```
+//!     declare_noinit_section!(crash_log: [u8; 1024]);
+//!     ```
+//!
+//!  - [`persistent_section`]
+//!
+//!     Use if your section retains its content across resets, defaults are loaded on CRC mismatch.
+//!
+//!     ```
+//!     persistent_section!(settings);
+//!     unsafe { settings().init() };
+//!     ```
+//!
+//!  - [`section_static`]
+//!
+//!     Use to place a static, e.g. a `StaticCell`, into your section (`static-cell` feature).
+//!
+//!     ```
+//!     section_static!(".custom_data", BUFFERS: StaticCell<[u32; 256]> = StaticCell::new());
+//!     ```
+//!
+//!  - [`heap_section`] and [`init_heap`]
+//!
+//!     Use if your heap is backed by a dedicated section (`alloc` feature).
+//!
+//!     ```
+//!     init_heap!(HEAP, heap_region);
+//!     ```
+//!
+//! With `macros` feature enabled, the [`in_section`] attribute could be used to place statics into
+//! a section given by the same name as passed to the macros above.
+//!
+//! ```
+//! #[in_section("custom_data")]
+//! static mut STATIC_VARIABLE: u32 = INITIAL_VALUE;
+//! ```
+//!
+//! The [`declare_section`] macro (`macros` feature) declares a section by a single declaration
+//! generating both its initialization function and its linker script fragment, which could be
+//! written to a linker script by `build.rs`.
+//!
+//! ```
+//! declare_section! { name: custom_data, vma: DATA, lma: FLASH, insert: before .uninit }
+//! ```
+//!
 //! # Example
 //!
 //! Simple example defines a section `.custom_data` with start at 4-byte aligned `__scustom_data`
@@ -86,16 +409,179 @@
 //! }
 //! ```
 //!
+//! # Fast copy
+//!
+//! With `fast-copy` feature enabled on ARM targets, the aligned words of copied sections are moved
+//! by `ldmia`/`stmia` bursts of 8 words, the rest word by word. The bursts use Thumb-2 encoding,
+//! so the feature requires ARMv7-M or ARMv8-M Mainline, e.g. Cortex-M3, M4, M7 or M33. The
+//! registers clobbered by the bursts are declared to the compiler, which saves the callee-saved
+//! ones. The `fast-copy` example measures the copy in DWT cycles, run it with and without the
+//! feature to compare.
+//!
+//! # Boot time
+//!
+//! With `bench-dwt` feature enabled, the DWT cycles spent initializing each section by
+//! [`init_sections`] and its variants are recorded and could be read after boot by
+//! `bench::report()`, see the `bench` module and the `bench-dwt` example. Nothing is recorded on
+//! cores without DWT cycle counter, e.g. Cortex-M0.
+//!
+//! With `bench-systick` feature enabled, the cycles are counted by SysTick instead, which is
+//! available on Cortex-M0/M0+ too, and reported by the same `bench::report()`. The SysTick is
+//! disabled and cleared once each section is measured, so it is free to be programmed by the
+//! application. The DWT is preferred on cores having it if both features are enabled.
+//!
+//! # Overlapping regions
+//!
+//! With `overlap-ok` feature enabled, the load data of a copied section or region may overlap its
+//! memory, e.g. a blob relocated within the same RAM bank by [`init_region()`]. The overlapping
+//! regions are copied word by word away from the overlap, i.e. by ascending addresses if the
+//! destination is below the source and by descending ones otherwise, as `core::ptr::copy` does.
+//! Nothing is copied if the source and destination are the same. The copies of given width only
+//! proceed by ascending addresses, so with `asserts` feature enabled they reject the destination
+//! inside the source only.
+//!
+//! # Running from RAM
+//!
+//! If the firmware is loaded directly into RAM by a debugger, or it executes fully from RAM, the
+//! load address of a copied section may equal its run address. Such a section is left as is,
+//! neither copied nor rejected as an overlap with `asserts` feature enabled. Mind the data is
+//! then only initialized by the debugger's load, so a reset without reloading keeps the values
+//! written at run time, unlike the same firmware booted from flash.
+//!
+//! # Post-copy verification
+//!
+//! With `verify` feature enabled, the sections marked `verify` by [`init_sections`] are read back
+//! after the copy and compared with their load data, e.g. to demonstrate the RAM image matches
+//! its flash source in a safety-relevant product. The aligned body is compared word by word by
+//! volatile reads, the unaligned head and tail bytes one by one, with no `memcmp` called, which
+//! might rely on initialized RAM as `memcpy` might. The first mismatch is reported with its
+//! address and both values, by the failure handler with `failure-handler` feature enabled, see
+//! [Failed asserts](crate#failed-asserts).
+//!
+//! With `crc32-verify` feature enabled, the sections marked `crc32_verify` are checked by their
+//! CRC32 instead, with no load data read back, e.g. of the decompressed sections. The CRC is
+//! computed bitwise with no table, by the reflected IEEE 802.3 polynomial `0xEDB8_8320`, and
+//! compared with the `u32` stored at the `__crc<section>` symbol. The value comes from the build,
+//! computed by [`crc32`] of the `std` feature over the section image: a build script could emit
+//! it into a linker script fragment, see the `crc32-sections` example, or a post-link tool could
+//! patch a word reserved in the load region.
+//!
+//! ```ld
+//! SECTIONS {
+//!   .crc : {
+//!     . = ALIGN(4);
+//!     __crccustom_data = .;
+//!     LONG(0x1C291CA3);
+//!   } > FLASH
+//! }
+//! ```
+//!
+//! With `fletcher32-verify` feature enabled, the sections marked `verify(fletcher32)` are checked
+//! by their Fletcher-32 instead, stored at the `__fletcher<section>` symbol and computed by
+//! [`fletcher32`] in the build. It sums halfwords, a couple of additions each with a reduction
+//! once per 359 of them, rather than the eight shift and XOR steps per byte of the bitwise
+//! CRC32, so it is several times faster. It still detects the copy faults, stuck or flipped
+//! bits, though weaker than CRC32 against reordered data. With `bench-dwt` or `bench-systick`
+//! feature enabled, the cycles of each checksum are reported by `bench::report()` as
+//! `<section>:crc32` or `<section>:fletcher32`, see the `checksum-bench` example.
+//!
+//! # Failed asserts
+//!
+//! The sections are checked with `asserts` feature enabled, in all builds. With
+//! `asserts-debug-only` feature enabled instead, they are checked in the builds with
+//! `debug_assertions` only, e.g. of the `dev` profile, and the checks compile away in the release
+//! builds, so the same workspace features serve both.
+//!
+//! The `asserts` feature enables all the checks, each could be enabled on its own instead:
+//!
+//! - `assert-align`: the section addresses are aligned to the access width, the copied sections
+//!   are aligned to the word equally to their load data, see [`InitError::AlignmentMismatch`],
+//!   and with `strict-alignment` feature they are word aligned too. With `strict-length` feature
+//!   the copied sections are whole words long, so a section end missing its trailing `ALIGN` in
+//!   the linker script fails instead of copying the tail bytes one by one.
+//! - `assert-order`: the section end does not precede its start.
+//! - `assert-overlap`: the copied section memory does not overlap its load data, unless
+//!   `overlap-ok` feature is enabled.
+//! - `assert-bounds`: the strided sections, the fragments, the tables, the sparse table words and
+//!   the NOR flash regions fit the memory given. The sections are checked against the flash and
+//!   RAM declared by [`set_flash_bounds`] and [`set_ram_bounds`] by `memory-bounds` feature, not
+//!   by this one.
+//!
+//! The flash cost of the checks on the `demo` example, built in the `release` profile of
+//! `thumbv7em-none-eabi` target with 10652 bytes of code with no checks:
+//!
+//! | feature          | code bytes |
+//! |------------------|-----------:|
+//! | `assert-align`   |       +240 |
+//! | `assert-order`   |       +188 |
+//! | `assert-overlap` |       +180 |
+//! | `assert-bounds`  |         +0 |
+//! | `asserts`        |       +312 |
+//!
+//! The checks share the failure path, so the costs do not add up, and `assert-bounds` costs
+//! nothing to the sections the `demo` initializes, none of them strided, fragmented or in a table.
+//!
+//! With `asserts` feature enabled on ARM targets, a malformed section found in pre-init is
+//! reported with no panic, which would pull in `core::fmt` code and could rely on the statics not
+//! initialized yet. The [`InitError::code`] is written to `r0`, the [`InitError::address`] to
+//! `r1` and both to the scratch memory given by [`set_failure_scratch`], if any, then `bkpt` is
+//! executed forever, halting the attached debugger or escalating to the HardFault otherwise, with
+//! `r0` and `r1` stacked in its exception frame. The out-of-bounds sections of `memory-bounds`
+//! feature given no handler are reported the same way.
+//!
+//! With `asserts-panic` feature enabled, it panics with the [`InitError`] message instead, as it
+//! does on other targets, e.g. in the host tests. With `failure-handler` feature enabled, the
+//! error is passed to the handler given by [`set_failure_handler`].
+//!
+//! With `semihosting-errors` feature enabled on ARM targets, a message naming the section, if
+//! known, the failed check and its addresses is printed to the debugger console by semihosting
+//! before the breakpoint, e.g.
+//!
+//! ```text
+//! linker-sections: section `sram2` failed: section end 0x20010000 precedes its start 0x20010400
+//! ```
+//!
+//! The feature is meant for the bring-up on the bench only, as the semihosting call hard faults
+//! with no debugger attached, and it pulls in the `core::fmt` code the default path avoids. It
+//! cannot be combined with `asserts-panic` feature. The section name is known to the sections
+//! copied by [`init_sections`] and its variants, the other checks report no name.
+//!
+//! # Double initialization
+//!
+//! With `debug-double-init-guard` feature enabled, the sections initialized by [`init_sections`]
+//! and its variants are recorded by their start, and an attempt to initialize a section again,
+//! e.g. reached from pre-init and from a board init helper both, is reported as
+//! [`InitError::DoubleInit`] the same way as a failed assert. The guard is active in the builds
+//! with `debug_assertions` only and compiles away otherwise, as it does with the feature disabled.
+//!
+//! The record is kept in `.uninit.linker_sections_guard` section, so it is not cleared by the
+//! runtime zeroing `.bss` after pre-init, and it is cleared by [`init_guard_reset`] instead, which
+//! shall be called first in pre-init, before any section is initialized. The pre-init functions
+//! defined by [`pre_init_sections`] and [`provide_pre_init_sections`] call it. The sections are
+//! initialized on purpose again by [`reinit_section`], which is not guarded, and the first 32
+//! sections only are recorded.
+//!
 //! # Safety
 //!
-//! - The symbols must be 4-byte aligned.
+//! - The symbols of zeroed or filled sections must be aligned to [`Word`], i.e. 4-byte aligned on
+//!   32-bit targets and 8-byte aligned on 64-bit targets. A copied section could start and end at
+//!   any address, the bytes not forming aligned words are copied byte by byte, with
+//!   `strict-alignment` feature enabled its start and load address are asserted to be word
+//!   aligned, with `strict-length` feature enabled its length is asserted to be whole words.
 //! - The symbols must point to memory with required access (read, write).
 //! - The symbols must represent continuos memory.
+//! - The sections could be copied by `memcpy` and zeroed by `memset` of the linked runtime, e.g.
+//!   `__aeabi_memcpy` on ARM, which must not rely on initialized RAM then, e.g. a vendor `memcpy`
+//!   reading its configuration from `.data`. With `no-memcpy` feature enabled, the sections are
+//!   copied, zeroed and filled by volatile loops inlined into the crate code only, which the
+//!   compiler never lowers to `memcpy` or `memset` calls, see the `no-memcpy` example.
 //!
 //! # Limitations
 //!
-//! - Each section's name shall be a valid rust function name, but it does not have to be snake_case.
-//! - Only one macro can be called and it can be called at most once.
+//! - Each section's name shall be a valid rust identifier, but it does not have to be snake_case.
+//!   Use [`init_sections_raw`] for symbols which are not valid rust identifiers.
+//! - Each section can be listed at most once in a single macro call, otherwise compile error
+//!   ``section `<section>` listed twice`` is emitted.
 
 #![no_std]
 
@@ -104,20 +590,126 @@ pub extern crate with_builtin_macros;
 #[doc(hidden)]
 pub use with_builtin_macros::{with_builtin, with_eager_expansions};
 
-#[macro_export]
-/// Defines pre-init function initializing linker section memory.
+#[cfg(feature = "cortex-m-rt")]
+#[doc(hidden)]
+pub extern crate cortex_m_rt;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(double_init_guard)]
+use core::sync::atomic::AtomicUsize;
+use core::{
+    mem::ManuallyDrop,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+};
+
+/// Asserts `$condition` of a section if the check, `align`, `bounds`, `order` or `overlap`, is
+/// enabled, reports `$error` by [`init_failed`] if it does not hold.
+#[cfg(asserts)]
+macro_rules! init_assert {
+    (align, $($assert:tt)+) => {
+        #[cfg(assert_align)]
+        init_assert!($($assert)+);
+    };
+    (bounds, $($assert:tt)+) => {
+        #[cfg(assert_bounds)]
+        init_assert!($($assert)+);
+    };
+    (order, $($assert:tt)+) => {
+        #[cfg(assert_order)]
+        init_assert!($($assert)+);
+    };
+    (overlap, $($assert:tt)+) => {
+        #[cfg(assert_overlap)]
+        init_assert!($($assert)+);
+    };
+    ($condition:expr, $error:expr$(,)?) => {
+        if !$condition {
+            $crate::init_failed($error);
+        }
+    };
+}
+
+#[cfg(feature = "macros")]
+pub use linker_sections_macros::{declare_section, in_section};
+
+#[cfg(any(feature = "bench-dwt", feature = "bench-systick"))]
+pub mod bench;
+#[cfg(feature = "compress-heatshrink")]
+pub mod heatshrink;
+#[cfg(feature = "compress-lz4")]
+pub mod lz4;
+#[cfg(feature = "nor-flash")]
+pub mod nor;
+#[cfg(feature = "compress-rle")]
+pub mod rle;
+#[cfg(feature = "sparse-init")]
+pub mod sparse;
+
+/// Memory word the sections are copied by, `u64` on 64-bit targets and `u32` otherwise.
 ///
-/// This macro accepts linker section names as arguments and assumes the linker symbols are named
-/// after given section names prefixed with
-///  - `__s` for section VMA's start (usually points to RAM)
-///  - `__e` for section VMA's end (usually points to RAM)
-///  - `__si` for section LMA's start (usually points to FLASH)
+/// The section addresses shall be aligned to it for the sections to be copied word by word.
+#[cfg(target_pointer_width = "64")]
+pub type Word = u64;
+/// Memory word the sections are copied by, `u64` on 64-bit targets and `u32` otherwise.
 ///
-/// If the symbols in the linker script are named `__scustom_data`, `__ecustom_data` and `__sicustom_data`,
-/// as depicted in an example below, the macro call should be
+/// The section addresses shall be aligned to it for the sections to be copied word by word.
+#[cfg(not(target_pointer_width = "64"))]
+pub type Word = u32;
+
+/// Linker symbol type, the section boundary symbols are declared as extern statics of it.
 ///
-/// ```
-/// init_sections!(custom_data)
+/// It is zero-sized, so no object is claimed at the symbol address, the symbol only gives the
+/// address to the pointers made by [`symbol_ptr`] and [`symbol_ptr_mut`].
+#[doc(hidden)]
+#[repr(C)]
+pub struct Symbol {
+    _address: [u8; 0],
+}
+
+/// Returns pointer to the memory at `symbol`'s address with the provenance exposed by it.
+///
+/// A symbol denotes a memory region start or end rather than an object, so the pointer is
+/// re-materialized from the exposed address and the lengths are computed on integers.
+#[doc(hidden)]
+#[inline(always)]
+pub fn symbol_ptr<T>(symbol: *const Symbol) -> *const T {
+    core::ptr::with_exposed_provenance(symbol.expose_provenance())
+}
+
+/// Returns mutable pointer to the memory at `symbol`'s address, see [`symbol_ptr`].
+#[doc(hidden)]
+#[inline(always)]
+pub fn symbol_ptr_mut<T>(symbol: *mut Symbol) -> *mut T {
+    core::ptr::with_exposed_provenance_mut(symbol.expose_provenance())
+}
+
+/// Size of [`Word`] in bytes.
+const WORD: usize = core::mem::size_of::<Word>();
+
+/// Number of times a word of a section with `verify_writes` is written again if it does not read
+/// back, unless given by the section, see [`init_sections`].
+pub const VERIFY_WRITE_RETRIES: usize = 3;
+
+/// Canary word telling a section marked `canary` was initialized and kept since, unless given by
+/// the section, see [`init_sections`].
+pub const CANARY: Word = 0xC0DE_5AFE;
+
+#[macro_export]
+/// Defines pre-init function initializing linker section memory.
+///
+/// This macro accepts linker section names as arguments and assumes the linker symbols are named
+/// after given section names prefixed with
+///  - `__s` for section VMA's start (usually points to RAM)
+///  - `__e` for section VMA's end (usually points to RAM)
+///  - `__si` for section LMA's start (usually points to FLASH)
+///
+/// If the symbols in the linker script are named `__scustom_data`, `__ecustom_data` and `__sicustom_data`,
+/// as depicted in an example below, the macro call should be
+///
+/// ```
+/// init_sections!(custom_data)
 /// ```
 ///
 /// ```text
@@ -155,9 +747,574 @@ pub use with_builtin_macros::{with_builtin, with_eager_expansions};
 /// ```
 /// init_sections!(section_a section_b section_c);
 /// ```
+///
+/// Sections with custom symbol prefixes could be mixed with the default ones, see
+/// [`init_sections_with_prefixes`]. The sections are initialized in the given order.
+///
+/// ```
+/// init_sections!(custom_data, weird_section(_beg, _end, _rom));
+/// ```
+///
+/// Attributes, e.g. `#[cfg]`, could be placed before a section and they are applied to all the
+/// code generated for that section
+///
+/// ```
+/// init_sections!(custom_data, #[cfg(feature = "big-board")] sram4);
+/// ```
+///
+/// The macro could be called multiple times, even in the same scope
+///
+/// ```
+/// init_sections!(section_a);
+/// init_sections!(section_b);
+/// ```
+///
+/// Sections which may be missing in the linker script, e.g. on some board revisions, could be
+/// marked `optional`. The boundary symbols of such section are declared weak, so they resolve to
+/// zero if not defined and the section is skipped at runtime if it is empty.
+///
+/// ```
+/// init_sections!(custom_data, optional sram4_data);
+/// ```
+///
+/// Alternatively, the symbols could be defined in the linker script by
+///
+/// ```text
+/// PROVIDE(__ssram4_data = 0);
+/// PROVIDE(__esram4_data = 0);
+/// PROVIDE(__sisram4_data = 0);
+/// ```
+///
+/// Note that a section named `optional` has to be separated by a comma from the following one.
+///
+/// The rest of the memory region behind a section could be zeroed after the copy, see
+/// [`init_sections_with_prefixes`].
+///
+/// ```
+/// init_sections!(custom_data zero_to __eregion_data);
+/// ```
+///
+/// By default, the section is copied by the fastest means available, usually 32-bit accesses.
+/// Sections in memory faulting on some access widths, e.g. on a 16-bit external bus, could be
+/// given the copy width by `: u8`, `: u16` or `: u32` following the section name, the section is
+/// then copied by volatile accesses of that width only. With `asserts` feature enabled, the
+/// section boundaries and load address are checked to be aligned to the width.
+///
+/// ```
+/// init_sections!(custom_data, ext_data: u16, weird_fifo: u8);
+/// ```
+///
+/// The `: pma` marker is an alias of `: u16` for peripheral memory tolerating halfword accesses
+/// only, e.g. STM32 USB packet memory or some CAN message RAMs. The accesses are volatile, so the
+/// compiler never merges adjacent halfwords into word accesses, which would bus-fault there.
+///
+/// ```
+/// init_sections!(usb_pma: pma);
+/// ```
+///
+/// Packet memory seen by the CPU with each halfword in a wider slot, e.g. 32-bit slots of STM32F1
+/// USB packet memory, is given `stride` in halfwords following the `: u16` or `: pma` marker. The
+/// section memory holds the strided layout, the load data the consecutive halfwords, the `i`-th
+/// of them is written to the section start plus `i * stride` halfwords, see
+/// [`section_init_strided()`].
+///
+/// ```
+/// init_sections!(usb_pma: pma stride 2, ext_fifo: u16 stride 2);
+/// ```
+///
+/// Sections in ECC protected RAM are given the `: ecc` or `: ecc64` marker. The memory is then
+/// written by volatile full 32-bit or 64-bit words only and never read before, as a partial word
+/// write or a read of a not yet written word computes the ECC over uninitialized memory and raises
+/// an ECC error. With `asserts` feature enabled, the section boundaries and load address are
+/// checked to be aligned to the ECC word, there is no fallback to narrower accesses. The markers
+/// are accepted by [`zero_sections`] and [`fill_sections`] as well. The memories needing it are
+/// e.g.
+///  - STM32H7 AXI SRAM and ITCM, 64-bit ECC words, use `: ecc64`
+///  - STM32H7 DTCM, SRAM1 to SRAM4 and backup SRAM, 32-bit ECC words, use `: ecc`
+///  - i.MX RT1170 OCRAM with ECC enabled, 64-bit ECC words, use `: ecc64`
+///  - i.MX RT1170 DTCM with ECC enabled, 32-bit ECC words, use `: ecc`
+///
+/// ```
+/// init_sections!(axi_data: ecc64, dtcm_data: ecc);
+/// ```
+///
+/// Sections given the `: swap32` marker are copied by 32-bit accesses with the bytes of each word
+/// reversed, so data generated in the other byte order land in the required one in a single pass,
+/// e.g. a memory window shared with a big-endian DSP. It is appropriate only if the whole section
+/// consists of 32-bit words, data of other widths, e.g. halfwords or byte strings, would get
+/// scrambled. A partial word at the section end is left untouched, with `asserts` feature enabled
+/// the section boundaries and load address are checked to be aligned to 32 bits. The `: swap16`
+/// marker does the same for sections of 16-bit entries by volatile halfword accesses, e.g. a
+/// display controller palette RAM expecting big-endian entries, the boundaries are checked to be
+/// aligned to 16 bits, so the length is even.
+///
+/// ```
+/// init_sections!(dsp_window: swap32, palette: swap16);
+/// ```
+///
+/// Sections given the `: widen8to32` marker are copied from packed bytes, possibly not aligned,
+/// by 32-bit stores only, e.g. a table stored packed in flash to save space and copied into
+/// a peripheral SRAM accepting only word writes. The trailing 1 to 3 bytes are written as a final
+/// word padded by zeros, see [`section_init_widen`].
+///
+/// ```
+/// init_sections!(peripheral_tables: widen8to32);
+/// ```
+///
+/// Note that the memory behind the section given by `zero_to` is zeroed by [`Word`] writes.
+///
+/// With `dcache-maintenance` feature enabled, sections marked `clean_dcache`, following the other
+/// markers, have the data cache lines covering them, including the memory zeroed by `zero_to`,
+/// cleaned and invalidated after the copy, followed by a DSB. It is needed on Cortex-M7, e.g.
+/// STM32H7, if the data cache is enabled before the sections are initialized, e.g. by a
+/// bootloader, and the section memory is read by DMA later, which would see stale memory while
+/// the copied data linger in the cache. The lines are rounded to the 32-byte cache line
+/// boundaries, so the lines shared with the neighbouring memory are cleaned as well, which only
+/// writes their dirty data back. Memory not cached, e.g. DTCM, shall not be marked. If the cache
+/// is enabled after the sections are initialized, no maintenance is needed, as enabling it, e.g.
+/// by `SCB::enable_dcache` of `cortex-m`, invalidates it.
+///
+/// ```
+/// init_sections!(axi_data: ecc64 clean_dcache, dtcm_data, sram1_data zero_to __esram1 clean_dcache);
+/// ```
+///
+/// Sections with code, e.g. interrupt handlers run from RAM, are marked `code`, following the
+/// other markers including `clean_dcache`. A DSB and an ISB are issued after the copy, so the
+/// copied instructions are complete and no stale prefetched ones are executed. With
+/// `icache-maintenance` feature enabled, the instruction cache lines covering the section are
+/// invalidated before the ISB, or the whole instruction cache if the section is larger than
+/// 64 KiB. It is needed on Cortex-M7 with the instruction cache enabled, the section in cached
+/// memory shall be marked `clean_dcache` as well, so the instructions are fetched from the memory
+/// and not from the data cache. On cores without cache, e.g. Cortex-M0 or Cortex-M3, only the
+/// barriers are issued.
+///
+/// ```
+/// init_sections!(ramfunc clean_dcache code, itcm_code code);
+/// ```
+///
+/// With `barriers` feature enabled, the barriers are issued once after all the sections of the
+/// macro call are initialized, so the memory written could be consumed by DMA or other bus masters
+/// right away. It is a DSB and an ISB on ARM, a FENCE on RISC-V and a compiler fence only on other
+/// architectures. Sections not needing it are marked `no_barrier`, following the other markers,
+/// the barriers are not issued if all the sections are marked.
+///
+/// ```
+/// init_sections!(dma_descriptors, scratch no_barrier);
+/// ```
+///
+/// Sections in memory which may drop writes, e.g. an external PSRAM during bring-up, could be
+/// given `verify_writes(<handler>)` following the section name or its width marker. Each word is
+/// then read back after the copy and written again up to [`VERIFY_WRITE_RETRIES`] times, or up
+/// to the number given as the second argument, if it does not match. The copy stops at the first
+/// word not matching after all the retries and the handler, a `fn(usize)`, is called with its
+/// address. The words are copied by [`Word`] volatile accesses, unless the width is given, e.g.
+/// `: ecc` writes whole ECC words only. The stride, `in <section>;` clause and inlining hints are
+/// not supported.
+///
+/// ```
+/// fn psram_failed(address: usize) {
+///     panic!("PSRAM write failed at {address:#x}");
+/// }
+///
+/// init_sections!(psram_data verify_writes(psram_failed), sram4: ecc verify_writes(psram_failed, 8));
+/// ```
+///
+/// With `verify` feature enabled, sections marked `verify`, following the width and `zero_to`
+/// markers, are compared with their load data once copied, see
+/// [Post-copy verification](crate#post-copy-verification). The first word not matching is
+/// reported as [`InitError::VerifyMismatch`] the same as a failed assert. Given `verify(<flag>)`,
+/// the [`VerifiedFlag`] records the section matched. Only the sections copied unchanged, with no
+/// width or `: u32`, `: ecc` or `: ecc64`, could be verified, the `in <section>;` clause and
+/// inlining hints are not supported.
+///
+/// ```
+/// #[unsafe(link_section = ".uninit.verified")]
+/// static DATA_VERIFIED: VerifiedFlag = VerifiedFlag::new();
+///
+/// init_sections!(data verify(DATA_VERIFIED), dtcm_data: ecc zero_to __edtcm verify);
+/// ```
+///
+/// With `crc32-verify` feature enabled, sections marked `crc32_verify` instead are checked by the
+/// CRC32 of their memory once copied, compared with the `u32` stored at `__crc<section>`, e.g.
+/// `__crcdata`, see [Post-copy verification](crate#post-copy-verification). The mismatch is
+/// reported as [`InitError::CrcMismatch`] the same as a failed assert. Besides the sections
+/// verified by `verify`, the `compressed(..)` sections could be checked, as the CRC is computed
+/// over the destination only.
+///
+/// ```
+/// init_sections!(data crc32_verify, dtcm_data: ecc zero_to __edtcm crc32_verify);
+/// ```
+///
+/// With `fletcher32-verify` feature enabled, sections marked `verify(fletcher32)` are checked
+/// the same way by the Fletcher-32 of their memory, compared with the `u32` stored at
+/// `__fletcher<section>`, and the mismatch is reported as [`InitError::FletcherMismatch`]. The
+/// symbol names the algorithm, so a build computing the other checksum fails to link rather than
+/// to boot. Mind a [`VerifiedFlag`] named `fletcher32` could not be passed to `verify(..)`.
+///
+/// ```
+/// init_sections!(data verify(fletcher32), dtcm_data compressed(lz4) verify(fletcher32));
+/// ```
+///
+/// With `compress-lz4` feature enabled, sections marked `compressed(lz4)` following the section
+/// name are initialized by decompressing their load data, an LZ4 blob made on the host, see the
+/// `lz4` module for the blob format and a build script recipe. The blob is checked not to be read
+/// nor the section written out of bounds, a malformed one is reported to the handler given as the
+/// second argument, a `fn(lz4::Error)`, or it panics without the handler. The width, the
+/// `in <section>;` clause and inlining hints are not supported.
+///
+/// ```
+/// fn assets_corrupted(error: linker_sections::lz4::Error) {
+///     defmt::error!("UI assets corrupted: {}", defmt::Debug2Format(&error));
+/// }
+///
+/// init_sections!(ui_assets compressed(lz4), fonts compressed(lz4, assets_corrupted));
+/// ```
+///
+/// Sections dominated by long runs of a single byte, e.g. zeroed structs with a few fields set,
+/// could be marked `compressed(rle)` with `compress-rle` feature enabled instead. Their load data
+/// is a run-length encoded blob decoded by far less code, see the `rle` module and the
+/// `rle-sections` example, the handler is a `fn(rle::Error)`.
+///
+/// ```
+/// init_sections!(config_tables compressed(rle));
+/// ```
+///
+/// With `compress-heatshrink` feature enabled, sections could be marked `compressed(heatshrink)`,
+/// their load data is a heatshrink (LZSS) stream with a header of its window and lookahead
+/// sizes, see the `heatshrink` module, the handler is a `fn(heatshrink::Error)`. It takes less
+/// flash for the decoder than LZ4 but decodes several times slower.
+///
+/// ```
+/// init_sections!(log_strings compressed(heatshrink));
+/// ```
+///
+/// Mostly zero sections, e.g. a large state of a few hundreds nonzero default words, could be
+/// marked `sparse` with `sparse-init` feature enabled. The section is zeroed and the words listed
+/// by its load data, a table of offset and value pairs, are written then, see the `sparse`
+/// module. The width, the `in <section>;` clause and inlining hints are not supported.
+///
+/// ```
+/// init_sections!(controller_state sparse);
+/// ```
+///
+/// Sections marked `canary`, following the width, `zero_to` and verification markers, are kept
+/// over a warm reset if still intact, e.g. a large mirror in an external SDRAM. A canary word is
+/// checked before the copy, the section is left as is if it holds the magic, otherwise the
+/// section is initialized and the magic is written afterwards. The canary cell is the word at
+/// `__e<section>`, just past the copied range, or the word at the symbol given by
+/// `canary(at <symbol>)`. The magic is [`CANARY`] unless given by `canary(magic = <expr>)`, it
+/// shall not be zero. With `assert-overlap` feature enabled, the cell is checked to lie outside
+/// the section. The `in <section>;` clause and inlining hints are not supported.
+///
+/// ```
+/// init_sections!(asset_mirror canary, fonts canary(at __fonts_canary, magic = FONTS_V2));
+/// ```
+///
+/// The cell shall live in memory neither initialized by the runtime nor by the load, e.g.
+/// reserved past the section end by the linker script, and the canary shall be cleared by
+/// [`clear_canary`] whenever the memory could hold anything, e.g. on a power-on reset.
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .asset_mirror : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __sasset_mirror = .;
+///         *(.asset_mirror .asset_mirror.*);
+///         . = ALIGN(4);
+///         __easset_mirror = .;
+///     } > SDRAM AT>FLASH
+///     __siasset_mirror = LOADADDR(.asset_mirror);
+///
+///     .asset_mirror_canary (NOLOAD) : ALIGN(4)
+///     {
+///         . += 4;
+///     } > SDRAM
+/// } INSERT AFTER .uninit;
+/// ```
+///
+/// The initialization code could be placed into a section given by `in <section>;` clause, e.g. to
+/// run it from RAM. The code is generated as a single `#[inline(never)]` function placed in the
+/// section, the memory is copied word by word by code inlined into that function. The section
+/// with the code shall be initialized before, so it cannot be listed in the same macro call,
+/// otherwise compile error is emitted.
+///
+/// ```
+/// init_sections!(ramcode);
+/// init_sections!(in ramcode; custom_data, buffers);
+/// ```
+///
+/// Note that the inlining is not guaranteed without optimizations, so build with `opt-level` at
+/// least `1` when the code shall not call into FLASH.
+///
+/// The inlining of the initialization code could be controlled by `@inline_always;` or
+/// `@inline_never;` hint. With `@inline_always`, the code is generated as `#[inline(always)]`
+/// function copying the memory word by word, so no function is called at all. With
+/// `@inline_never`, the code is generated as `#[inline(never)]` function doing the same as the
+/// macro without any hint. The hint could be combined with `in <section>;` clause, except for
+/// `@inline_always`.
+///
+/// ```
+/// init_sections!(@inline_always; custom_data, buffers);
+/// init_sections!(in ramcode; @inline_never; sram2);
+/// ```
+///
+/// With `crc32-verify` feature enabled, the whole flash image could be checked first by
+/// `verify_image(<start>, <end>, <crc>);` clause, which calls [`verify_image`] before any section
+/// is touched. The clause is passed through by the macros defining the pre-init function, e.g.
+/// [`pre_init_sections`], so a partially programmed image is caught ahead of all of their work.
+///
+/// ```
+/// pre_init_sections!(verify_image(__simage, __eimage, __image_crc); in ramcode; custom_data);
+/// ```
 macro_rules! init_sections {
-    ($($section_name:ident$(,)?)+) => {
-        $crate::init_sections_with_prefixes!($($section_name(__s, __e, __si),)*);
+    (@opts[$($opts:tt)*] verify_image($start:ident, $end:ident, $crc:ident$(,)?); $($sections:tt)+) => {{
+        $crate::verify_image!($start, $end, $crc);
+        $crate::init_sections!(@opts[$($opts)*] $($sections)+)
+    }};
+    (@opts[$($opts:tt)*] in $place:ident; $($sections:tt)+) => {
+        $crate::init_sections!(@opts[$($opts)* in $place;] $($sections)+)
+    };
+    (@opts[$($opts:tt)*] @$hint:ident; $($sections:tt)+) => {
+        $crate::init_sections!(@opts[$($opts)* @$hint;] $($sections)+)
+    };
+    (@opts[$($opts:tt)*] $($sections:tt)+) => {
+        $crate::init_sections_with_prefixes!($($opts)* (__s, __e, __si): $($sections)+)
+    };
+    ($($sections:tt)+) => {
+        $crate::init_sections!(@opts[] $($sections)+)
+    };
+}
+
+#[macro_export]
+/// Initializes linker section memory at most once since power-up.
+///
+/// This macro accepts the same arguments as [`init_sections`] and returns `true` if the sections
+/// were initialized by this call or `false` if they were already initialized before, so it could
+/// be called from multiple places, e.g. from pre-init and from a software reset recovery path,
+/// without copying over live data again.
+///
+/// ```
+/// if init_sections_once!(custom_data, calibration) {
+///     defmt::info!("sections initialized");
+/// }
+/// ```
+///
+/// Every macro call has its own guard word placed in `.uninit.linker_sections_once` section, so
+/// the guard lives outside any section being initialized and it is cleared neither by the
+/// runtime on startup nor by a software reset. The `.uninit` output section is provided by
+/// `cortex-m-rt`, when using another runtime, make sure `.uninit.*` input sections are placed
+/// into a `NOLOAD` output section.
+///
+/// The guard word content is undefined at power-up, so there is a tiny chance of 1 in 2^32 it
+/// holds the guard value already and the sections are not initialized.
+macro_rules! init_sections_once {
+    ($($sections:tt)+) => {{
+        mod __linker_sections {
+            #[unsafe(link_section = ".uninit.linker_sections_once")]
+            static GUARD: $crate::OnceGuard = $crate::OnceGuard::new();
+
+            pub(super) fn acquire() -> bool {
+                GUARD.acquire()
+            }
+        }
+
+        if __linker_sections::acquire() {
+            $crate::init_sections!($($sections)+);
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+#[macro_export]
+/// Clears the canary of sections marked `canary`, so they are initialized again.
+///
+/// This macro accepts section names, the canary cells being the words at `__e<section>`, or
+/// canary cell symbols following `at`, the same as given by the `canary(at <symbol>)` markers of
+/// [`init_sections`]. Call it before the sections are initialized whenever their content could
+/// not be trusted, e.g. on a power-on reset, when the canary cells hold random values, or once
+/// the content is known to be corrupt.
+///
+/// ```
+/// if cold_boot {
+///     clear_canary!(asset_mirror, at __fonts_canary);
+/// }
+/// init_sections!(asset_mirror canary, fonts canary(at __fonts_canary), data);
+/// ```
+macro_rules! clear_canary {
+    (@cell $cell:ident) => {{
+        $crate::pointer_mut!($cell);
+
+        unsafe {
+            $crate::canary_clear($crate::symbol_mut!($cell));
+        }
+    }};
+    (at $cell:ident $(, $($rest:tt)*)?) => {
+        $crate::clear_canary!(@cell $cell);
+        $($crate::clear_canary!($($rest)*);)?
+    };
+    ($section_name:ident $(, $($rest:tt)*)?) => {
+        $crate::with_eager_expansions! {
+            $crate::clear_canary!(@cell #{ concat_idents!(__e, $section_name) });
+        }
+        $($crate::clear_canary!($($rest)*);)?
+    };
+    () => {};
+}
+
+#[cfg(feature = "provide-pre-init")]
+#[macro_export]
+/// Defines `__pre_init` function initializing linker section memory.
+///
+/// This macro shall be called at module scope and accepts the same arguments as
+/// [`init_sections`]. The defined function overrides the default `__pre_init` provided by
+/// `cortex-m-rt`, so no other pre-init function is needed. Available with `provide-pre-init`
+/// feature only.
+///
+/// The minimal setup is
+///
+/// ```
+/// use linker_sections::provide_pre_init_sections;
+///
+/// provide_pre_init_sections!(custom_data);
+/// ```
+///
+/// There can be only one `__pre_init` function, defining your own `#[cortex_m_rt::pre_init]`
+/// function as well results in duplicate `__pre_init` symbol error.
+macro_rules! provide_pre_init_sections {
+    ($($sections:tt)+) => {
+        const _: () = {
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn __pre_init() {
+                $crate::init_guard_reset();
+                $crate::init_sections!($($sections)+);
+            }
+        };
+    };
+}
+
+#[macro_export]
+/// Defines named function initializing linker section memory.
+///
+/// This macro shall be called at module scope and accepts function name followed by the same
+/// arguments as [`init_sections`]. Unlike [`init_sections`], the function is only defined, not
+/// called, so it could be called from multiple places, e.g. from pre-init and from a factory reset
+/// handler.
+///
+/// ```
+/// define_sections_init!(fn restore_defaults; custom_data, calibration);
+/// ```
+///
+/// expands to
+///
+/// ```
+/// pub(crate) unsafe fn restore_defaults() {
+///     init_sections!(custom_data, calibration);
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Calling the defined function overwrites the whole sections with their initial values, so
+/// - no reference to any variable placed in the sections shall be alive during the call,
+/// - no other context (e.g. interrupt handler) shall access the sections during the call,
+/// - any state kept in the sections is lost, e.g. already initialized `StaticCell` could be
+///   initialized again resulting in multiple mutable references to the same memory.
+macro_rules! define_sections_init {
+    ($(#[$attr:meta])* fn $fn_name:ident; $($sections:tt)+) => {
+        $(#[$attr])*
+        pub(crate) unsafe fn $fn_name() {
+            $crate::init_sections!($($sections)+);
+        }
+    };
+}
+
+#[cfg(feature = "no-stack")]
+#[macro_export]
+/// Defines named function initializing linker section memory without touching the stack.
+///
+/// This macro shall be called at module scope and accepts function name followed by section names
+/// as [`init_sections`] does, the sections being defined by `__s<section>`, `__e<section>` and
+/// `__si<section>` word aligned symbols. The function is `#[unsafe(naked)]`, so it has neither
+/// prologue nor epilogue, and it copies the sections by [`section_init_no_stack()`], e.g. on parts
+/// whose stack memory is not usable before some pre-init setup. Available with `no-stack`
+/// feature on ARM targets only.
+///
+/// ```
+/// define_sections_init_no_stack!(fn init_stack_free; custom_data, calibration);
+/// ```
+///
+/// The defined function follows the register contract of [`section_init_no_stack()`], except it
+/// changes `r12` as well, keeping its return address there. Neither the optional sections nor
+/// the other modifiers of [`init_sections`] are supported. Defined as `__pre_init` with
+/// `#[unsafe(no_mangle)]` attribute, it replaces the default pre-init function of `cortex-m-rt`.
+///
+/// ```
+/// define_sections_init_no_stack!(#[unsafe(no_mangle)] fn __pre_init; custom_data);
+/// ```
+///
+/// # Safety
+///
+/// Same as for [`define_sections_init`].
+macro_rules! define_sections_init_no_stack {
+    ($(#[$attr:meta])* fn $fn_name:ident; $($section_name:ident),+ $(,)?) => {
+        const _: () = {
+            $crate::sections_unique!([$([$section_name])+] $);
+        };
+
+        $(#[$attr])*
+        #[unsafe(naked)]
+        pub(crate) unsafe extern "C" fn $fn_name() {
+            core::arch::naked_asm!(
+                // the return address is kept in r12, which the copy routine does not change
+                "mov r12, lr",
+                $(
+                    concat!("ldr r0, =__s", stringify!($section_name)),
+                    concat!("ldr r1, =__e", stringify!($section_name)),
+                    concat!("ldr r2, =__si", stringify!($section_name)),
+                    "bl {init}",
+                )+
+                "bx r12",
+                init = sym $crate::section_init_no_stack,
+            );
+        }
+    };
+}
+
+#[cfg(feature = "cortex-m-rt")]
+#[macro_export]
+/// Defines `#[cortex_m_rt::pre_init]` function initializing linker section memory.
+///
+/// This macro shall be called at module scope and accepts the same arguments as
+/// [`init_sections`]. Available with `cortex-m-rt` feature only.
+///
+/// ```
+/// pre_init_sections!(custom_data_a, custom_data_b);
+/// ```
+///
+/// expands to
+///
+/// ```
+/// #[cortex_m_rt::pre_init]
+/// unsafe fn __pre_init_sections() {
+///     init_sections!(custom_data_a, custom_data_b);
+/// }
+/// ```
+///
+/// There can be only one pre-init function, defining your own `#[cortex_m_rt::pre_init]` function
+/// as well results in compile error due to `__pre_init` symbol being already defined.
+macro_rules! pre_init_sections {
+    ($($sections:tt)+) => {
+        const _: () = {
+            #[$crate::cortex_m_rt::pre_init]
+            unsafe fn __pre_init_sections() {
+                $crate::init_guard_reset();
+                $crate::init_sections!($($sections)+);
+            }
+        };
     };
 }
 
@@ -213,89 +1370,11065 @@ macro_rules! init_sections {
 ///     section_c(__s __e __si)
 /// );
 /// ```
-macro_rules! init_sections_with_prefixes {
-    ($($section_name:ident($beg:ident,$end:ident,$src:ident)$(,)?)+) => {
-        fn __init_sections() {$(
-            $crate::section_init_with_prefixes!($section_name($beg, $end, $src));
-            $section_name();
-        )*}
-
-        __init_sections();
-    };
-}
-
-#[macro_export]
-#[doc(hidden)]
-macro_rules! section_init_with_prefixes {
-    ($section_name:ident($beg:ident, $end:ident, $src:ident)) => {
-        #[allow(non_snake_case)]
-        fn $section_name() {
-            $crate::with_eager_expansions! { $crate::pointer_mut!( #{ concat_idents!($beg, $section_name) } ) };
-            $crate::with_eager_expansions! { $crate::pointer!( #{ concat_idents!($end, $section_name) } ) };
-            $crate::with_eager_expansions! { $crate::pointer!( #{ concat_idents!($src, $section_name) } ) };
-
-            let src: *const u32 = core::ptr::addr_of!(
-                $crate::with_builtin! { let $name = concat_idents!($src, $section_name) in { $name } }
-            );
-            let dst: *mut u32 = core::ptr::addr_of_mut!(
-                $crate::with_builtin! { let $name = concat_idents!($beg, $section_name) in { $name } }
-            );
-            let end: *const u32 = core::ptr::addr_of!(
-                $crate::with_builtin! { let $name = concat_idents!($end, $section_name) in { $name } }
-            );
-
-            unsafe { $crate::section_init(dst, end, src); }
-        }
-    };
-}
+///
+/// Sections sharing the same prefixes could be grouped as shown below. The group prefixes apply to
+/// every following section without its own prefixes until the next group.
+///
+/// ```
+/// init_sections_with_prefixes!((__start_, __stop_, __load_): sram2, sram3, buffers);
+/// ```
+/// ```
+/// init_sections_with_prefixes!(
+///     (__start_, __stop_, __load_): sram2, sram3,
+///     (__s, __e, __si): section_a section_b,
+///     section_c(__s, __e, __si),
+/// );
+/// ```
+///
+/// The initialization code could be placed into a section and its inlining could be controlled the
+/// same way as for [`init_sections`].
+///
+/// ```
+/// init_sections_with_prefixes!(in ramcode; section_a(__s, __e, __si));
+/// init_sections_with_prefixes!(@inline_always; section_b(__s, __e, __si));
+/// ```
+///
+/// If the section occupies only a part of a memory region, the rest of the region could be zeroed
+/// after the copy by `zero_to` followed by the region end symbol. The memory from the section end
+/// up to the given symbol is zeroed then, with `asserts` feature enabled it is checked the region
+/// end does not precede the section end.
+///
+/// ```
+/// init_sections_with_prefixes!(custom_data(__s, __e, __si) zero_to __eregion_data);
+/// init_sections!(custom_data zero_to __eregion_data, buffers);
+/// ```
+macro_rules! init_sections_with_prefixes {
+    (@{() ()}[$($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?)*]) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
 
-#[macro_export]
-#[doc(hidden)]
-macro_rules! pointer {
-    ($name:ident) => {
-        unsafe extern "C" {
-            static $name: u32;
+        $(
+            $crate::section_init_with_prefixes!($(#[$attr])* $section_name($beg, $end, $src$(, $optional)?) $(@width($($width)+))? $(@zero_to($tail))? $(@verify($($verify)+))? $(@canary($($canary)+))? $(@clean_dcache($dcache))? $(@code($code))? $(@no_barrier($no_barrier))?);
+        )*
+        $crate::sections_barrier!([$([$($no_barrier)?])*]);
+    }};
+    (@{($place:ident) (always)}[$($done:tt)*]) => {
+        compile_error!("`@inline_always` cannot be combined with `in <section>`");
+    };
+    (@{($place:ident) $inline:tt}[$($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?)*]) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+        $crate::sections_not_placed_in!($place [$([$(#[$attr])* $section_name])*] $);
+
+        mod __linker_sections {
+            #[unsafe(link_section = concat!(".", stringify!($place)))]
+            #[inline(never)]
+            pub(super) fn init() {
+                $(
+                    $crate::init_sections_with_prefixes!(@section $(#[$attr])* $section_name($beg, $end, $src$(, $optional)?) $(@width($($width)+))? $(@zero_to($tail))? $(@verify($($verify)+))? $(@canary($($canary)+))? $(@clean_dcache($dcache))? $(@code($code))?);
+                )*
+            }
+        }
+
+        __linker_sections::init();
+        $crate::sections_barrier!([$([$($no_barrier)?])*]);
+    }};
+    (@{() (always)}[$($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?)*]) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        mod __linker_sections {
+            #[inline(always)]
+            pub(super) fn init() {
+                $(
+                    $crate::init_sections_with_prefixes!(@section $(#[$attr])* $section_name($beg, $end, $src$(, $optional)?) $(@width($($width)+))? $(@zero_to($tail))? $(@verify($($verify)+))? $(@canary($($canary)+))? $(@clean_dcache($dcache))? $(@code($code))?);
+                )*
+            }
+        }
+
+        __linker_sections::init();
+        $crate::sections_barrier!([$([$($no_barrier)?])*]);
+    }};
+    (@{() (never)}[$($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?)*]) => {{
+        mod __linker_sections {
+            #[inline(never)]
+            pub(super) fn init() {
+                $crate::init_sections_with_prefixes!(@{() ()}[$($(#[$attr])* $section_name($beg, $end, $src$(, $optional)?) $(@width($($width)+))? $(@zero_to($tail))? $(@verify($($verify)+))? $(@canary($($canary)+))? $(@clean_dcache($dcache))? $(@code($code))? $(@no_barrier($no_barrier))?)*]);
+            }
+        }
+
+        __linker_sections::init();
+    }};
+    (@section $(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))?) => {
+        $(#[$attr])*
+        {
+            let bench = $crate::section_bench_start();
+            let section: $crate::Section = $crate::section_with_prefixes!($section_name($beg, $end, $src$(, $optional)?));
+
+            $crate::section_copy!(in_place section(stringify!($section_name)) [$($optional)?] [$($($width)+)?]);
+            $crate::section_verify!(in_place section(stringify!($section_name)) [$($($width)+)?] [$($($verify)+)?]);
+            $crate::section_canary!(in_place [$($($canary)+)?]);
+            $crate::section_zero_tail!(section [$($tail)?] [$($optional)?]);
+            $crate::section_clean_dcache!(section [$($tail)?] [$($dcache)?]);
+            $crate::section_sync_code!(section [$($tail)?] [$($code)?]);
+            $crate::section_bench_record(stringify!($section_name), bench);
         }
     };
+    (@{$($opts:tt)*}[$($done:tt)*] , $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)*] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] : $width:ident stride $stride:literal $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @width($width stride $stride)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] : $width:ident verify_writes($($verify:tt)+) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @width($width verify_writes($($verify)+))] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] verify_writes($($verify:tt)+) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @width(word verify_writes($($verify)+))] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] : $width:ident $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @width($width)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] compressed($codec:ident$(, $handler:path)?$(,)?) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @width(compressed($codec$(, $handler)?))] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] sparse $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @width(sparse)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] crc32_verify $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @verify(crc32_verify)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] verify(fletcher32) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @verify(fletcher32_verify)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] verify($flag:path) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @verify(verify $flag)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] verify $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @verify(verify)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] canary($($canary:tt)*) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @canary(canary($($canary)*))] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] canary $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @canary(canary)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] clean_dcache $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @clean_dcache(clean_dcache)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] code $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @code(code)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] no_barrier $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @no_barrier(no_barrier)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] zero_to $tail:ident $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* @zero_to($tail)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] $(#[$attr:meta])* optional $section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* $(#[$attr])* $section_name($beg, $end, $src, optional)] $($rest)*)
+    };
+    (@{$($opts:tt)*}[$($done:tt)*] $(#[$attr:meta])* $section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[$($done)* $(#[$attr])* $section_name($beg, $end, $src)] $($rest)*)
+    };
+    (@opts{() $inline:tt} in $place:ident; $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@opts{($place) $inline} $($rest)*)
+    };
+    (@opts{$place:tt ()} @inline_always; $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@opts{$place (always)} $($rest)*)
+    };
+    (@opts{$place:tt ()} @inline_never; $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@opts{$place (never)} $($rest)*)
+    };
+    (@opts{$place:tt $inline:tt} $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$place $inline}()[] $($rest)*)
+    };
+    // the sections of each prefix group are given its prefixes, the markers are kept as they are
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*]) => {
+        $crate::init_sections_with_prefixes!(@{$($opts)*}[] $($done)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] ($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?): $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}($beg, $end, $src)[$($done)*] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] $name:ident($($args:tt)*) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* $name($($args)*)] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] : $width:ident stride $stride:literal $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* : $width stride $stride] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] : $width:ident $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* : $width] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] zero_to $tail:ident $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* zero_to $tail] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] sparse $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* sparse] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] crc32_verify $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* crc32_verify] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] verify $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* verify] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] canary $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* canary] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] clean_dcache $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* clean_dcache] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] code $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* code] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] no_barrier $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* no_barrier] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] optional $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* optional] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}($beg:ident, $end:ident, $src:ident)[$($done:tt)*] $section_name:ident $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}($beg, $end, $src)[$($done)* $section_name($beg, $end, $src)] $($rest)*)
+    };
+    (@groups{$($opts:tt)*}$group:tt[$($done:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{$($opts)*}$group[$($done)* $token] $($rest)*)
+    };
+    (in $place:ident; $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@opts{() ()} in $place; $($rest)*)
+    };
+    (@inline_always; $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@opts{() ()} @inline_always; $($rest)*)
+    };
+    (@inline_never; $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@opts{() ()} @inline_never; $($rest)*)
+    };
+    ($section_name:ident $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{() ()}()[] $section_name $($rest)*)
+    };
+    (#[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{() ()}()[] #[$($attr)*] $($rest)*)
+    };
+    (($($prefixes:tt)*) $($rest:tt)*) => {
+        $crate::init_sections_with_prefixes!(@groups{() ()}()[] ($($prefixes)*) $($rest)*)
+    };
 }
 
 #[macro_export]
-#[doc(hidden)]
-macro_rules! pointer_mut {
-    ($name:ident) => {
-        unsafe extern "C" {
-            static mut $name: u32;
-        }
-    };
+/// Defines pre-init function initializing linker section memory.
+///
+/// This macro accepts linker section names and symbol suffixes as arguments. The symbol names are
+/// formed by the section name followed by the suffix.
+///
+/// If the symbols in the linker script are named `custom_data_start`, `custom_data_end` and
+/// `custom_data_load`, as depicted in an example below, the macro call should be
+///
+/// ```
+/// init_sections_with_suffixes!(custom_data(_start, _end, _load))
+/// ```
+///
+/// ```text
+/// MEMORY
+/// {
+///     FLASH   : ORIGIN = 0x08000000, LENGTH = 32K
+///     RAM     : ORIGIN = 0x20000000, LENGTH = 16K
+///     DATA    : ORIGIN = 0x20004000, LENGTH = 16K
+/// }
+///
+/// SECTIONS
+/// {
+///     .custom_data : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         custom_data_start = .;
+///         *(.custom_data .custom_data.*);
+///
+///         . = ALIGN(4);
+///         custom_data_end = .;
+///     } > DATA AT>FLASH
+///
+///     custom_data_load = LOADADDR(.custom_data);
+/// } INSERT BEFORE .uninit;
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// init_sections_with_suffixes!(section_a(_start, _end, _load), section_b(_start, _end, _load));
+/// ```
+/// ```
+/// init_sections_with_suffixes!(
+///     section_a(_start, _end, _load)
+///     section_b(_start, _end, _load),
+/// );
+/// ```
+macro_rules! init_sections_with_suffixes {
+    ($($(#[$attr:meta])* $section_name:ident($beg:ident,$end:ident,$src:ident)$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $(
+            $crate::section_init_with_suffixes!($(#[$attr])* $section_name($beg, $end, $src));
+        )*
+    }};
 }
 
-#[doc(hidden)]
-pub unsafe fn section_init(dst: *mut u32, end: *const u32, src: *const u32) {
-    // not using defmt::asserts since defmt is not initialized at the moment this function being executed
+#[macro_export]
+/// Defines pre-init function initializing linker section memory.
+///
+/// This macro accepts linker section names and full names of their boundary symbols as arguments.
+/// Use it if your section symbols do not follow any prefix convention, otherwise look at
+/// [`init_sections`] or [`init_sections_with_prefixes`].
+///
+/// The symbols are given in order section VMA's start, section VMA's end and section LMA's start.
+/// If the symbols in the linker script are named `_custom_data_begin`, `_custom_data_end` and
+/// `_custom_data_flash`, the macro call should be
+///
+/// ```
+/// init_sections_with_symbols!(custom_data(_custom_data_begin, _custom_data_end, _custom_data_flash))
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// init_sections_with_symbols!(
+///     section_a(_section_a_begin, _section_a_end, _section_a_flash),
+///     section_b(_section_b_begin, _section_b_end, _section_b_flash),
+/// );
+/// ```
+macro_rules! init_sections_with_symbols {
+    ($($(#[$attr:meta])* $section_name:ident($beg:ident,$end:ident,$src:ident)$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
 
-    #[cfg(feature = "asserts")]
-    {
-        // section start shall be less or equal to section end
-        assert!(dst as *const u32 <= end);
+        $(
+            $crate::section_init_with_symbols!($(#[$attr])* $section_name($beg, $end, $src));
+        )*
+    }};
+}
+
+#[macro_export]
+/// Defines pre-init function initializing linker section memory.
+///
+/// This macro accepts linker section names and symbol prefixes as arguments, where the second
+/// symbol defines section length instead of section end. The prefixes are given in order section
+/// VMA's start, section length and section LMA's start.
+///
+/// Note that the *address* of the length symbol, not its contents, is the section length in bytes,
+/// as it is in case of linker script symbols defined by `SIZEOF`. The length shall be a multiple
+/// of 4 bytes.
+///
+/// If the symbols in the linker script are named `__scustom_data`, `__lcustom_data` and
+/// `__sicustom_data`, as depicted in an example below, the macro call should be
+///
+/// ```
+/// init_sections_with_len!(custom_data(__s, __l, __si))
+/// ```
+///
+/// ```text
+/// MEMORY
+/// {
+///     FLASH   : ORIGIN = 0x08000000, LENGTH = 32K
+///     RAM     : ORIGIN = 0x20000000, LENGTH = 16K
+///     DATA    : ORIGIN = 0x20004000, LENGTH = 16K
+/// }
+///
+/// SECTIONS
+/// {
+///     .custom_data : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __scustom_data = .;
+///         *(.custom_data .custom_data.*);
+///         . = ALIGN(4);
+///     } > DATA AT>FLASH
+///
+///     __lcustom_data = SIZEOF(.custom_data);
+///     __sicustom_data = LOADADDR(.custom_data);
+/// } INSERT BEFORE .uninit;
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// init_sections_with_len!(section_a(__s, __l, __si), section_b(__s, __l, __si));
+/// ```
+macro_rules! init_sections_with_len {
+    ($($(#[$attr:meta])* $section_name:ident($beg:ident,$len:ident,$src:ident)$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
 
-        // src and dst must be 4-byte aligned because of 4-byte oriented memcopy
-        assert!(src as usize % 4 == 0);
-        assert!(dst as usize % 4 == 0);
+        $(
+            $crate::section_init_len_with_prefixes!($(#[$attr])* $section_name($beg, $len, $src));
+        )*
+    }};
+}
 
-        // to calculate section length, section end must be 4-byte aligned
-        assert!(end as usize % 4 == 0);
-    }
+#[macro_export]
+/// Initializes linker section memory with data gathered from multiple load fragments.
+///
+/// This macro accepts linker section name, optionally followed by VMA start and end symbol
+/// prefixes, `(__s, __e)` by default, and a list of load fragments. Each fragment is given by its
+/// LMA start and end symbols, the fragments are copied one after another into the section memory
+/// in the order given. It is useful if the load data of one section are split across
+/// non-contiguous flash regions, e.g. the end of one flash bank and the start of the other, which
+/// a single `__si<section>` symbol cannot express. With `asserts` feature enabled, the fragment
+/// lengths are checked to sum up to the section length.
+///
+/// ```
+/// init_sections_fragments!(custom_data[__sicustom_data0..__eicustom_data0, __sicustom_data1..__eicustom_data1]);
+/// init_sections_fragments!(custom_data(__s, __e)[__si0..__ei0, __si1..__ei1]);
+/// ```
+///
+/// The section could be described by an output section per fragment, placed contiguously in RAM,
+/// but loaded to different flash regions, as depicted in an example below.
+///
+/// ```text
+/// MEMORY
+/// {
+///     FLASH   : ORIGIN = 0x08000000, LENGTH = 1022K
+///     BANK1   : ORIGIN = 0x080FF800, LENGTH =    2K
+///     BANK2   : ORIGIN = 0x08100000, LENGTH =    2K
+///     RAM     : ORIGIN = 0x20000000, LENGTH =   16K
+///     DATA    : ORIGIN = 0x20004000, LENGTH =   16K
+/// }
+///
+/// SECTIONS
+/// {
+///     .custom_data0 : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __scustom_data = .;
+///         *(.custom_data0 .custom_data0.*);
+///         . = ALIGN(4);
+///     } > DATA AT>BANK1
+///     __sicustom_data0 = LOADADDR(.custom_data0);
+///     __eicustom_data0 = LOADADDR(.custom_data0) + SIZEOF(.custom_data0);
+///
+///     .custom_data1 : ALIGN(4)
+///     {
+///         *(.custom_data1 .custom_data1.*);
+///         . = ALIGN(4);
+///         __ecustom_data = .;
+///     } > DATA AT>BANK2
+///     __sicustom_data1 = LOADADDR(.custom_data1);
+///     __eicustom_data1 = LOADADDR(.custom_data1) + SIZEOF(.custom_data1);
+/// } INSERT BEFORE .uninit;
+/// ```
+///
+/// Multiple sections could be passed as
+///
+/// ```
+/// init_sections_fragments!(section_a[__sia0..__eia0, __sia1..__eia1], section_b(__s, __e)[__sib0..__eib0]);
+/// ```
+macro_rules! init_sections_fragments {
+    ($($(#[$attr:meta])* $section_name:ident$(($beg:ident$(,)? $end:ident$(,)?))?[$($load:ident..$load_end:ident),+$(,)?]$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
 
-    let len = unsafe { end.offset_from(dst) } as usize;
+        $(
+            $crate::section_init_fragments_with_prefixes!($(#[$attr])* $section_name($($beg, $end)?)[$($load..$load_end),+]);
+        )*
+    }};
+}
 
-    #[cfg(feature = "asserts")]
-    {
-        let src = src as usize;
-        let dst = dst as usize;
+#[macro_export]
+/// Initializes the beginning of linker section memory given by a runtime length.
+///
+/// This macro accepts linker section name, optionally followed by symbol prefixes the same way as
+/// [`section`] does, and a length expression in bytes. The expression is evaluated at the
+/// initialization time, the length is rounded up to whole words and clamped to the section size,
+/// then only that part of the section is copied. The macro returns the number of bytes copied.
+///
+/// ```
+/// let copied = init_section_partial!(config, config_valid_len());
+/// ```
+/// ```
+/// init_section_partial!(config(__s, __e, __si), CONFIG_HEADER.len as usize);
+/// ```
+///
+/// It is useful if the section holds a variable length blob, e.g. configuration with its valid
+/// length stored in a header, so the unused rest of the section is neither copied nor
+/// overwritten.
+macro_rules! init_section_partial {
+    ($section_name:ident, $len:expr$(,)?) => {
+        $crate::init_section_partial!($section_name(__s, __e, __si), $len)
+    };
+    ($section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?), $len:expr$(,)?) => {
+        $crate::scoped!(fn(len_bytes: usize = $len) -> usize {
+            let section: $crate::Section =
+                $crate::section_with_prefixes!($section_name($beg, $end, $src));
 
-        // check for memory region overlap
-        assert!(src > dst + len || src + len < dst);
-    }
+            unsafe {
+                $crate::section_init_partial(section.start(), section.end(), section.load(), len_bytes)
+            }
+        })
+    };
+}
+
+#[macro_export]
+/// Initializes linker section memory using GNU-style symbol names.
+///
+/// This macro accepts linker section names as arguments and assumes the linker symbols are named
+/// the way GCC startup files and linker scripts usually do. The naming preset is selected per
+/// section by a marker before the section name
+///  - no marker for `__<section>_start__`, `__<section>_end__` and `__<section>_load_start__`
+///    (e.g. newlib or SEGGER linker scripts),
+///  - `cmsis` for `__<section>_start__`, `__<section>_end__` and `__etext` as section LMA's start
+///    (e.g. CMSIS `gcc_arm.ld`), so it could be used for one section only,
+///  - `zero` for `__<section>_start__` and `__<section>_end__` of a section to be zeroed.
+///
+/// If the symbols in the linker script are named `__data_start__`, `__data_end__`, `__etext`,
+/// `__bss_start__` and `__bss_end__`, as depicted in an example below, the macro call should be
+///
+/// ```
+/// init_sections_gnu!(cmsis data, zero bss)
+/// ```
+///
+/// ```text
+/// SECTIONS
+/// {
+///     __etext = ALIGN(4);
+///
+///     .data : AT (__etext)
+///     {
+///         __data_start__ = .;
+///         *(.data .data.*)
+///         . = ALIGN(4);
+///         __data_end__ = .;
+///     } > RAM
+///
+///     .bss (NOLOAD) :
+///     {
+///         . = ALIGN(4);
+///         __bss_start__ = .;
+///         *(.bss .bss.*)
+///         . = ALIGN(4);
+///         __bss_end__ = .;
+///     } > RAM
+/// }
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// init_sections_gnu!(data, ram2_data, zero bss, zero ram2_bss);
+/// ```
+macro_rules! init_sections_gnu {
+    (@[$($(#[$attr:meta])* $preset:ident $section_name:ident)*]) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $(
+            $crate::section_init_gnu!($(#[$attr])* $preset $section_name);
+        )*
+    }};
+    (@[$($done:tt)*] , $($rest:tt)*) => {
+        $crate::init_sections_gnu!(@[$($done)*] $($rest)*)
+    };
+    (@[$($done:tt)*] $(#[$attr:meta])* cmsis $section_name:ident $($rest:tt)*) => {
+        $crate::init_sections_gnu!(@[$($done)* $(#[$attr])* cmsis $section_name] $($rest)*)
+    };
+    (@[$($done:tt)*] $(#[$attr:meta])* zero $section_name:ident $($rest:tt)*) => {
+        $crate::init_sections_gnu!(@[$($done)* $(#[$attr])* zero $section_name] $($rest)*)
+    };
+    (@[$($done:tt)*] $(#[$attr:meta])* $section_name:ident $($rest:tt)*) => {
+        $crate::init_sections_gnu!(@[$($done)* $(#[$attr])* gnu $section_name] $($rest)*)
+    };
+    ($($sections:tt)+) => {
+        $crate::init_sections_gnu!(@[] $($sections)+)
+    };
+}
 
-    unsafe { core::ptr::copy_nonoverlapping(src, dst, len) };
+#[macro_export]
+/// Initializes linker section memory.
+///
+/// This macro accepts full names of section boundary symbols as string literals. Use it if your
+/// section symbols are not valid rust identifiers, e.g. they contain dots or dollar signs,
+/// otherwise look at [`init_sections_with_symbols`].
+///
+/// The symbols are given in order section VMA's start, section VMA's end and section LMA's start.
+/// If the symbols in the linker script are named `__s.ccm.data`, `__e.ccm.data` and
+/// `__si.ccm.data`, the macro call should be
+///
+/// ```
+/// init_sections_raw!("__s.ccm.data", "__e.ccm.data", "__si.ccm.data")
+/// ```
+///
+/// Multiple sections could be passed separated by semicolon as
+///
+/// ```
+/// init_sections_raw!(
+///     "__s.ccm.data", "__e.ccm.data", "__si.ccm.data";
+///     "sram$data$start", "sram$data$end", "sram$data$load";
+///     "__1st_data_start", "__1st_data_end", "__1st_data_load";
+/// );
+/// ```
+macro_rules! init_sections_raw {
+    ($($(#[$attr:meta])* $beg:literal, $end:literal, $src:literal$(,)?);+$(;)?) => {$(
+        $(#[$attr])*
+        $crate::section_init_raw!($beg, $end, $src);
+    )+};
+}
+
+#[macro_export]
+/// Initializes memory region given by addresses.
+///
+/// This macro accepts region start, region end and initialization data start addresses as
+/// arguments. Use it if your memory map is fixed, e.g. by a bootloader header, and no linker
+/// symbols are available. The addresses shall be `usize` const expressions.
+///
+/// ```
+/// init_region!(0x2000_4000, 0x2000_8000, 0x0801_0000);
+/// ```
+///
+/// See [`init_region()`] for details.
+macro_rules! init_region {
+    ($dst:expr, $end:expr, $src:expr$(,)?) => {{
+        const DST: usize = $dst;
+        const END: usize = $end;
+        const SRC: usize = $src;
+
+        unsafe {
+            $crate::init_region(DST, END, SRC);
+        }
+    }};
+}
+
+#[cfg(feature = "crc32-verify")]
+#[macro_export]
+/// Checks the CRC32 of the flash image before any section is initialized.
+///
+/// This macro accepts the image start, the image end and the stored CRC symbols as arguments,
+/// e.g. spanning the load data of all the sections initialized afterwards. The CRC32 of the
+/// `start..end` bytes is computed by volatile reads the same as by [`crc32`] and compared with the
+/// `u32` at the CRC symbol, which shall lie outside the image. The mismatch is reported as
+/// [`InitError::ImageCrcMismatch`] the same as a failed assert, so a partially programmed flash
+/// stops the boot before half of the RAM is initialized. Available with `crc32-verify` feature
+/// only.
+///
+/// ```
+/// #[cortex_m_rt::pre_init]
+/// unsafe fn pre_init() {
+///     verify_image!(__simage, __eimage, __image_crc);
+///     init_sections!(custom_data, buffers);
+/// }
+/// ```
+///
+/// The same is done by `verify_image(..);` clause of [`init_sections`] and the macros defining
+/// the pre-init function. The linker script bounds the image and reserves the CRC word after it,
+/// both in flash.
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .image_crc : ALIGN(4)
+///     {
+///         __image_crc = .;
+///         LONG(0xFFFFFFFF);
+///     } > FLASH
+/// } INSERT AFTER .custom_data;
+///
+/// __simage = LOADADDR(.data);
+/// __eimage = LOADADDR(.custom_data) + SIZEOF(.custom_data);
+/// ```
+///
+/// The CRC depends on the code compiled, so it is patched after the link, e.g. by `objcopy` and
+/// a short script computing the same CRC32 as `zlib`. The binary starts at the flash origin, here
+/// `0x08000000`, and the image bounds, here `0x08003000..0x08003400`, are read from the map file
+/// or by `nm`. The gaps are filled by `0xFF`, as the erased flash reads. A post-link tool written
+/// in Rust could call [`crc32`] with `std` feature enabled instead.
+///
+/// ```text
+/// arm-none-eabi-objcopy -O binary --gap-fill 0xFF app.elf app.bin
+/// python3 -c "import struct, sys, zlib
+/// image = open('app.bin', 'rb').read()[0x3000:0x3400]
+/// sys.stdout.buffer.write(struct.pack('<I', zlib.crc32(image)))" > crc.bin
+/// arm-none-eabi-objcopy --update-section .image_crc=crc.bin app.elf
+/// ```
+macro_rules! verify_image {
+    ($start:ident, $end:ident, $crc:ident$(,)?) => {{
+        // declared apart, so the CRC could be placed right at the image end symbol
+        unsafe {
+            $crate::image_verify(
+                {
+                    $crate::pointer!($start);
+                    $crate::symbol!($start)
+                },
+                {
+                    $crate::pointer!($end);
+                    $crate::symbol!($end)
+                },
+                {
+                    $crate::pointer!($crc);
+                    $crate::symbol!($crc)
+                },
+            );
+        }
+    }};
+}
+
+#[macro_export]
+/// Registers linker section to be initialized by [`init_registered_sections`].
+///
+/// This macro shall be called at module scope and accepts linker section name, optionally followed
+/// by symbol prefixes the same way as [`init_sections`] does. It places a [`SectionDescriptor`]
+/// into `.linker_sections_table` section, so the sections could be registered independently in
+/// different modules and no central list of sections is needed.
+///
+/// ```
+/// register_section!(custom_data);
+/// register_section!(weird_section(_beg, _end, _rom));
+/// ```
+///
+/// The linker script fragment `linker_sections_table.x` shipped with this crate shall be passed to
+/// the linker, e.g. in `build.rs` by
+///
+/// ```
+/// println!("cargo:rustc-link-arg=-Tlinker_sections_table.x");
+/// ```
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .linker_sections_table : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __slinker_sections_table = .;
+///         KEEP(*(.linker_sections_table .linker_sections_table.*));
+///         . = ALIGN(4);
+///         __elinker_sections_table = .;
+///     } > FLASH
+/// } INSERT AFTER .rodata;
+/// ```
+macro_rules! register_section {
+    ($(#[$attr:meta])* $section_name:ident$(,)?) => {
+        $crate::register_section!($(#[$attr])* $section_name(__s, __e, __si));
+    };
+    ($(#[$attr:meta])* $section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?)$(,)?) => {
+        $crate::with_eager_expansions! {
+            $crate::section_register_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) },
+                #{ concat_idents!($src, $section_name) }
+            ));
+        }
+    };
+}
+
+#[macro_export]
+/// Initializes all linker sections registered by [`register_section`].
+///
+/// The macro walks the `.linker_sections_table` section between `__slinker_sections_table` and
+/// `__elinker_sections_table` symbols and initializes every registered section. It shall be
+/// usually called in pre-init function.
+///
+/// ```
+/// init_registered_sections!();
+/// ```
+macro_rules! init_registered_sections {
+    () => {
+        $crate::init_section_table!(__slinker_sections_table, __elinker_sections_table)
+    };
+}
+
+#[macro_export]
+/// Initializes all linker sections listed in a section table.
+///
+/// This macro accepts the table start and end symbols as arguments. The table consists of
+/// [`SectionInitEntry`] records, each holding section VMA's start, VMA's end and LMA's start, so
+/// all the sections are initialized by a single loop instead of code generated per section. This
+/// saves flash when there are many sections.
+///
+/// Measured on `thumbv7em-none-eabi` release build, each table entry takes 12 bytes while code
+/// generated per section by [`init_sections`] takes about 30 bytes, but the loop itself takes
+/// about 200 bytes. So with 4 sections the table takes 84 bytes more, with 12 sections it saves
+/// 160 bytes.
+///
+/// ```
+/// init_section_table!(__ssection_init_table, __esection_init_table);
+/// ```
+///
+/// The table is usually emitted by the linker script, as depicted below, but it could be authored
+/// in rust as well as an array of [`SectionInitEntry`] placed into the table section.
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .section_init_table : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __ssection_init_table = .;
+///         LONG(__scustom_data_a) LONG(__ecustom_data_a) LONG(__sicustom_data_a)
+///         LONG(__scustom_data_b) LONG(__ecustom_data_b) LONG(__sicustom_data_b)
+///         __esection_init_table = .;
+///     } > FLASH
+/// } INSERT AFTER .rodata;
+/// ```
+macro_rules! init_section_table {
+    ($start:ident, $end:ident$(,)?) => {
+        $crate::scoped!(fn() -> () {
+            $crate::pointer!($start);
+            $crate::pointer!($end);
+
+            let table: *const $crate::SectionInitEntry = $crate::symbol!($start);
+            let table_end: *const $crate::SectionInitEntry = $crate::symbol!($end);
+
+            unsafe {
+                $crate::section_table_init(table, table_end);
+            }
+        })
+    };
+}
+
+#[macro_export]
+/// Initializes linker section memory again at runtime.
+///
+/// This macro accepts linker section name, optionally followed by symbol prefixes the same way as
+/// [`section`] does, and returns the number of bytes restored. It is intended to restore default
+/// values of the variables placed in the section while the system is running, e.g. on a "restore
+/// factory calibration" command.
+///
+/// ```
+/// let restored = reinit_section!(calibration);
+/// defmt::info!("restored {} bytes", restored);
+/// ```
+///
+/// With `critical-section` feature enabled, the section is copied within
+/// `critical_section::with`, so no interrupt could observe a half-copied section.
+///
+/// # Safety
+///
+/// The section is overwritten by its initial values, so
+/// - no reference, especially `&mut`, to any variable placed in the section shall be alive during
+///   the call,
+/// - without `critical-section` feature, no other context (e.g. interrupt handler) shall access
+///   the section during the call,
+/// - any state kept in the section is lost, e.g. already initialized `StaticCell` could be
+///   initialized again resulting in multiple mutable references to the same memory.
+///
+/// # Chunked copy
+///
+/// A large section, e.g. in an external SDRAM, could be copied in chunks by `chunked(<chunk
+/// bytes>, <yield>)` following the section, the yield being a `FnMut()` called between the chunks,
+/// e.g. to feed a watchdog or let other threads run, see [`section_init_chunked`]. The section is
+/// then not copied within `critical_section::with` even with `critical-section` feature enabled,
+/// so the other contexts running in between shall not access it. The marker is accepted by this
+/// macro only, the sections initialized in pre-init are not expected to yield.
+///
+/// ```
+/// let restored = reinit_section!(sdram_data chunked(64 * 1024, || watchdog.feed()));
+/// ```
+///
+/// A section too large for interrupts to be blocked during its copy could be marked
+/// `no_critical_section`, it is then not copied within `critical_section::with`, so no other
+/// context shall access it during the call.
+///
+/// ```
+/// let restored = reinit_section!(sdram_assets no_critical_section);
+/// ```
+macro_rules! reinit_section {
+    ($section_name:ident$(($($prefixes:tt)*))? no_critical_section) => {
+        $crate::scoped!(fn() -> usize {
+            let section: $crate::Section = $crate::section!($section_name$(($($prefixes)*))?);
+
+            unsafe { section.init() };
+
+            section.len_bytes()
+        })
+    };
+    ($section_name:ident$(($($prefixes:tt)*))? chunked($chunk_bytes:expr, $yield_fn:expr$(,)?)) => {
+        $crate::scoped!(fn(chunk_bytes: usize = $chunk_bytes, yield_fn: &mut dyn FnMut() = &mut $yield_fn) -> usize {
+            let section: $crate::Section = $crate::section!($section_name$(($($prefixes)*))?);
+
+            unsafe { section.init_chunked(chunk_bytes, yield_fn) };
+
+            section.len_bytes()
+        })
+    };
+    ($($section:tt)+) => {
+        unsafe { $crate::section!($($section)+).reinit() }
+    };
+}
+
+#[macro_export]
+/// Initializes linker section memory if given predicate holds.
+///
+/// This macro accepts linker section names, each optionally followed by symbol prefixes the same
+/// way as [`section`] does, and followed by `if` with a `bool` predicate expression. The predicate
+/// is evaluated just before the section is initialized and the section is skipped if it is
+/// `false`, e.g. to preserve a retained section on warm restart.
+///
+/// ```
+/// init_sections_if!(retained if cold_boot(), custom_data if true);
+/// ```
+/// ```
+/// init_sections_if!(
+///     retained(__start_, __stop_, __load_) if cold_boot(),
+///     #[cfg(feature = "big-board")] sram4 if !warm_restart,
+/// );
+/// ```
+///
+/// The sections are processed in the given order.
+///
+/// # Predicate
+///
+/// The macro is usually called in pre-init, where `.data` and `.bss` are not initialized yet, so
+/// the predicate shall not rely on any static variable, it should only read hardware registers,
+/// e.g. reset cause register, or local variables.
+macro_rules! init_sections_if {
+    ($($(#[$attr:meta])* $section_name:ident$(($($prefixes:tt)*))? if $predicate:expr),+$(,)?) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $(
+            $(#[$attr])*
+            $crate::scoped!(fn(predicate: bool = $predicate) -> () {
+                unsafe {
+                    $crate::section!($section_name$(($($prefixes)*))?).init_if(predicate);
+                }
+            });
+        )*
+    }};
+}
+
+#[macro_export]
+/// Returns [`DeferredInit`] handle initializing linker section memory later.
+///
+/// This macro accepts linker section names, each optionally followed by symbol prefixes the same
+/// way as [`section`] does. The section memory is not touched until [`DeferredInit::run`] is
+/// called, so it could be used for memories which are not ready in pre-init, e.g. an external
+/// SDRAM which is usable only once the clocks and the memory controller are configured.
+///
+/// ```
+/// let sdram = defer_sections_init!(sdram_data, sdram_heap(__start_, __stop_, __load_));
+///
+/// configure_fmc();
+///
+/// unsafe { sdram.run() };
+/// ```
+///
+/// The handle is `#[must_use]` and with debug assertions enabled, dropping it without calling
+/// [`DeferredInit::run`] panics.
+///
+/// With `critical-section` feature enabled, each section is copied within
+/// `critical_section::with`, so no interrupt could observe a half-copied section. A section too
+/// large for interrupts to be blocked during its copy could be marked `no_critical_section`
+/// following its name, no other context (e.g. interrupt handler) shall then access it during the
+/// copy.
+///
+/// ```
+/// let sdram = defer_sections_init!(sdram_data, sdram_assets no_critical_section);
+/// ```
+macro_rules! defer_sections_init {
+    (@[$({$(#[$attr:meta])* $section_name:ident$(($($prefixes:tt)*))?} $(@$unlocked:ident)?)*]) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $crate::DeferredInit::new([
+            $(
+                $(#[$attr])*
+                $crate::section!($section_name$(($($prefixes)*))?),
+            )*
+        ])
+        .with_unlocked([
+            $(
+                $(#[$attr])*
+                $crate::section_unlocked!($($unlocked)?),
+            )*
+        ])
+    }};
+    (@[$($done:tt)*] , $($rest:tt)*) => {
+        $crate::defer_sections_init!(@[$($done)*] $($rest)*)
+    };
+    (@[$($done:tt)*] no_critical_section $($rest:tt)*) => {
+        $crate::defer_sections_init!(@[$($done)* @no_critical_section] $($rest)*)
+    };
+    (@[$($done:tt)*] $(#[$attr:meta])* $section_name:ident($($prefixes:tt)*) $($rest:tt)*) => {
+        $crate::defer_sections_init!(@[$($done)* {$(#[$attr])* $section_name($($prefixes)*)}] $($rest)*)
+    };
+    (@[$($done:tt)*] $(#[$attr:meta])* $section_name:ident $($rest:tt)*) => {
+        $crate::defer_sections_init!(@[$($done)* {$(#[$attr])* $section_name}] $($rest)*)
+    };
+    ($($sections:tt)+) => {
+        $crate::defer_sections_init!(@[] $($sections)+)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_unlocked {
+    () => {
+        false
+    };
+    (no_critical_section) => {
+        true
+    };
+}
+
+#[macro_export]
+/// Defines code initializing linker section memory by a [`SectionCopier`], e.g. by DMA.
+///
+/// This macro accepts an expression evaluating to the copier, or a mutable reference to it,
+/// followed by linker section names, each optionally followed by symbol prefixes the same way as
+/// [`section`] does. The word aligned body of each section is copied by the copier, the bytes not
+/// forming aligned words and the alignment and overlap checks are done by the crate.
+///
+/// ```
+/// init_sections_with_copier!(&mut dma; sdram_data, sdram_heap(__start_, __stop_, __load_));
+/// ```
+///
+/// The copier usually needs clocks and peripherals configured, so the macro is meant to be called
+/// in `main` rather than in pre-init, e.g. for a memory which is not ready in pre-init either, see
+/// [`defer_sections_init`] and [`DeferredInit::run_with`]. The sections are copied in the given
+/// order, if the copier returns before the copy is done, the sections shall not be accessed until
+/// it is.
+macro_rules! init_sections_with_copier {
+    ($copier:expr; $($(#[$attr:meta])* $section_name:ident$(($($prefixes:tt)*))?$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $crate::scoped!(fn(copier: &mut dyn $crate::SectionCopier = &mut $copier) -> () {
+            $(
+                $(#[$attr])*
+                {
+                    let section: $crate::Section = $crate::section!($section_name$(($($prefixes)*))?);
+
+                    unsafe { section.init_with(copier) };
+                }
+            )*
+        });
+    }};
+}
+
+#[macro_export]
+/// Defines code initializing linker section memory while feeding a watchdog.
+///
+/// This macro accepts `every <N> words with <feed>;` clause followed by linker section names, each
+/// optionally followed by symbol prefixes the same way as [`section`] does. The `feed`, a `fn()`,
+/// is called after every `N` [`Word`]s copied and after the last, possibly partial, chunk of each
+/// section, so it is called at least once per section, even for an empty one, and the time
+/// between the calls is bounded by the copy of `N` words. `N` is at least one word.
+///
+/// ```
+/// fn feed_iwdg() {
+///     IWDG.kr().write(|w| w.key().reset());
+/// }
+///
+/// init_sections_with_feed!(every 4096 words with feed_iwdg; sdram_assets, sdram_data(__s, __e, __si));
+/// ```
+///
+/// It is meant for watchdogs which cannot be paused, e.g. STM32 independent watchdog enabled by
+/// option bytes, with a timeout shorter than a copy of a large section, e.g. in a bootloader.
+macro_rules! init_sections_with_feed {
+    (every $words:tt words with $feed:path; $($(#[$attr:meta])* $section_name:ident$(($($prefixes:tt)*))?),+$(,)?) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $crate::scoped!(fn(words: usize = $words, feed: fn() = $feed) -> () {
+            $(
+                $(#[$attr])*
+                {
+                    let section: $crate::Section = $crate::section!($section_name$(($($prefixes)*))?);
+
+                    unsafe { $crate::section_init_fed(section.start(), section.end(), section.load(), words, feed) };
+                }
+            )*
+        });
+    }};
+}
+
+#[macro_export]
+/// Initializes linker section memory, recording the first malformed section instead of panicking.
+///
+/// This macro accepts a path to a static [`InitErrorCell`] followed by linker section names, each
+/// optionally followed by symbol prefixes the same way as [`section`] does. Each section is
+/// initialized by [`checked_section_init`], a malformed one is left untouched and its error is
+/// recorded in the cell unless an error was recorded already, the following sections are
+/// initialized anyway. The cell is cleared first.
+///
+/// ```
+/// #[unsafe(link_section = ".uninit.init_error")]
+/// static INIT_ERROR: InitErrorCell = InitErrorCell::new();
+///
+/// #[cortex_m_rt::pre_init]
+/// unsafe fn pre_init() {
+///     try_init_sections!(INIT_ERROR; custom_data, sram2(__s, __e, __si));
+/// }
+///
+/// #[cortex_m_rt::entry]
+/// fn main() -> ! {
+///     if let Some(error) = INIT_ERROR.get() {
+///         defmt::error!("section init failed: {}", defmt::Display2Format(&error));
+///     }
+///     // ...
+/// }
+/// ```
+///
+/// It is meant for pre-init, where a panic locks up silently as neither the logging nor the panic
+/// handler output is up yet.
+///
+/// # Safety
+///
+/// Same as for [`init_sections`].
+macro_rules! try_init_sections {
+    ($cell:path; $($(#[$attr:meta])* $section_name:ident$(($($prefixes:tt)*))?$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $crate::scoped!(fn(cell: &$crate::InitErrorCell = &$cell) -> () {
+            cell.clear();
+
+            $(
+                $(#[$attr])*
+                {
+                    let section: $crate::Section = $crate::section!($section_name$(($($prefixes)*))?);
+
+                    if let Err(error) = unsafe { $crate::checked_section_init(section.start(), section.end(), section.load()) } {
+                        cell.record(error);
+                    }
+                }
+            )*
+        });
+    }};
+}
+
+#[macro_export]
+/// Initializes linker section memory with load data read from an external NOR flash.
+///
+/// This macro accepts an expression evaluating to a mutable reference to the flash, a
+/// [`nor::ReadNorFlash`], followed by linker section names, each optionally followed by symbol
+/// prefixes of the section VMA's start and end, `__s` and `__e` by default, and followed by `at`
+/// with the `u32` offset of the section load data in the flash instead of an `__si<section>`
+/// symbol. It returns the first read error of the flash, if any, see
+/// [`nor::init_section_from_nor`].
+///
+/// ```
+/// init_sections_from_nor!(&mut spi_nor; sdram_assets at 0x10_0000, fonts(__start_, __stop_) at FONTS_OFFSET)?;
+/// ```
+///
+/// The flash is read through its driver, so the macro is meant to be called in `main` once the
+/// driver is up rather than in pre-init. The sections are initialized in the given order and the
+/// sections following a failed one are not touched.
+///
+/// # Safety
+///
+/// Same as for [`reinit_section`], the sections are overwritten.
+macro_rules! init_sections_from_nor {
+    ($flash:expr; $($(#[$attr:meta])* $section_name:ident$(($beg:ident, $end:ident))? at $offset:expr),+$(,)?) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        let nor_sections = ($flash, [
+            $(
+                $(#[$attr])*
+                ($offset, $crate::section_without_load!($section_name$(($beg, $end))?)),
+            )*
+        ]);
+
+        unsafe { $crate::nor::init_sections_at(nor_sections.0, &nor_sections.1) }
+    }};
+}
+
+#[cfg(feature = "memory-bounds")]
+#[macro_export]
+/// Declares the flash bounds the section load data shall lie within (`memory-bounds` feature).
+///
+/// This macro accepts the flash start and end addresses, constant expressions, defining
+/// `__sflash_bounds` and `__eflash_bounds` symbols, or `linker_script` keyword if the linker script
+/// defines these symbols itself, e.g. `__sflash_bounds = ORIGIN(FLASH);`. Both forms are optionally
+/// followed by a handler `fn(address: usize)` of load data out of the bounds. The macro shall be
+/// called once per firmware, at the item level.
+///
+/// ```
+/// set_flash_bounds!(0x0800_0000, 0x0810_0000);
+/// set_flash_bounds!(linker_script, report_bounds);
+/// ```
+///
+/// Before each section is initialized, its load data `src..src + len` is checked to lie within
+/// `__sflash_bounds..__eflash_bounds`, otherwise the handler is called with the first address
+/// out of the bounds and the section is left untouched, or compressed section data fail as
+/// truncated. With no handler given, it fails as a failed assert does, see
+/// [Failed asserts](crate#failed-asserts). The load data read through [`SectionSource`] or
+/// [`nor::ReadNorFlash`] is not checked, nor are the sections initialized by code inlined into
+/// them, e.g. by `in <section>` marker, as no code outside them is called.
+macro_rules! set_flash_bounds {
+    (linker_script$(, $handler:path)?$(,)?) => {
+        $crate::flash_bounds_hook!($($handler)?);
+    };
+    ($start:expr, $end:expr$(, $handler:path)?$(,)?) => {
+        core::arch::global_asm!(
+            ".globl __sflash_bounds",
+            ".set __sflash_bounds, {start}",
+            ".globl __eflash_bounds",
+            ".set __eflash_bounds, {end}",
+            start = const $start,
+            end = const $end,
+        );
+
+        $crate::flash_bounds_hook!($($handler)?);
+    };
+}
+
+#[cfg(feature = "memory-bounds")]
+#[macro_export]
+/// Declares the RAM bounds the section memory shall lie within (`memory-bounds` feature).
+///
+/// This macro accepts one or more ranges separated by `;`, each given by its start and end
+/// addresses, e.g. integer constants or pointers to linker script symbols, optionally followed by
+/// a handler `fn(section: Section, address: usize)` of section memory out of the bounds. The
+/// adjacent ranges are contiguous, so a section may span them. The macro shall be called once per
+/// firmware, at the item level.
+///
+/// ```
+/// set_ram_bounds!(0x2000_0000, 0x2002_0000; 0x1000_0000, 0x1001_0000);
+/// set_ram_bounds!(&raw const __sram_bounds, &raw const __eram_bounds, report_bounds);
+/// ```
+///
+/// Before each section is initialized, zeroed or filled, its memory is checked to lie within the
+/// ranges, otherwise the handler is called with the [`Section`] and the first address out of the
+/// bounds and the section is left untouched, or compressed section data fail as overrun. With no
+/// handler given, it fails as a failed assert does, see [Failed asserts](crate#failed-asserts).
+/// The same as for [`set_flash_bounds`], the sections initialized by code inlined into them are
+/// not checked.
+macro_rules! set_ram_bounds {
+    ($($start:expr, $end:expr);+ $(, $handler:path)?) => {
+        $crate::ram_bounds_hook!([$(($start, $end))+] $($handler)?);
+    };
+}
+
+#[cfg(feature = "memory-bounds")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! ram_bounds_hook {
+    ([$($range:tt)+]) => {
+        $crate::ram_bounds_hook!([$($range)+] $crate::out_of_ram_bounds);
+    };
+    ([$(($start:expr, $end:expr))+] $handler:path) => {
+        #[unsafe(no_mangle)]
+        fn __linker_sections_within_ram_bounds(checked_section: $crate::Section) -> bool {
+            $crate::check_ram_bounds(checked_section, &[$(($start as usize, $end as usize)),+], $handler)
+        }
+    };
+}
+
+#[cfg(feature = "memory-bounds")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! flash_bounds_hook {
+    () => {
+        $crate::flash_bounds_hook!($crate::out_of_flash_bounds);
+    };
+    ($handler:path) => {
+        #[unsafe(no_mangle)]
+        fn __linker_sections_out_of_flash_bounds(address: usize) {
+            $handler(address)
+        }
+    };
+}
+
+#[cfg(feature = "failure-handler")]
+#[macro_export]
+/// Declares the handler of malformed sections found by the asserts (`failure-handler` feature).
+///
+/// This macro accepts a handler `fn(error: &InitError) -> !` called instead of the default failure
+/// path, see [Failed asserts](crate#failed-asserts), by each failed assert of `asserts` feature
+/// and by the default handlers of [`set_flash_bounds`] and [`set_ram_bounds`]. The macro shall be
+/// called once per firmware, at the item level.
+///
+/// ```
+/// set_failure_handler!(record_and_reset);
+///
+/// fn record_and_reset(error: &InitError) -> ! {
+///     unsafe { BREADCRUMB.write(error.code()) };
+///     cortex_m::peripheral::SCB::sys_reset()
+/// }
+/// ```
+///
+/// The handler is bound at link time, so it may be called in pre-init, before the statics are
+/// initialized. It shall not access any static but those in the sections not initialized by the
+/// startup, e.g. a `(NOLOAD)` section kept across the resets, nor rely on the panic handler or the
+/// logging being set up.
+///
+/// On Arm targets, a firmware giving no handler fails the same as with the feature disabled, on
+/// other targets the handler shall be given.
+macro_rules! set_failure_handler {
+    ($handler:path$(,)?) => {
+        #[unsafe(no_mangle)]
+        fn __linker_sections_on_error(error: &$crate::InitError) -> ! {
+            $handler(error)
+        }
+    };
+}
+
+#[cfg(any(
+    feature = "assert-align",
+    feature = "assert-bounds",
+    feature = "assert-order",
+    feature = "assert-overlap",
+    feature = "asserts-debug-only",
+    feature = "memory-bounds"
+))]
+#[macro_export]
+/// Declares the scratch memory the default failure path records the error to.
+///
+/// This macro accepts the address of two words, a constant expression, defining
+/// `__linker_sections_failure_scratch` symbol, the same as the linker script could do, e.g.
+/// `__linker_sections_failure_scratch = ORIGIN(NOINIT_RAM);`. The macro shall be called at most
+/// once per firmware, at the item level.
+///
+/// ```
+/// set_failure_scratch!(0x2000_7FF8);
+/// ```
+///
+/// On a failed assert, [`InitError::code`] and [`InitError::address`] are written to the first
+/// and the second word, before the breakpoint, see [Failed asserts](crate#failed-asserts).
+/// Nothing is written with `asserts-panic` or `failure-handler` feature enabled.
+macro_rules! set_failure_scratch {
+    ($address:expr$(,)?) => {
+        core::arch::global_asm!(
+            ".globl __linker_sections_failure_scratch",
+            ".set __linker_sections_failure_scratch, {address}",
+            address = const $address,
+        );
+    };
+}
+
+#[macro_export]
+/// Relocates linker sections whose memory may overlap their own or other sections' load data.
+///
+/// This macro accepts linker section names, each optionally followed by symbol prefixes the same
+/// way as [`section`] does. It is meant e.g. for a bootloader whose application image is loaded
+/// contiguously into RAM by a ROM loader, so the `.data` sections shall be moved from the image
+/// to their VMAs partially overlapping it.
+///
+/// ```
+/// relocate_sections!(data, fast_data, ramcode);
+/// ```
+///
+/// The sections are moved in the given order, except a section is moved only after all the
+/// sections whose load data its memory overlaps, so no section reads load data already
+/// overwritten. Each section is copied word by word by ascending addresses if its memory is below
+/// its load data and by descending ones otherwise, see [`relocate()`].
+///
+/// # Panics
+///
+/// Panics if the sections overlap each other's load data in a cycle, e.g. two sections swapping
+/// places, which cannot be relocated without a temporary buffer.
+macro_rules! relocate_sections {
+    ($($(#[$attr:meta])* $section_name:ident$(($($prefixes:tt)*))?$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        let mut sections = [
+            $(
+                $(#[$attr])*
+                $crate::section!($section_name$(($($prefixes)*))?),
+            )*
+        ];
+
+        unsafe { $crate::relocate(&mut sections) };
+    }};
+}
+
+#[macro_export]
+/// Returns [`Section`] describing linker section memory.
+///
+/// This macro accepts linker section name, optionally followed by symbol prefixes the same way as
+/// [`init_sections`] does. Unlike [`init_sections`], the section is not initialized, the returned
+/// value could be used later to get section bounds or to initialize the section.
+///
+/// ```
+/// let custom_data = section!(custom_data);
+/// let weird_section = section!(weird_section(_beg, _end, _rom));
+///
+/// unsafe { custom_data.init() };
+/// defmt::info!("custom_data has {} bytes", custom_data.len_bytes());
+/// ```
+///
+/// The macro could be used in const context as well, so the section could be stored in a static
+///
+/// ```
+/// static CUSTOM_DATA: Section = section!(custom_data);
+/// ```
+///
+/// Optional section, see [`init_sections`], is given by `optional` marker. Such section cannot be
+/// used in const context.
+///
+/// ```
+/// let sram4 = section!(optional sram4_data);
+/// ```
+///
+/// Section whose load data is not memory mapped, e.g. in an external NOR flash, is given by its
+/// VMA's start and end prefixes only, its LMA is null.
+///
+/// ```
+/// let nor_assets = section!(nor_assets(__s, __e));
+/// ```
+macro_rules! section {
+    (optional $section_name:ident$(,)?) => {
+        $crate::section!(optional $section_name(__s, __e, __si))
+    };
+    (optional $section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?)$(,)?) => {
+        $crate::section_with_prefixes!($section_name($beg, $end, $src, optional))
+    };
+    ($section_name:ident$(,)?) => {
+        $crate::section!($section_name(__s, __e, __si))
+    };
+    ($section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?)$(,)?) => {
+        $crate::section_with_prefixes!($section_name($beg, $end, $src))
+    };
+    ($section_name:ident($beg:ident, $end:ident$(,)?)$(,)?) => {
+        $crate::section_without_load!($section_name($beg, $end))
+    };
+}
+
+#[macro_export]
+/// Initializes linker section memory described by CMSIS copy and zero tables.
+///
+/// The macro walks the copy table between `__copy_table_start__` and `__copy_table_end__` symbols
+/// and the zero table between `__zero_table_start__` and `__zero_table_end__` symbols, as emitted
+/// by CMSIS and many vendor linker scripts, copying and zeroing the memory respectively. See
+/// [`CopyTableEntry`] and [`ZeroTableEntry`] for the entry layout.
+///
+/// The lengths are given in words as CMSIS 5 specifies by default, pass `bytes` if your linker
+/// script gives them in bytes.
+///
+/// ```
+/// init_cmsis_tables!();
+/// init_cmsis_tables!(bytes);
+/// ```
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .copy.table :
+///     {
+///         . = ALIGN(4);
+///         __copy_table_start__ = .;
+///         LONG (__etext)
+///         LONG (__data_start__)
+///         LONG ((__data_end__ - __data_start__) / 4)
+///         __copy_table_end__ = .;
+///     } > FLASH
+///
+///     .zero.table :
+///     {
+///         . = ALIGN(4);
+///         __zero_table_start__ = .;
+///         LONG (__bss_start__)
+///         LONG ((__bss_end__ - __bss_start__) / 4)
+///         __zero_table_end__ = .;
+///     } > FLASH
+/// }
+/// ```
+///
+/// With `asserts` feature enabled, the tables are checked to be 4-byte aligned, with the end not
+/// preceding the start and holding whole entries only.
+macro_rules! init_cmsis_tables {
+    () => {
+        $crate::init_cmsis_tables!(words)
+    };
+    (words) => {
+        $crate::init_cmsis_tables!(@$crate::LengthUnit::Words)
+    };
+    (bytes) => {
+        $crate::init_cmsis_tables!(@$crate::LengthUnit::Bytes)
+    };
+    (@$unit:path) => {
+        $crate::scoped!(fn() -> () {
+            $crate::pointer!(__copy_table_start__);
+            $crate::pointer!(__copy_table_end__);
+            $crate::pointer!(__zero_table_start__);
+            $crate::pointer!(__zero_table_end__);
+
+            let copy_table: *const $crate::CopyTableEntry =
+                $crate::symbol!(__copy_table_start__);
+            let copy_table_end: *const $crate::CopyTableEntry =
+                $crate::symbol!(__copy_table_end__);
+            let zero_table: *const $crate::ZeroTableEntry =
+                $crate::symbol!(__zero_table_start__);
+            let zero_table_end: *const $crate::ZeroTableEntry =
+                $crate::symbol!(__zero_table_end__);
+
+            unsafe {
+                $crate::copy_table_init(copy_table, copy_table_end, $unit);
+                $crate::zero_table_init(zero_table, zero_table_end, $unit);
+            }
+        })
+    };
+}
+
+#[macro_export]
+/// Defines pre-init function zeroing linker section memory.
+///
+/// This macro accepts linker section names as arguments and assumes the linker symbols are named
+/// after given section names prefixed with
+///  - `__s` for section VMA's start (usually points to RAM)
+///  - `__e` for section VMA's end (usually points to RAM)
+///
+/// No LMA symbol is needed, the section is filled with zero words from its start to its end.
+///
+/// If the symbols in the linker script are named `__sfast_bss` and `__efast_bss`, as depicted in
+/// an example below, the macro call should be
+///
+/// ```
+/// zero_sections!(fast_bss)
+/// ```
+///
+/// ```text
+/// MEMORY
+/// {
+///     FLASH   : ORIGIN = 0x08000000, LENGTH = 32K
+///     RAM     : ORIGIN = 0x20000000, LENGTH = 16K
+///     DATA    : ORIGIN = 0x20004000, LENGTH = 16K
+/// }
+///
+/// SECTIONS
+/// {
+///     .fast_bss (NOLOAD) : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __sfast_bss = .;
+///         *(.fast_bss .fast_bss.*);
+///
+///         . = ALIGN(4);
+///         __efast_bss = .;
+///     } > DATA
+/// } INSERT BEFORE .uninit;
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// zero_sections!(section_a, section_b, section_c);
+/// ```
+/// ```
+/// zero_sections!(section_a section_b section_c);
+/// ```
+///
+/// Sections in ECC protected RAM are given the `: ecc` or `: ecc64` marker, the memory is then
+/// zeroed by volatile full 32-bit or 64-bit words only, see [`init_sections`].
+///
+/// ```
+/// zero_sections!(axi_bss: ecc64, dtcm_bss: ecc);
+/// ```
+macro_rules! zero_sections {
+    ($($(#[$attr:meta])* $section_name:ident$(: $mode:ident)?$(,)?)+) => {
+        $crate::zero_sections_with_prefixes!($($(#[$attr])* $section_name(__s, __e)$(: $mode)?,)*);
+    };
+}
+
+#[macro_export]
+/// Defines pre-init function zeroing linker section memory.
+///
+/// This macro accepts linker section names and symbol prefixes as arguments. If your section
+/// symbols are prefixed with `__s` and `__e`, look at [`zero_sections`].
+///
+/// If the symbols in the linker script are named `__sbss2` and `__ebss2`, the macro call should be
+///
+/// ```
+/// zero_sections_with_prefixes!(bss2(__s, __e))
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// zero_sections_with_prefixes!(section_a(__s, __e), section_b(__s, __e));
+/// ```
+/// ```
+/// zero_sections_with_prefixes!(
+///     section_a(__s, __e)
+///     section_b(__s, __e)
+/// );
+/// ```
+///
+/// The `: ecc` and `: ecc64` markers following the prefixes are accepted as by [`zero_sections`].
+///
+/// ```
+/// zero_sections_with_prefixes!(axi_bss(__s, __e): ecc64);
+/// ```
+macro_rules! zero_sections_with_prefixes {
+    ($($(#[$attr:meta])* $section_name:ident($beg:ident,$end:ident)$(: $mode:ident)?$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $(
+            $crate::section_zero_with_prefixes!($(#[$attr])* $section_name($beg, $end)$(: $mode)?);
+        )*
+    }};
+}
+
+#[macro_export]
+/// Defines pre-init function filling linker section memory with a pattern.
+///
+/// This macro accepts a fill word followed by linker section names as arguments and assumes the
+/// linker symbols are named after given section names prefixed with
+///  - `__s` for section VMA's start (usually points to RAM)
+///  - `__e` for section VMA's end (usually points to RAM)
+///
+/// The fill word shall be a [`Word`] const expression, so a constant could be used as well.
+///
+/// ```
+/// fill_sections!(0xA5A5_A5A5; buffers)
+/// ```
+///
+/// Multiple section names could be passed as
+///
+/// ```
+/// const PATTERN: u32 = 0xA5A5_A5A5;
+///
+/// fill_sections!(PATTERN; section_a, section_b, section_c);
+/// ```
+/// ```
+/// fill_sections!(0xA5A5_A5A5; section_a section_b section_c);
+/// ```
+///
+/// Sections in ECC protected RAM are given the `: ecc` or `: ecc64` marker, the memory is then
+/// filled by volatile full 32-bit or 64-bit words only, see [`init_sections`].
+///
+/// ```
+/// fill_sections!(0xA5A5_A5A5; axi_buffers: ecc64);
+/// ```
+macro_rules! fill_sections {
+    ($value:expr; $($(#[$attr:meta])* $section_name:ident$(: $mode:ident)?$(,)?)+) => {{
+        $crate::sections_unique!([$([$(#[$attr])* $section_name])*] $);
+
+        $(
+            $crate::section_fill_with_prefixes!($value; $(#[$attr])* $section_name(__s, __e)$(: $mode)?);
+        )*
+    }};
+}
+
+#[macro_export]
+/// Fills stack memory with a pattern, e.g. to measure stack usage by [`stack_watermark`] later.
+///
+/// This macro accepts the stack bottom (lowest address) symbol, the stack top (highest address)
+/// symbol and the fill word, and returns the number of bytes painted. The symbol names are given
+/// explicitly as they vary between runtimes, e.g. `cortex-m-rt` uses `_stack_end` for the bottom
+/// and `_stack_start` for the top.
+///
+/// ```
+/// paint_stack!(_stack_end, _stack_start, 0xCCCC_CCCC);
+/// ```
+///
+/// The stack is painted from the bottom up to 64 bytes below the current stack pointer, so the
+/// frame running the macro is not clobbered. It shall be usually called in pre-init function.
+///
+/// # Safety
+///
+/// Nothing shall be kept in the stack memory below the current stack pointer, e.g. no other
+/// stack shall share the region.
+macro_rules! paint_stack {
+    ($bottom:ident, $top:ident, $pattern:expr$(,)?) => {
+        $crate::scoped!(fn(pattern: u32 = $pattern) -> usize {
+            $crate::pointer_mut!($bottom);
+            $crate::pointer!($top);
+
+            unsafe {
+                $crate::stack_paint(
+                    $crate::symbol_mut!($bottom),
+                    $crate::symbol!($top),
+                    $crate::stack_pointer(),
+                    pattern,
+                )
+            }
+        })
+    };
+}
+
+#[macro_export]
+/// Keeps linker sections in the binary even if nothing references them.
+///
+/// This macro accepts linker section names as arguments and places a zero-sized `#[used]` anchor
+/// static named `__keep_<section>` into each of them. The anchor is retained with
+/// `--gc-sections`, so the section is emitted and its boundary symbols keep their placement even
+/// if all the other statics in the section are discarded, e.g. lookup tables consumed only via
+/// raw pointers after the section initialization. It shall be called at module level.
+///
+/// ```
+/// keep_section_symbols!(custom_data, lookup_tables);
+/// ```
+///
+/// The anchor keeps its own input section only. To keep all the statics placed in the section,
+/// the linker script shall wrap the section inputs by `KEEP`. The matching guidance string could
+/// be defined as a constant, e.g. for a `build.rs` generating the linker script.
+///
+/// ```
+/// keep_section_symbols!(custom_data => CUSTOM_DATA_KEEP);
+///
+/// assert_eq!(CUSTOM_DATA_KEEP, "KEEP(*(.custom_data .custom_data.*));");
+/// ```
+macro_rules! keep_section_symbols {
+    ($($(#[$attr:meta])* $section_name:ident $(=> $keep:ident)?$(,)?)+) => {
+        $(
+            $crate::with_eager_expansions! {
+                $crate::section_keep!(
+                    $(#[$attr])* $section_name(#{ concat_idents!(__keep_, $section_name) })
+                    $(=> $keep)?
+                );
+            }
+        )+
+    };
+}
+
+#[macro_export]
+/// Declares linker section memory deliberately left uninitialized at boot.
+///
+/// This macro shall be called at module scope and accepts linker section name followed by the
+/// type of its content. It assumes the linker symbols are named after given section name
+/// prefixed with
+///  - `__s` for section start,
+///  - `__e` for section end.
+///
+/// The macro defines a function named after the section returning the section memory as
+/// `&'static mut MaybeUninit<T>`. The function returns `Some` on the first call only and `None`
+/// afterwards, so the memory is obtained safely exactly once. Any content survives a software
+/// reset, e.g. crash logs or reboot counters, but it is undefined at power-up.
+///
+/// ```
+/// declare_noinit_section!(crash_log: [u8; 1024]);
+///
+/// let crash_log = crash_log().unwrap();
+/// ```
+///
+/// Symbol prefixes could be given the same way as for [`init_sections_with_prefixes`].
+///
+/// ```
+/// declare_noinit_section!(pub crash_log(__s, __e): [u8; 1024]);
+/// ```
+///
+/// With `debug_assertions` enabled, the function panics if the type does not fit between the
+/// section boundary symbols or if the section start is not aligned for the type.
+///
+/// On cores without atomic swap, e.g. Cortex-M0, the guard is checked and set by a plain load and
+/// store, enable `critical-section` feature if the function may be called by an interrupt handler.
+///
+/// The section shall be `NOLOAD` and it shall reserve enough space, as nothing is placed into it.
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .crash_log (NOLOAD) : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __scrash_log = .;
+///         . += 1024;
+///         . = ALIGN(4);
+///         __ecrash_log = .;
+///     } > RAM
+/// } INSERT AFTER .uninit;
+/// ```
+macro_rules! declare_noinit_section {
+    ($(#[$attr:meta])* $vis:vis $section_name:ident: $ty:ty) => {
+        $crate::declare_noinit_section!($(#[$attr])* $vis $section_name(__s, __e): $ty);
+    };
+    ($(#[$attr:meta])* $vis:vis $section_name:ident($beg:ident$(,)? $end:ident$(,)?): $ty:ty) => {
+        $crate::with_eager_expansions! {
+            $crate::section_noinit_with_symbols!(
+                $(#[$attr])* $vis $section_name(
+                    #{ concat_idents!($beg, $section_name) },
+                    #{ concat_idents!($end, $section_name) }
+                ): $ty
+            );
+        }
+    };
+}
+
+#[macro_export]
+/// Declares linker section retaining its content across resets, e.g. in a battery-backed SRAM.
+///
+/// This macro shall be called at module scope and accepts linker section name, optionally
+/// followed by symbol prefixes the same way as [`init_sections_with_prefixes`]. The macro
+/// defines a function named after the section returning [`PersistentSection`].
+///
+/// The last word of the section holds CRC32 of the preceding section content.
+/// [`PersistentSection::init`] loads the defaults from the section LMA only if the CRC does not
+/// match, i.e. on the first power-up or if the content is corrupt, and rewrites the CRC then.
+/// It could be called in pre-init, whether the defaults were loaded could be read later by
+/// [`PersistentSection::defaults_loaded`].
+///
+/// ```
+/// persistent_section!(settings);
+///
+/// #[cortex_m_rt::pre_init]
+/// unsafe fn pre_init() {
+///     unsafe { settings().init() };
+/// }
+///
+/// if settings().defaults_loaded() {
+///     defmt::info!("settings restored to defaults");
+/// }
+/// ```
+///
+/// Whenever the section content is modified, [`PersistentSection::update_crc`] shall be called,
+/// otherwise the defaults are loaded on the next boot.
+///
+/// The trailer word shall be reserved at the end of the section by the linker script.
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .settings : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __ssettings = .;
+///         *(.settings .settings.*);
+///         . = ALIGN(4);
+///         LONG(0);
+///         __esettings = .;
+///     } > BACKUP_SRAM AT>FLASH
+///     __sisettings = LOADADDR(.settings);
+/// } INSERT AFTER .uninit;
+/// ```
+///
+/// The flag telling whether the defaults were loaded is placed in `.uninit.linker_sections_flag`
+/// section, so it is not cleared by the runtime after pre-init.
+macro_rules! persistent_section {
+    ($(#[$attr:meta])* $vis:vis $section_name:ident) => {
+        $crate::persistent_section!($(#[$attr])* $vis $section_name(__s, __e, __si));
+    };
+    ($(#[$attr:meta])* $vis:vis $section_name:ident($beg:ident$(,)? $end:ident$(,)? $src:ident$(,)?)) => {
+        $crate::with_eager_expansions! {
+            $crate::section_persistent_with_symbols!(
+                $(#[$attr])* $vis $section_name(
+                    #{ concat_idents!($beg, $section_name) },
+                    #{ concat_idents!($end, $section_name) },
+                    #{ concat_idents!($src, $section_name) }
+                )
+            );
+        }
+    };
+}
+
+#[cfg(feature = "static-cell")]
+#[macro_export]
+/// Defines static placed into given linker section, e.g. a `StaticCell`.
+///
+/// This macro shall be called at module scope and accepts the section name including the leading
+/// dot followed by a static definition. Available with `static-cell` feature only.
+///
+/// ```
+/// section_static!(".custom_data", BUFFERS: StaticCell<[u32; 256]> = StaticCell::new());
+/// ```
+///
+/// expands to
+///
+/// ```
+/// #[unsafe(link_section = ".custom_data")]
+/// static BUFFERS: StaticCell<[u32; 256]> = StaticCell::new();
+/// ```
+///
+/// followed by a compile-time check the section name is valid and the static occupies some
+/// section memory.
+///
+/// The static content, e.g. the `StaticCell` state, is meaningless until the section is
+/// initialized by [`init_sections`] or any other macro of this crate, so the static shall not be
+/// accessed before.
+macro_rules! section_static {
+    ($section:literal, $(#[$attr:meta])* $vis:vis $name:ident: $ty:ty = $init:expr$(;)?) => {
+        $(#[$attr])*
+        #[unsafe(link_section = $section)]
+        $vis static $name: $ty = $init;
+
+        const _: () = {
+            assert!(
+                $crate::section_name_valid($section),
+                concat!("invalid section name \"", $section, "\", expected e.g. \".custom_data\"")
+            );
+            assert!(
+                core::mem::size_of::<$ty>() != 0,
+                concat!("zero-sized static `", stringify!($name), "` occupies no section memory")
+            );
+        };
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[macro_export]
+/// Returns start address and size of linker section backing a heap.
+///
+/// This macro accepts linker section name, optionally followed by symbol prefixes the same way as
+/// [`zero_sections_with_prefixes`], and returns `(usize, usize)` tuple of the section start and
+/// its size in bytes. It assumes the linker symbols are named after given section name prefixed
+/// with
+///  - `__s` for section start,
+///  - `__e` for section end.
+///
+/// The section is not initialized at all, so it shall be `NOLOAD` and it shall not be listed in
+/// any other macro of this crate. Available with `alloc` feature only.
+///
+/// ```
+/// let (start, size) = heap_section!(heap_region);
+/// unsafe { HEAP.init(start, size) };
+/// ```
+///
+/// # Panics
+///
+/// Panics if the section is empty or if its start or end is not 4-byte aligned.
+macro_rules! heap_section {
+    ($section_name:ident) => {
+        $crate::heap_section!($section_name(__s, __e))
+    };
+    ($section_name:ident($beg:ident$(,)? $end:ident$(,)?)) => {
+        $crate::with_eager_expansions! {
+            $crate::section_heap_with_symbols!(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) }
+            )
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[macro_export]
+/// Initializes heap allocator with linker section memory.
+///
+/// This macro accepts the heap allocator, e.g. `embedded_alloc::LlffHeap`, followed by the same
+/// arguments as [`heap_section`], and calls the heap `init(start, size)` method. The heap is
+/// initialized at most once per macro call site, the macro returns `true` if the heap was
+/// initialized by this call. Available with `alloc` feature only.
+///
+/// ```
+/// #[global_allocator]
+/// static HEAP: LlffHeap = LlffHeap::empty();
+///
+/// init_heap!(HEAP, heap_region);
+/// ```
+///
+/// The heap section could be defined as
+///
+/// ```text
+/// SECTIONS
+/// {
+///     .heap_region (NOLOAD) : ALIGN(4)
+///     {
+///         . = ALIGN(4);
+///         __sheap_region = .;
+///         . += 16K;
+///         . = ALIGN(4);
+///         __eheap_region = .;
+///     } > HEAP_RAM
+/// } INSERT AFTER .uninit;
+/// ```
+///
+/// # Panics
+///
+/// Same as [`heap_section`].
+macro_rules! init_heap {
+    ($heap:expr, $($section:tt)+) => {{
+        mod __linker_sections {
+            static TAKEN: $crate::TakeGuard = $crate::TakeGuard::new();
+
+            pub(super) fn take() -> bool {
+                TAKEN.take()
+            }
+
+            pub(super) fn region() -> (usize, usize) {
+                $crate::heap_section!($($section)+)
+            }
+        }
+
+        if __linker_sections::take() {
+            let (start, size) = __linker_sections::region();
+            unsafe { $heap.init(start, size) };
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_with_prefixes {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?) => {
+        $crate::with_eager_expansions! {
+            $crate::section_init_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) },
+                #{ concat_idents!($src, $section_name) }
+                $(, $optional)?
+            ) $(@width($($width)+))? $(@zero_to($tail))? $(@verify($($verify)+))? $(@canary($($canary)+))? $(@clean_dcache($dcache))? $(@code($code))? $(@no_barrier($no_barrier))?);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_with_prefixes {
+    ($section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?)) => {
+        $crate::with_eager_expansions! {
+            $crate::section_with_symbols!(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) },
+                #{ concat_idents!($src, $section_name) }
+                $(, $optional)?
+            )
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_with_suffixes {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident)) => {
+        $crate::with_eager_expansions! {
+            $crate::section_init_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!($section_name, $beg) },
+                #{ concat_idents!($section_name, $end) },
+                #{ concat_idents!($section_name, $src) }
+            ));
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_with_symbols {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) @width($width:ident verify_writes($handler:path$(, $retries:expr)?$(,)?)) $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?) => {
+        $(#[$attr])*
+        $crate::scoped!(fn(
+            handler: fn(usize) = $handler,
+            retries: usize = $crate::verify_write_retries!($($retries)?),
+            $(canary_magic: $crate::Word = $crate::canary_magic!($($canary)+),)?
+        ) -> () {
+            let bench = $crate::section_bench_start();
+            let section: $crate::Section = $crate::section_with_symbols!($beg, $end, $src$(, $optional)?);
+            $crate::section_init_guard(stringify!($section_name), section.start().addr(), section.end().addr());
+
+            $crate::section_canary!(section(canary_magic) [$($($canary)+)?] {
+                $crate::section_copy!(section(stringify!($section_name)) [$($optional)?] [$width verify_writes(handler, retries)]);
+                $crate::section_verify!(section($section_name, (), bench) [$width verify_writes] [$($($verify)+)?]);
+                $crate::section_zero_tail!(section [$($tail)?] [$($optional)?]);
+                $crate::section_clean_dcache!(section [$($tail)?] [$($dcache)?]);
+                $crate::section_sync_code!(section [$($tail)?] [$($code)?]);
+            });
+            $crate::section_bench_record(stringify!($section_name), bench);
+        });
+    };
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) @width(compressed($codec:ident$(, $handler:path)?)) $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?) => {
+        $(#[$attr])*
+        $crate::scoped!(fn(
+            handler: fn($crate::$codec::Error) = $crate::compressed_handler!($codec $($handler)?),
+            $(canary_magic: $crate::Word = $crate::canary_magic!($($canary)+),)?
+        ) -> () {
+            let bench = $crate::section_bench_start();
+            let section: $crate::Section = $crate::section_with_symbols!($beg, $end, $src$(, $optional)?);
+            $crate::section_init_guard(stringify!($section_name), section.start().addr(), section.end().addr());
+
+            $crate::section_canary!(section(canary_magic) [$($($canary)+)?] {
+                $crate::section_copy!(section(stringify!($section_name)) [$($optional)?] [compressed($codec, handler)]);
+                $crate::section_verify!(section($section_name, (), bench) [compressed] [$($($verify)+)?]);
+                $crate::section_zero_tail!(section [$($tail)?] [$($optional)?]);
+                $crate::section_clean_dcache!(section [$($tail)?] [$($dcache)?]);
+                $crate::section_sync_code!(section [$($tail)?] [$($code)?]);
+            });
+            $crate::section_bench_record(stringify!($section_name), bench);
+        });
+    };
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident$(, $optional:ident)?) $(@width($($width:tt)+))? $(@zero_to($tail:ident))? $(@verify($($verify:tt)+))? $(@canary($($canary:tt)+))? $(@clean_dcache($dcache:ident))? $(@code($code:ident))? $(@no_barrier($no_barrier:ident))?) => {
+        $(#[$attr])*
+        $crate::scoped!(fn(
+            verified: Option<&'static $crate::VerifiedFlag> = $crate::verified_flag!($($($verify)+)?),
+            $(canary_magic: $crate::Word = $crate::canary_magic!($($canary)+),)?
+        ) -> () {
+            let bench = $crate::section_bench_start();
+            let section: $crate::Section = $crate::section_with_symbols!($beg, $end, $src$(, $optional)?);
+            $crate::section_init_guard(stringify!($section_name), section.start().addr(), section.end().addr());
+
+            $crate::section_canary!(section(canary_magic) [$($($canary)+)?] {
+                $crate::section_copy!(section(stringify!($section_name)) [$($optional)?] [$($($width)+)?]);
+                $crate::section_verify!(section($section_name, verified, bench) [$($($width)+)?] [$($($verify)+)?]);
+                $crate::section_zero_tail!(section [$($tail)?] [$($optional)?]);
+                $crate::section_clean_dcache!(section [$($tail)?] [$($dcache)?]);
+                $crate::section_sync_code!(section [$($tail)?] [$($code)?]);
+            });
+            $crate::section_bench_record(stringify!($section_name), bench);
+        });
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_copy {
+    (in_place $section:ident($name:expr) [$($optional:ident)?] []) => {
+        unsafe {
+            $crate::section_init_in_place($section);
+        }
+    };
+    (in_place $section:ident($name:expr) [$($optional:ident)?] [$width:ident verify_writes $verify:tt]) => {
+        compile_error!("`verify_writes` cannot be combined with `in <section>` or inline hints");
+    };
+    (in_place $section:ident($name:expr) [$($optional:ident)?] [compressed $compressed:tt]) => {
+        compile_error!("`compressed` cannot be combined with `in <section>` or inline hints");
+    };
+    (in_place $section:ident($name:expr) [$($optional:ident)?] [sparse]) => {
+        compile_error!("`sparse` cannot be combined with `in <section>` or inline hints");
+    };
+    (in_place $section:ident($name:expr) [$($optional:ident)?] [$($width:tt)+]) => {
+        $crate::section_copy!($section($name) [$($optional)?] [$($width)+]);
+    };
+    ($section:ident($name:expr) [optional] [$($width:tt)*]) => {
+        if !$section.is_empty() {
+            $crate::section_copy!($section($name) [] [$($width)*]);
+        }
+    };
+    ($section:ident($name:expr) [] []) => {
+        unsafe {
+            $crate::section_init_named($section.start(), $section.end(), $section.load(), $name);
+        }
+    };
+    ($section:ident($name:expr) [] [$width:ident verify_writes($handler:ident, $retries:ident)]) => {{
+        let mismatch = unsafe {
+            $crate::section_init_verify::<$crate::section_width_type!($width)>(
+                $section.start(),
+                $section.end(),
+                $section.load(),
+                $retries,
+            )
+        };
+
+        if let Some(address) = mismatch {
+            $handler(address);
+        }
+    }};
+    ($section:ident($name:expr) [] [compressed(lz4, $handler:ident)]) => {
+        if let Err(error) = unsafe {
+            $crate::section_init_lz4($section.start(), $section.end(), $section.load())
+        } {
+            $handler(error);
+        }
+    };
+    ($section:ident($name:expr) [] [compressed(heatshrink, $handler:ident)]) => {
+        if let Err(error) = unsafe {
+            $crate::section_init_heatshrink($section.start(), $section.end(), $section.load())
+        } {
+            $handler(error);
+        }
+    };
+    ($section:ident($name:expr) [] [compressed(rle, $handler:ident)]) => {
+        if let Err(error) = unsafe {
+            $crate::section_init_rle($section.start(), $section.end(), $section.load())
+        } {
+            $handler(error);
+        }
+    };
+    ($section:ident($name:expr) [] [compressed($codec:ident, $handler:ident)]) => {
+        compile_error!(concat!(
+            "unsupported compression `",
+            stringify!($codec),
+            "`, expected `heatshrink`, `lz4` or `rle`"
+        ));
+    };
+    ($section:ident($name:expr) [] [sparse]) => {
+        unsafe {
+            $crate::section_init_sparse($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [u8]) => {
+        unsafe {
+            $crate::section_init_u8($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [u16]) => {
+        unsafe {
+            $crate::section_init_u16($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [pma $($stride:tt)*]) => {
+        $crate::section_copy!($section($name) [] [u16 $($stride)*]);
+    };
+    ($section:ident($name:expr) [] [u16 stride $stride:literal]) => {
+        unsafe {
+            $crate::section_init_u16_strided($section.start(), $section.end(), $section.load(), $stride);
+        }
+    };
+    ($section:ident($name:expr) [] [$width:ident stride $stride:literal]) => {
+        compile_error!(concat!(
+            "unsupported strided copy width `",
+            stringify!($width),
+            "`, expected `u16` or `pma`"
+        ));
+    };
+    ($section:ident($name:expr) [] [u32]) => {
+        unsafe {
+            $crate::section_init_u32($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [ecc]) => {
+        $crate::section_copy!($section($name) [] [u32]);
+    };
+    ($section:ident($name:expr) [] [ecc64]) => {
+        unsafe {
+            $crate::section_init_u64($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [widen8to32]) => {
+        unsafe {
+            $crate::section_init_widen(
+                $section.start().cast(),
+                $section.end().cast(),
+                $section.load().cast(),
+            );
+        }
+    };
+    ($section:ident($name:expr) [] [swap16]) => {
+        unsafe {
+            $crate::section_init_swap16($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [swap32]) => {
+        unsafe {
+            $crate::section_init_swap32($section.start(), $section.end(), $section.load());
+        }
+    };
+    ($section:ident($name:expr) [] [$width:ident]) => {
+        compile_error!(concat!(
+            "unsupported copy width `",
+            stringify!($width),
+            "`, expected `u8`, `u16`, `u32`, `pma`, `ecc`, `ecc64`, `swap16`, `swap32` or `widen8to32`"
+        ));
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_verify {
+    (in_place $section:ident($name:expr) [$($width:tt)*] []) => {};
+    (in_place $section:ident($name:expr) [$($width:tt)*] [$marker:ident $($flag:tt)*]) => {
+        compile_error!(concat!(
+            "`",
+            stringify!($marker),
+            "` cannot be combined with `in <section>` or inline hints"
+        ));
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [$($width:tt)*] []) => {
+        let _ = $verified;
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [$($width:tt)*] [crc32_verify]) => {
+        let _ = $verified;
+        $crate::section_verify!(@checksum $section($section_name, $bench) [$($width)*] ("crc32_verify", __crc, section_crc32_verify, "crc32"));
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [$($width:tt)*] [fletcher32_verify]) => {
+        let _ = $verified;
+        $crate::section_verify!(@checksum $section($section_name, $bench) [$($width)*] ("verify(fletcher32)", __fletcher, section_fletcher32_verify, "fletcher32"));
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [$(u32)?] [$($verify:tt)+]) => {
+        $crate::section_verify!(@compare $section($section_name, $verified));
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [ecc] [$($verify:tt)+]) => {
+        $crate::section_verify!(@compare $section($section_name, $verified));
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [ecc64] [$($verify:tt)+]) => {
+        $crate::section_verify!(@compare $section($section_name, $verified));
+    };
+    ($section:ident($section_name:ident, $verified:expr, $bench:ident) [$($width:tt)+] [$($verify:tt)+]) => {
+        compile_error!(concat!(
+            "`verify` cannot be combined with `",
+            stringify!($($width)+),
+            "`, expected no width, `u32`, `ecc` or `ecc64`"
+        ));
+    };
+    (@compare $section:ident($section_name:ident, $verified:expr)) => {
+        unsafe {
+            $crate::section_verify(
+                $section.start(),
+                $section.end(),
+                $section.load(),
+                stringify!($section_name),
+                $verified,
+            );
+        }
+    };
+    (@checksum $section:ident($section_name:ident, $bench:ident) [$(u32)?] $checksum:tt) => {
+        $crate::section_verify!(@symbol $section($section_name, $bench) $checksum);
+    };
+    (@checksum $section:ident($section_name:ident, $bench:ident) [ecc] $checksum:tt) => {
+        $crate::section_verify!(@symbol $section($section_name, $bench) $checksum);
+    };
+    (@checksum $section:ident($section_name:ident, $bench:ident) [ecc64] $checksum:tt) => {
+        $crate::section_verify!(@symbol $section($section_name, $bench) $checksum);
+    };
+    (@checksum $section:ident($section_name:ident, $bench:ident) [compressed] $checksum:tt) => {
+        $crate::section_verify!(@symbol $section($section_name, $bench) $checksum);
+    };
+    (@checksum $section:ident($section_name:ident, $bench:ident) [sparse] $checksum:tt) => {
+        $crate::section_verify!(@symbol $section($section_name, $bench) $checksum);
+    };
+    (@checksum $section:ident($section_name:ident, $bench:ident) [$($width:tt)+] ($marker:literal, $prefix:ident, $verify:ident, $label:literal)) => {
+        compile_error!(concat!(
+            "`",
+            $marker,
+            "` cannot be combined with `",
+            stringify!($($width)+),
+            "`, expected no width, `u32`, `ecc`, `ecc64`, `compressed` or `sparse`"
+        ));
+    };
+    (@symbol $section:ident($section_name:ident, $bench:ident) ($marker:literal, $prefix:ident, $verify:ident, $label:literal)) => {
+        $crate::with_eager_expansions! {
+            $crate::section_verify!(@symbol $section($section_name, $bench) $verify($label) #{ concat_idents!($prefix, $section_name) });
+        }
+    };
+    (@symbol $section:ident($section_name:ident, $bench:ident) $verify:ident($label:literal) $expected:ident) => {{
+        $crate::pointer!($expected);
+
+        let split = $crate::section_bench_split($bench);
+        unsafe {
+            $crate::$verify(
+                $section.start(),
+                $section.end(),
+                $crate::symbol!($expected),
+                stringify!($section_name),
+            );
+        }
+        $crate::section_bench_record_split(concat!(stringify!($section_name), ":", $label), split);
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_canary {
+    (in_place []) => {};
+    (in_place [$($canary:tt)+]) => {
+        compile_error!("`canary` cannot be combined with `in <section>` or inline hints");
+    };
+    ($section:ident($magic:ident) [] { $($init:tt)* }) => {
+        $($init)*
+    };
+    ($section:ident($magic:ident) [$($canary:tt)+] { $($init:tt)* }) => {
+        // the closure is made outside the unsafe block, so the init code keeps its own ones
+        let init = || { $($init)* };
+        let cell: *mut $crate::Word = $crate::canary_cell!($section $($canary)+);
+
+        unsafe {
+            $crate::section_canary(&$section, cell, $magic, init);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! canary_cell {
+    (@at $section:ident) => {
+        $section.end().cast_mut()
+    };
+    (@at $section:ident $cell:ident) => {{
+        $crate::pointer_mut!($cell);
+        $crate::symbol_mut!($cell)
+    }};
+    ($section:ident canary) => {
+        $crate::canary_cell!(@at $section)
+    };
+    ($section:ident canary($(at $cell:ident)?$(,)? $(magic = $magic:expr)?$(,)?)) => {
+        $crate::canary_cell!(@at $section $($cell)?)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! canary_magic {
+    (canary) => {
+        $crate::CANARY
+    };
+    (canary($(at $cell:ident)?$(,)? magic = $magic:expr$(,)?)) => {
+        $magic
+    };
+    (canary($(at $cell:ident)?$(,)?)) => {
+        $crate::CANARY
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! verified_flag {
+    ($(verify)? $(crc32_verify)? $(fletcher32_verify)?) => {
+        None
+    };
+    (verify $flag:path) => {
+        Some(&$flag)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! compressed_handler {
+    ($codec:ident) => {
+        $crate::$codec::failed
+    };
+    ($codec:ident $handler:path) => {
+        $handler
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! verify_write_retries {
+    () => {
+        $crate::VERIFY_WRITE_RETRIES
+    };
+    ($retries:expr) => {{
+        const RETRIES: usize = $retries;
+        RETRIES
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_width_type {
+    (word) => {
+        $crate::Word
+    };
+    (u8) => {
+        u8
+    };
+    (u16) => {
+        u16
+    };
+    (pma) => {
+        u16
+    };
+    (u32) => {
+        u32
+    };
+    (ecc) => {
+        u32
+    };
+    (ecc64) => {
+        u64
+    };
+    ($width:ident) => {
+        compile_error!(concat!(
+            "unsupported verified copy width `",
+            stringify!($width),
+            "`, expected `u8`, `u16`, `u32`, `pma`, `ecc` or `ecc64`"
+        ))
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_without_load {
+    ($section_name:ident) => {
+        $crate::section_without_load!($section_name(__s, __e))
+    };
+    ($section_name:ident($beg:ident, $end:ident)) => {
+        $crate::with_eager_expansions! {
+            {
+                $crate::pointer_mut!(#{ concat_idents!($beg, $section_name) });
+                $crate::pointer!(#{ concat_idents!($end, $section_name) });
+
+                $crate::Section::new(
+                    $crate::symbol_mut!(#{ concat_idents!($beg, $section_name) }),
+                    $crate::symbol!(#{ concat_idents!($end, $section_name) }),
+                    core::ptr::null(),
+                )
+            }
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_with_symbols {
+    ($beg:ident, $end:ident, $src:ident) => {{
+        $crate::pointer_mut!($beg);
+        $crate::pointer!($end);
+        $crate::pointer!($src);
+
+        $crate::Section::new(
+            $crate::symbol_mut!($beg),
+            $crate::symbol!($end),
+            $crate::symbol!($src),
+        )
+    }};
+    ($beg:ident, $end:ident, $src:ident, optional) => {{
+        $crate::pointer_mut!($beg);
+        $crate::pointer!($end);
+        $crate::pointer!($src);
+
+        // weak symbols resolve to zero if not defined, the directive is emitted in the same
+        // function as the symbol references, so they end up in the same object file
+        unsafe {
+            core::arch::asm!(
+                ".weak {beg}",
+                ".weak {end}",
+                ".weak {src}",
+                beg = sym $beg,
+                end = sym $end,
+                src = sym $src,
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+
+        // the compiler assumes the symbols are distinct non-null addresses otherwise
+        $crate::Section::new(
+            core::hint::black_box($crate::symbol_mut!($beg)),
+            core::hint::black_box($crate::symbol!($end)),
+            core::hint::black_box($crate::symbol!($src)),
+        )
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_gnu {
+    ($(#[$attr:meta])* gnu $section_name:ident) => {
+        $crate::with_eager_expansions! {
+            $crate::section_init_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!(__, $section_name, _start__) },
+                #{ concat_idents!(__, $section_name, _end__) },
+                #{ concat_idents!(__, $section_name, _load_start__) }
+            ));
+        }
+    };
+    ($(#[$attr:meta])* cmsis $section_name:ident) => {
+        $crate::with_eager_expansions! {
+            $crate::section_init_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!(__, $section_name, _start__) },
+                #{ concat_idents!(__, $section_name, _end__) },
+                __etext
+            ));
+        }
+    };
+    ($(#[$attr:meta])* zero $section_name:ident) => {
+        $crate::with_eager_expansions! {
+            $crate::section_zero_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!(__, $section_name, _start__) },
+                #{ concat_idents!(__, $section_name, _end__) }
+            ));
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_len_with_prefixes {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $len:ident, $src:ident)) => {
+        $crate::with_eager_expansions! {
+            $crate::section_init_len_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($len, $section_name) },
+                #{ concat_idents!($src, $section_name) }
+            ));
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_len_with_symbols {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $len:ident, $src:ident)) => {
+        $(#[$attr])*
+        $crate::scoped!(fn() -> () {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($len);
+            $crate::pointer!($src);
+
+            let src: *const $crate::Word = $crate::symbol!($src);
+            let dst: *mut $crate::Word = $crate::symbol_mut!($beg);
+            let len: usize = core::ptr::addr_of!($len).addr();
+            $crate::section_init_guard(stringify!($section_name), dst.addr(), dst.addr() + len);
+
+            unsafe { $crate::section_init_len(dst, len, src); }
+        });
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_fragments_with_prefixes {
+    ($(#[$attr:meta])* $section_name:ident()[$($fragments:tt)+]) => {
+        $crate::section_init_fragments_with_prefixes!($(#[$attr])* $section_name(__s, __e)[$($fragments)+]);
+    };
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident)[$($load:ident..$load_end:ident),+]) => {
+        $crate::with_eager_expansions! {
+            $crate::section_init_fragments_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) }
+            )[$($load..$load_end),+]);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_fragments_with_symbols {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident)[$($load:ident..$load_end:ident),+]) => {
+        $(#[$attr])*
+        $crate::scoped!(fn() -> () {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($end);
+            $(
+                $crate::pointer!($load);
+                $crate::pointer!($load_end);
+            )+
+
+            let dst: *mut $crate::Word = $crate::symbol_mut!($beg);
+            let end: *const $crate::Word = $crate::symbol!($end);
+            let fragments: &[(*const $crate::Word, *const $crate::Word)] =
+                &[$(($crate::symbol!($load), $crate::symbol!($load_end))),+];
+            $crate::section_init_guard(stringify!($section_name), dst.addr(), end.addr());
+
+            unsafe { $crate::section_init_fragments(dst, end, fragments); }
+        });
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_init_raw {
+    ($beg:literal, $end:literal, $src:literal) => {
+        $crate::scoped!(fn() -> () {
+            unsafe extern "C" {
+                #[link_name = $beg]
+                static mut BEG: $crate::Symbol;
+                #[link_name = $end]
+                static END: $crate::Symbol;
+                #[link_name = $src]
+                static SRC: $crate::Symbol;
+            }
+
+            let src: *const $crate::Word = $crate::symbol!(SRC);
+            let dst: *mut $crate::Word = $crate::symbol_mut!(BEG);
+            let end: *const $crate::Word = $crate::symbol!(END);
+
+            unsafe {
+                $crate::section_init(dst, end, src);
+            }
+        })
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_zero_with_prefixes {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident)$(: $mode:ident)?) => {
+        $crate::with_eager_expansions! {
+            $crate::section_zero_with_symbols!($(#[$attr])* $section_name(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) }
+            )$(: $mode)?);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_zero_with_symbols {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident)) => {
+        $(#[$attr])*
+        $crate::scoped!(fn() -> () {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($end);
+
+            let dst: *mut $crate::Word = $crate::symbol_mut!($beg);
+            let end: *const $crate::Word = $crate::symbol!($end);
+
+            unsafe {
+                $crate::section_zero(dst, end);
+            }
+        });
+    };
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident): $mode:ident) => {
+        $(#[$attr])*
+        $crate::scoped!(fn() -> () {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($end);
+
+            let dst: *mut $crate::Word = $crate::symbol_mut!($beg);
+            let end: *const $crate::Word = $crate::symbol!($end);
+
+            $crate::section_fill_mode!(dst, end, 0, $mode);
+        });
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_zero_tail {
+    ($section:ident [] [$($optional:ident)?]) => {};
+    ($section:ident [$tail:ident] []) => {{
+        $crate::pointer!($tail);
+
+        unsafe {
+            $crate::section_zero_tail($section.end(), $crate::symbol!($tail));
+        }
+    }};
+    ($section:ident [$tail:ident] [optional]) => {
+        if !$section.is_empty() {
+            $crate::section_zero_tail!($section [$tail] []);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_clean_dcache {
+    ($section:ident [$($tail:ident)?] []) => {};
+    ($section:ident [] [$dcache:ident]) => {
+        unsafe {
+            $crate::section_clean_dcache($section.start(), $section.end());
+        }
+    };
+    ($section:ident [$tail:ident] [$dcache:ident]) => {{
+        $crate::pointer!($tail);
+
+        unsafe {
+            $crate::section_clean_dcache($section.start(), $crate::symbol!($tail));
+        }
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_sync_code {
+    ($section:ident [$($tail:ident)?] []) => {};
+    ($section:ident [] [$code:ident]) => {
+        unsafe {
+            $crate::section_sync_code($section.start(), $section.end());
+        }
+    };
+    ($section:ident [$tail:ident] [$code:ident]) => {{
+        $crate::pointer!($tail);
+
+        unsafe {
+            $crate::section_sync_code($section.start(), $crate::symbol!($tail));
+        }
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sections_barrier {
+    ([$([no_barrier])*]) => {};
+    ([$($no_barrier:tt)*]) => {
+        $crate::sections_barrier();
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_fill_with_prefixes {
+    ($value:expr; $(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident)$(: $mode:ident)?) => {
+        $crate::with_eager_expansions! {
+            $crate::section_fill_with_symbols!($value; $(#[$attr])* $section_name(
+                #{ concat_idents!($beg, $section_name) },
+                #{ concat_idents!($end, $section_name) }
+            )$(: $mode)?);
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_fill_with_symbols {
+    ($value:expr; $(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident)$(: $mode:ident)?) => {
+        $(#[$attr])*
+        $crate::scoped!(fn(value: $crate::Word = { const VALUE: $crate::Word = $value; VALUE }) -> () {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($end);
+
+            let dst: *mut $crate::Word = $crate::symbol_mut!($beg);
+            let end: *const $crate::Word = $crate::symbol!($end);
+
+            $crate::section_fill_mode!(dst, end, value, $($mode)?);
+        });
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_fill_mode {
+    ($dst:ident, $end:ident, $value:ident,) => {
+        unsafe {
+            $crate::section_fill($dst, $end, $value);
+        }
+    };
+    ($dst:ident, $end:ident, $value:tt, ecc) => {
+        unsafe {
+            $crate::section_fill_u32($dst, $end, $value);
+        }
+    };
+    ($dst:ident, $end:ident, $value:tt, ecc64) => {
+        unsafe {
+            $crate::section_fill_u64($dst, $end, $value);
+        }
+    };
+    ($dst:ident, $end:ident, $value:tt, $mode:ident) => {
+        compile_error!(concat!(
+            "unsupported fill mode `",
+            stringify!($mode),
+            "`, expected `ecc` or `ecc64`"
+        ));
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_register_with_symbols {
+    ($(#[$attr:meta])* $section_name:ident($beg:ident, $end:ident, $src:ident)) => {
+        $(#[$attr])*
+        const _: () = {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($end);
+            $crate::pointer!($src);
+
+            #[used]
+            #[unsafe(link_section = ".linker_sections_table")]
+            // a static is initialized at compile time, so the symbol pointers are cast only
+            static DESCRIPTOR: $crate::SectionDescriptor = $crate::SectionDescriptor {
+                start: core::ptr::addr_of_mut!($beg).cast(),
+                end: core::ptr::addr_of!($end).cast(),
+                load: core::ptr::addr_of!($src).cast(),
+            };
+        };
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_keep {
+    ($(#[$attr:meta])* $section_name:ident($anchor:ident) => $keep:ident) => {
+        $crate::section_keep!($(#[$attr])* $section_name($anchor));
+
+        $(#[$attr])*
+        #[doc = concat!("Linker script `KEEP` guidance of `.", stringify!($section_name), "` section")]
+        #[allow(dead_code)]
+        pub const $keep: &str = concat!(
+            "KEEP(*(.",
+            stringify!($section_name),
+            " .",
+            stringify!($section_name),
+            ".*));"
+        );
+    };
+    ($(#[$attr:meta])* $section_name:ident($anchor:ident)) => {
+        $(#[$attr])*
+        const _: () = {
+            #[used]
+            #[allow(non_upper_case_globals)]
+            #[unsafe(link_section = concat!(".", stringify!($section_name)))]
+            static $anchor: [u32; 0] = [];
+        };
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_noinit_with_symbols {
+    ($(#[$attr:meta])* $vis:vis $section_name:ident($beg:ident, $end:ident): $ty:ty) => {
+        $(#[$attr])*
+        $vis fn $section_name() -> Option<&'static mut core::mem::MaybeUninit<$ty>> {
+            mod __linker_sections {
+                static TAKEN: $crate::TakeGuard = $crate::TakeGuard::new();
+
+                pub(super) fn take<T>() -> Option<&'static mut core::mem::MaybeUninit<T>> {
+                    $crate::pointer_mut!($beg);
+                    $crate::pointer!($end);
+
+                    if !TAKEN.take() {
+                        return None;
+                    }
+
+                    let start: *mut core::mem::MaybeUninit<T> = $crate::symbol_mut!($beg);
+                    let end: *const u8 = $crate::symbol!($end);
+                    $crate::noinit_check(start, end);
+
+                    // SAFETY: The memory is reserved for the section and it is handed out only once
+                    Some(unsafe { &mut *start })
+                }
+            }
+
+            __linker_sections::take::<$ty>()
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_persistent_with_symbols {
+    ($(#[$attr:meta])* $vis:vis $section_name:ident($beg:ident, $end:ident, $src:ident)) => {
+        $(#[$attr])*
+        $vis fn $section_name() -> $crate::PersistentSection {
+            $crate::pointer_mut!($beg);
+            $crate::pointer!($end);
+            $crate::pointer!($src);
+
+            #[unsafe(link_section = ".uninit.linker_sections_flag")]
+            static FLAG: $crate::PersistentFlag = $crate::PersistentFlag::new();
+
+            $crate::PersistentSection::new(
+                $crate::Section::new(
+                    $crate::symbol_mut!($beg),
+                    $crate::symbol!($end),
+                    $crate::symbol!($src),
+                ),
+                &FLAG,
+            )
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! section_heap_with_symbols {
+    ($beg:ident, $end:ident) => {{
+        $crate::pointer!($beg);
+        $crate::pointer!($end);
+
+        $crate::heap_region($crate::symbol!($beg), $crate::symbol!($end))
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sections_unique {
+    ([] $d:tt) => {};
+    ([[$(#[$attr:meta])* $section_name:ident] $([$(#[$other_attr:meta])* $other_name:ident])*] $d:tt) => {
+        $(#[$attr])*
+        {
+            #[allow(unused_macros)]
+            macro_rules! section_listed_twice {
+                ($section_name) => {
+                    compile_error!(concat!("section `", stringify!($section_name), "` listed twice"));
+                };
+                ($d other_name:ident) => {};
+            }
+
+            $(
+                $(#[$other_attr])*
+                section_listed_twice!($other_name);
+            )*
+        }
+
+        $crate::sections_unique!([$([$(#[$other_attr])* $other_name])*] $d);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sections_not_placed_in {
+    ($place:ident [$([$(#[$attr:meta])* $section_name:ident])*] $d:tt) => {{
+        #[allow(unused_macros)]
+        macro_rules! section_placed_in_itself {
+            ($place) => {
+                compile_error!(concat!(
+                    "section `",
+                    stringify!($place),
+                    "` cannot be initialized by code placed in it"
+                ));
+            };
+            ($d other_name:ident) => {};
+        }
+
+        $(
+            $(#[$attr])*
+            section_placed_in_itself!($section_name);
+        )*
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! scoped {
+    (fn($($arg:ident: $arg_ty:ty = $value:expr),*$(,)?) -> $ret:ty { $($body:tt)* }) => {{
+        // a module does not see the caller's items, so neither the bindings and symbols of the
+        // expansion collide with them nor they leak into the caller's scope
+        mod __linker_sections {
+            #[inline(always)]
+            pub(super) fn expand($($arg: $arg_ty),*) -> $ret {
+                $($body)*
+            }
+        }
+
+        __linker_sections::expand($($value),*)
+    }};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! pointer {
+    ($name:ident) => {
+        unsafe extern "C" {
+            static $name: $crate::Symbol;
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! pointer_mut {
+    ($name:ident) => {
+        unsafe extern "C" {
+            static mut $name: $crate::Symbol;
+        }
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! symbol {
+    ($name:ident) => {
+        $crate::symbol_ptr(core::ptr::addr_of!($name))
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! symbol_mut {
+    ($name:ident) => {
+        $crate::symbol_ptr_mut(core::ptr::addr_of_mut!($name))
+    };
+}
+
+/// Linker section record placed into `.linker_sections_table` by [`register_section`] or into a
+/// table walked by [`init_section_table`].
+#[repr(C)]
+pub struct SectionDescriptor {
+    /// Section VMA's start
+    pub start: *mut Word,
+    /// Section VMA's end
+    pub end: *const Word,
+    /// Section LMA's start
+    pub load: *const Word,
+}
+
+// SAFETY: The descriptor is immutable and it is only read during section initialization
+unsafe impl Sync for SectionDescriptor {}
+
+/// Section table entry, see [`init_section_table`].
+pub type SectionInitEntry = SectionDescriptor;
+
+/// Linker section memory given by its boundaries, see [`section`].
+///
+/// The value only holds section addresses, so it could be copied and stored in a static freely.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Section {
+    start: *mut Word,
+    end: *const Word,
+    load: *const Word,
+}
+
+// SAFETY: The section addresses are never dereferenced except in unsafe `Section::init`
+unsafe impl Sync for Section {}
+// SAFETY: The section addresses are never dereferenced except in unsafe `Section::init`
+unsafe impl Send for Section {}
+
+impl Section {
+    /// Creates section given by VMA's start, VMA's end and LMA's start.
+    #[inline(always)]
+    pub const fn new(start: *mut Word, end: *const Word, load: *const Word) -> Self {
+        Self { start, end, load }
+    }
+
+    /// Returns section VMA's start.
+    #[inline(always)]
+    pub const fn start(&self) -> *mut Word {
+        self.start
+    }
+
+    /// Returns section VMA's end.
+    #[inline(always)]
+    pub const fn end(&self) -> *const Word {
+        self.end
+    }
+
+    /// Returns section LMA's start.
+    #[inline(always)]
+    pub const fn load(&self) -> *const Word {
+        self.load
+    }
+
+    /// Returns section length in bytes, zero if the section end precedes its start.
+    pub fn len_bytes(&self) -> usize {
+        (self.end as usize).saturating_sub(self.start as usize)
+    }
+
+    /// Returns `true` if the section has zero length, e.g. optional section not defined.
+    pub fn is_empty(&self) -> bool {
+        core::ptr::eq(self.start, self.end)
+    }
+
+    /// Initializes section memory if `predicate` is `true`, returns the `predicate`.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`].
+    pub unsafe fn init_if(&self, predicate: bool) -> bool {
+        if predicate {
+            unsafe { self.init() };
+        }
+
+        predicate
+    }
+
+    /// Initializes section memory again at runtime, returns number of bytes restored.
+    ///
+    /// With `critical-section` feature enabled, the memory is copied in a critical section, so no
+    /// interrupt could observe a half-copied section.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], mainly no reference to any variable placed in the section
+    /// shall be alive during the call.
+    pub unsafe fn reinit(&self) -> usize {
+        locked(|| unsafe { self.init() });
+
+        self.len_bytes()
+    }
+
+    /// Initializes section memory with data from section LMA.
+    ///
+    /// # Safety
+    ///
+    /// - The addresses need not be aligned, but only if the section start and LMA are equal modulo
+    ///   4, the section is copied in words.
+    /// - The addresses must point to memory with required access (read for LMA, write for VMA).
+    /// - The section start must be less or equal to section end.
+    /// - The section VMA and LMA must not overlap, unless `overlap-ok` feature is enabled.
+    /// - No reference to any variable placed in the section shall be alive during the call.
+    pub unsafe fn init(&self) {
+        unsafe { section_init(self.start, self.end, self.load) };
+    }
+
+    /// Initializes section memory with data from section LMA in chunks of `chunk_bytes`, calling
+    /// `yield_fn` between the chunks, see [`section_init_chunked`].
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], the section shall not be accessed by `yield_fn` or by any
+    /// other context running while it is called.
+    pub unsafe fn init_chunked(&self, chunk_bytes: usize, yield_fn: impl FnMut()) {
+        unsafe { section_init_chunked(self.start, self.end, self.load, chunk_bytes, yield_fn) };
+    }
+
+    /// Initializes section memory with data from section LMA, the word aligned body is copied by
+    /// `copier`, e.g. by DMA, the rest by the CPU, see [`SectionCopier`].
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], if the copier returns before the copy is done, the section
+    /// shall not be accessed until it is.
+    pub unsafe fn init_with<C: SectionCopier + ?Sized>(&self, copier: &mut C) {
+        unsafe { section_init_with(copier, self.start, self.end, self.load) };
+    }
+
+    /// Initializes section memory with data read from `source` starting at its offset zero, the
+    /// section LMA is not used, see [`SectionSource`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is shorter than the section.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], except the LMA need not point to any memory.
+    pub unsafe fn init_from<S: SectionSource + ?Sized>(
+        &self,
+        source: &mut S,
+    ) -> Result<(), S::Error> {
+        unsafe { section_init_from_source(self.start, self.end, source, usize::MAX, || {}) }
+    }
+
+    /// Initializes section memory with data read from `source` in chunks of `chunk_bytes`,
+    /// calling `yield_fn` between the chunks, see [`section_init_from_source`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is shorter than the section.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init_chunked`], except the LMA need not point to any memory.
+    pub unsafe fn init_chunked_from<S: SectionSource + ?Sized>(
+        &self,
+        source: &mut S,
+        chunk_bytes: usize,
+        yield_fn: impl FnMut(),
+    ) -> Result<(), S::Error> {
+        unsafe { section_init_from_source(self.start, self.end, source, chunk_bytes, yield_fn) }
+    }
+
+    /// Returns the memory mapped section load data as a [`SectionSource`].
+    ///
+    /// # Safety
+    ///
+    /// The section LMA must point to memory readable for the section length as long as the
+    /// source is read.
+    pub unsafe fn mapped_source(&self) -> MappedSource {
+        unsafe { MappedSource::new(self.load.cast(), self.len_bytes()) }
+    }
+}
+
+/// Runs `f` within `critical_section::with` with `critical-section` feature enabled, so no
+/// interrupt could observe a half-copied section, otherwise runs it directly.
+#[inline(always)]
+fn locked<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "critical-section")]
+    return critical_section::with(|_| f());
+
+    #[cfg(not(feature = "critical-section"))]
+    f()
+}
+
+/// Copier of section memory, e.g. by DMA, see [`init_sections_with_copier`].
+///
+/// The copier is given the word aligned body of a section only, i.e. `dst` and `src` are aligned
+/// to [`Word`] and `len` is a multiple of its size, the bytes not forming aligned words are copied
+/// by the CPU. Sections whose start and LMA are not equal modulo [`Word`] size are copied by the
+/// CPU entirely. The alignment and overlap are checked by the crate the same way as for
+/// [`Section::init`].
+///
+/// The copier may return before the copy is done, e.g. once a DMA transfer is started, so the CPU
+/// could do other work in the meantime. It shall then wait for the previous copy before starting
+/// the next one and the sections shall not be accessed until the last copy is done.
+pub trait SectionCopier {
+    /// Copies `len` bytes from `src` to `dst`.
+    ///
+    /// # Safety
+    ///
+    /// - The addresses must point to memory with required access (read for `src`, write for
+    ///   `dst`).
+    /// - The source and destination regions must not overlap.
+    unsafe fn copy(&mut self, dst: *mut u8, src: *const u8, len: usize);
+}
+
+impl<C: SectionCopier + ?Sized> SectionCopier for &mut C {
+    unsafe fn copy(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+        unsafe { (**self).copy(dst, src, len) };
+    }
+}
+
+/// Copier moving memory by the CPU the same way as [`Section::init`] does.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CpuCopier;
+
+impl SectionCopier for CpuCopier {
+    unsafe fn copy(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+        unsafe { copy_bytes(dst, src, len) };
+    }
+}
+
+/// Load data of a section which is not memory mapped, e.g. on an SD card or sent by a host over
+/// a debug link, read by [`Section::init_from`] and the other `*_from` runtime initializations.
+///
+/// The data is read in pieces of at most [`SOURCE_CHUNK`] bytes into a stack buffer and copied
+/// into the section by the same code as [`Section::init`] does, the offsets are relative to the
+/// section start. A read error is returned as soon as it happens, the section is then initialized
+/// up to the piece failed exclusive and the rest of it is not touched.
+///
+/// The memory mapped load data is read through [`MappedSource`], a byte slice is a source as
+/// well.
+///
+/// ```
+/// struct SdImage<'a> {
+///     card: &'a mut SdCard,
+///     first_block: u32,
+///     len: usize,
+/// }
+///
+/// impl SectionSource for SdImage<'_> {
+///     type Error = SdError;
+///
+///     fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), SdError> {
+///         self.card.read_bytes(self.first_block, offset, buf)
+///     }
+///
+///     fn len(&self) -> usize {
+///         self.len
+///     }
+/// }
+///
+/// let assets = section!(sdram_assets(__s, __e));
+/// unsafe { assets.init_from(&mut SdImage { card: &mut card, first_block: 2048, len: ASSETS_LEN }) }?;
+/// ```
+pub trait SectionSource {
+    /// Read error of the source.
+    type Error;
+
+    /// Reads `buf.len()` bytes starting at `offset`.
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Returns the source length in bytes, it shall not be shorter than the sections it is read
+    /// into.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the source has zero length.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<S: SectionSource + ?Sized> SectionSource for &mut S {
+    type Error = S::Error;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).read(offset, buf)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+/// Malformed section found by [`checked_section_init`], holding the offending addresses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitError {
+    /// The section start is not aligned to the access width, e.g. to the word with
+    /// `strict-alignment` feature enabled.
+    MisalignedDst(usize),
+    /// The section end is not aligned to the access width, e.g. of a section zeroed by words.
+    MisalignedEnd(usize),
+    /// The load data start is not aligned to the access width, e.g. to the word with
+    /// `strict-alignment` feature enabled.
+    MisalignedSrc(usize),
+    /// The section end precedes its start.
+    Reversed { start: usize, end: usize },
+    /// The section memory overlaps its load data of `len` bytes.
+    Overlap { dst: usize, src: usize, len: usize },
+    /// The section or table length differs from the one expected, e.g. the total length of the
+    /// section fragments.
+    LengthMismatch { expected: usize, actual: usize },
+    /// The section is accessed at `address` at or past its `end`.
+    OutOfRange { address: usize, end: usize },
+    /// The section load data lies out of the flash bounds at the address, see
+    /// [`set_flash_bounds`].
+    OutOfFlashBounds(usize),
+    /// The section starting at `start` lies out of the RAM bounds at `address`, see
+    /// [`set_ram_bounds`].
+    OutOfRamBounds { start: usize, address: usize },
+    /// The load data and the section start differ in the alignment to the word, so no word
+    /// could be copied whole.
+    AlignmentMismatch { src: usize, dst: usize },
+    /// The section starting at `start` ends `remainder` bytes past the last whole word, e.g. with
+    /// no trailing `ALIGN` in the linker script, see `strict-length` feature.
+    UnalignedLength { start: usize, remainder: usize },
+    /// The section memory read back at `address` after the copy differs from its load data, see
+    /// `verify` marker of [`init_sections`].
+    VerifyMismatch {
+        address: usize,
+        expected: Word,
+        actual: Word,
+    },
+    /// The CRC32 of the section starting at `start` computed after the copy differs from the one
+    /// stored at `__crc<section>`, see `crc32_verify` marker of [`init_sections`].
+    CrcMismatch {
+        start: usize,
+        expected: u32,
+        actual: u32,
+    },
+    /// The Fletcher-32 of the section starting at `start` computed after the copy differs from
+    /// the one stored at `__fletcher<section>`, see `verify(fletcher32)` marker of
+    /// [`init_sections`].
+    FletcherMismatch {
+        start: usize,
+        expected: u32,
+        actual: u32,
+    },
+    /// The CRC32 of the flash image `start..end` differs from the one stored, checked before any
+    /// section is initialized, see [`verify_image`].
+    ImageCrcMismatch {
+        start: usize,
+        end: usize,
+        expected: u32,
+        actual: u32,
+    },
+    /// The canary cell at `cell` lies within the section `start..end` it guards, so the copy
+    /// would overwrite it, see `canary` marker of [`init_sections`].
+    CanaryInSection {
+        cell: usize,
+        start: usize,
+        end: usize,
+    },
+    /// The section starting at the address is initialized again since the boot, see
+    /// [Double initialization](crate#double-initialization).
+    DoubleInit(usize),
+}
+
+impl InitError {
+    /// Returns the error code recorded by the default failure path, see
+    /// [Failed asserts](crate#failed-asserts).
+    ///
+    /// | code | error                |
+    /// |------|----------------------|
+    /// | 1    | `MisalignedDst`      |
+    /// | 2    | `MisalignedEnd`      |
+    /// | 3    | `MisalignedSrc`      |
+    /// | 4    | `Reversed`           |
+    /// | 5    | `Overlap`            |
+    /// | 6    | `LengthMismatch`     |
+    /// | 7    | `OutOfRange`         |
+    /// | 8    | `OutOfFlashBounds`   |
+    /// | 9    | `OutOfRamBounds`     |
+    /// | 10   | `AlignmentMismatch`  |
+    /// | 11   | `UnalignedLength`    |
+    /// | 12   | `VerifyMismatch`     |
+    /// | 13   | `CrcMismatch`        |
+    /// | 14   | `FletcherMismatch`   |
+    /// | 15   | `ImageCrcMismatch`   |
+    /// | 16   | `CanaryInSection`    |
+    /// | 17   | `DoubleInit`         |
+    pub const fn code(&self) -> u32 {
+        match self {
+            Self::MisalignedDst(_) => 1,
+            Self::MisalignedEnd(_) => 2,
+            Self::MisalignedSrc(_) => 3,
+            Self::Reversed { .. } => 4,
+            Self::Overlap { .. } => 5,
+            Self::LengthMismatch { .. } => 6,
+            Self::OutOfRange { .. } => 7,
+            Self::OutOfFlashBounds(_) => 8,
+            Self::OutOfRamBounds { .. } => 9,
+            Self::AlignmentMismatch { .. } => 10,
+            Self::UnalignedLength { .. } => 11,
+            Self::VerifyMismatch { .. } => 12,
+            Self::CrcMismatch { .. } => 13,
+            Self::FletcherMismatch { .. } => 14,
+            Self::ImageCrcMismatch { .. } => 15,
+            Self::CanaryInSection { .. } => 16,
+            Self::DoubleInit(_) => 17,
+        }
+    }
+
+    /// Returns the faulting address recorded by the default failure path, i.e. the misaligned
+    /// address, the section start, the address accessed or out of the bounds, or zero for
+    /// [`InitError::LengthMismatch`] holding lengths only.
+    pub const fn address(&self) -> usize {
+        match *self {
+            Self::MisalignedDst(address)
+            | Self::MisalignedEnd(address)
+            | Self::MisalignedSrc(address)
+            | Self::Reversed { start: address, .. }
+            | Self::Overlap { dst: address, .. }
+            | Self::OutOfRange { address, .. }
+            | Self::OutOfFlashBounds(address)
+            | Self::OutOfRamBounds { address, .. }
+            | Self::AlignmentMismatch { dst: address, .. }
+            | Self::UnalignedLength { start: address, .. }
+            | Self::VerifyMismatch { address, .. }
+            | Self::CrcMismatch { start: address, .. }
+            | Self::FletcherMismatch { start: address, .. }
+            | Self::ImageCrcMismatch { start: address, .. }
+            | Self::CanaryInSection { cell: address, .. }
+            | Self::DoubleInit(address) => address,
+            Self::LengthMismatch { .. } => 0,
+        }
+    }
+}
+
+impl core::fmt::Display for InitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::MisalignedDst(address) => write!(f, "section start {address:#x} not aligned"),
+            Self::MisalignedEnd(address) => write!(f, "section end {address:#x} not aligned"),
+            Self::MisalignedSrc(address) => write!(f, "load data {address:#x} not aligned"),
+            Self::Reversed { start, end } => {
+                write!(f, "section end {end:#x} precedes its start {start:#x}")
+            }
+            Self::Overlap { dst, src, len } => write!(
+                f,
+                "section {dst:#x} overlaps its load data {src:#x}, {len} bytes long"
+            ),
+            Self::LengthMismatch { expected, actual } => {
+                write!(f, "section length {actual} differs from {expected}")
+            }
+            Self::OutOfRange { address, end } => {
+                write!(f, "section accessed at {address:#x} past its end {end:#x}")
+            }
+            Self::OutOfFlashBounds(address) => {
+                write!(f, "section load data out of flash bounds at {address:#x}")
+            }
+            Self::OutOfRamBounds { start, address } => {
+                write!(f, "section at {start:#x} out of RAM bounds at {address:#x}")
+            }
+            Self::AlignmentMismatch { src, dst } => write!(
+                f,
+                "section {dst:#x} and its load data {src:#x} differ in word alignment"
+            ),
+            Self::UnalignedLength { start, remainder } => write!(
+                f,
+                "section {start:#x} ends {remainder} bytes past its last whole word"
+            ),
+            Self::VerifyMismatch {
+                address,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "section reads {actual:#x} at {address:#x}, its load data {expected:#x}"
+            ),
+            Self::CrcMismatch {
+                start,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "section {start:#x} CRC32 {actual:#010x} differs from {expected:#010x}"
+            ),
+            Self::FletcherMismatch {
+                start,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "section {start:#x} Fletcher-32 {actual:#010x} differs from {expected:#010x}"
+            ),
+            Self::ImageCrcMismatch {
+                start,
+                end,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "image {start:#x}..{end:#x} CRC32 {actual:#010x} differs from {expected:#010x}"
+            ),
+            Self::CanaryInSection { cell, start, end } => write!(
+                f,
+                "canary {cell:#x} lies within its section {start:#x}..{end:#x}"
+            ),
+            Self::DoubleInit(start) => write!(f, "section {start:#x} initialized twice"),
+        }
+    }
+}
+
+/// First [`InitError`] recorded by [`try_init_sections`], kept for reporting once the logging is
+/// up.
+///
+/// The cell holds no error once created. When the sections are initialized in pre-init, the
+/// cell shall be placed outside `.bss`, e.g. in `.uninit` section, otherwise it is zeroed by the
+/// runtime after the error is recorded. The macro clears the cell before the sections are
+/// initialized.
+///
+/// ```
+/// #[unsafe(link_section = ".uninit.init_error")]
+/// static INIT_ERROR: InitErrorCell = InitErrorCell::new();
+/// ```
+pub struct InitErrorCell {
+    recorded: AtomicU32,
+    error: core::cell::UnsafeCell<core::mem::MaybeUninit<InitError>>,
+}
+
+// SAFETY: The error is written before the `recorded` word is set and read after it is seen set.
+// The cell is meant to be written from a single context, e.g. pre-init, and read afterwards.
+unsafe impl Sync for InitErrorCell {}
+
+impl InitErrorCell {
+    /// Value of the `recorded` word once an error is recorded.
+    const RECORDED: u32 = 0xE220_4EC0;
+
+    /// Creates cell holding no error.
+    pub const fn new() -> Self {
+        Self {
+            recorded: AtomicU32::new(0),
+            error: core::cell::UnsafeCell::new(core::mem::MaybeUninit::uninit()),
+        }
+    }
+
+    /// Records `error` unless an error is recorded already.
+    pub fn record(&self, error: InitError) {
+        if self.recorded.load(Ordering::Acquire) != Self::RECORDED {
+            unsafe { (*self.error.get()).write(error) };
+            self.recorded.store(Self::RECORDED, Ordering::Release);
+        }
+    }
+
+    /// Returns the recorded error, if any.
+    pub fn get(&self) -> Option<InitError> {
+        (self.recorded.load(Ordering::Acquire) == Self::RECORDED)
+            .then(|| unsafe { (*self.error.get()).assume_init() })
+    }
+
+    /// Forgets the recorded error, if any.
+    pub fn clear(&self) {
+        self.recorded.store(0, Ordering::Release);
+    }
+}
+
+impl Default for InitErrorCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reports a malformed section found by an assert, to the handler given by
+/// [`set_failure_handler`] with `failure-handler` feature enabled, or by the default failure path
+/// otherwise.
+#[doc(hidden)]
+#[cold]
+#[inline(never)]
+pub fn init_failed(error: InitError) -> ! {
+    section_failed(None, error)
+}
+
+/// Reports a malformed section named `section`, if known, the same as [`init_failed`] does, its
+/// name is printed with `semihosting-errors` feature enabled.
+#[cold]
+#[inline(never)]
+fn section_failed(section: Option<&'static str>, error: InitError) -> ! {
+    #[cfg(feature = "failure-handler")]
+    {
+        unsafe extern "Rust" {
+            fn __linker_sections_on_error(error: &InitError) -> !;
+        }
+
+        let _ = section;
+        unsafe { __linker_sections_on_error(&error) }
+    }
+
+    #[cfg(not(feature = "failure-handler"))]
+    default_failure(section, &error)
+}
+
+#[cfg(all(
+    feature = "semihosting-errors",
+    feature = "asserts-panic",
+    target_arch = "arm"
+))]
+compile_error!("`semihosting-errors` and `asserts-panic` features are mutually exclusive");
+
+/// Length of the message printed by [`semihosting_failure`], including the terminating nul, the
+/// longer messages are truncated.
+#[cfg(feature = "semihosting-errors")]
+#[cfg_attr(not(target_arch = "arm"), allow(dead_code))]
+const FAILURE_MESSAGE_LEN: usize = 160;
+
+/// Formats the message of a malformed section named `section`, if known, into `buffer`, truncated
+/// to leave room for the terminating nul, returns its length.
+#[cfg(feature = "semihosting-errors")]
+#[cfg_attr(not(target_arch = "arm"), allow(dead_code))]
+fn failure_message(buffer: &mut [u8], section: Option<&str>, error: &InitError) -> usize {
+    struct Message<'a> {
+        buffer: &'a mut [u8],
+        len: usize,
+    }
+
+    impl core::fmt::Write for Message<'_> {
+        fn write_str(&mut self, text: &str) -> core::fmt::Result {
+            let room = self.buffer.len().saturating_sub(self.len + 1);
+            let len = text.len().min(room);
+            self.buffer[self.len..self.len + len].copy_from_slice(&text.as_bytes()[..len]);
+            self.len += len;
+
+            Ok(())
+        }
+    }
+
+    let mut message = Message { buffer, len: 0 };
+    let _ = match section {
+        Some(name) => core::fmt::Write::write_fmt(
+            &mut message,
+            format_args!("linker-sections: section `{name}` failed: {error}\n"),
+        ),
+        None => core::fmt::Write::write_fmt(
+            &mut message,
+            format_args!("linker-sections: section failed: {error}\n"),
+        ),
+    };
+
+    message.len
+}
+
+/// Prints the message of a malformed section to the debugger console by semihosting `SYS_WRITE0`
+/// call.
+#[cfg(all(feature = "semihosting-errors", target_arch = "arm"))]
+fn semihosting_report(section: Option<&'static str>, error: &InitError) {
+    /// Semihosting operation writing a nul terminated string.
+    const SYS_WRITE0: usize = 0x04;
+
+    let mut buffer = [0; FAILURE_MESSAGE_LEN];
+    failure_message(&mut buffer, section, error);
+
+    // the buffer is zeroed, so the message is nul terminated, the call hard faults with no
+    // debugger attached
+    unsafe {
+        core::arch::asm!(
+            "bkpt #0xab",
+            inout("r0") SYS_WRITE0 => _,
+            in("r1") buffer.as_ptr(),
+            options(nostack),
+        )
+    };
+}
+
+/// Default failure path of a malformed section named `section`, if known, see
+/// [Failed asserts](crate#failed-asserts).
+#[cfg_attr(feature = "failure-handler", allow(dead_code))]
+fn default_failure(section: Option<&'static str>, error: &InitError) -> ! {
+    #[cfg(all(feature = "semihosting-errors", target_arch = "arm"))]
+    semihosting_report(section, error);
+
+    #[cfg(not(all(feature = "semihosting-errors", target_arch = "arm")))]
+    let _ = section;
+
+    #[cfg(all(target_arch = "arm", not(feature = "asserts-panic")))]
+    failure_breakpoint(error.code(), error.address());
+
+    #[cfg(any(not(target_arch = "arm"), feature = "asserts-panic"))]
+    panic!("{error}");
+}
+
+/// Records `code` and `address` into `r0` and `r1` and into the scratch memory given by
+/// [`set_failure_scratch`], if any, then executes `bkpt` forever.
+///
+/// Nothing but registers and the scratch memory is touched, so it works before any static is
+/// initialized and pulls in no `core::fmt` code.
+#[cfg(all(target_arch = "arm", not(feature = "asserts-panic")))]
+#[inline(always)]
+fn failure_breakpoint(code: u32, address: usize) -> ! {
+    // the literal load and the branches are Thumb-1 encodings, so it runs on Cortex-M0 too, the
+    // weak scratch symbol resolves to zero unless defined, r2 need not be preserved as it never
+    // returns
+    unsafe {
+        core::arch::asm!(
+            ".weak __linker_sections_failure_scratch",
+            "ldr r2, 3f",
+            "cmp r2, #0",
+            "beq 2f",
+            "str r0, [r2]",
+            "str r1, [r2, #4]",
+            "2:",
+            "bkpt #0",
+            "b 2b",
+            ".p2align 2",
+            "3:",
+            ".word __linker_sections_failure_scratch",
+            in("r0") code,
+            in("r1") address,
+            options(noreturn, nostack),
+        )
+    }
+}
+
+/// Failure handler of the firmware giving no handler with `failure-handler` feature enabled,
+/// e.g. the firmware sharing the crate with others enabling the feature, fails the same as with
+/// the feature disabled.
+#[cfg(all(feature = "failure-handler", target_arch = "arm"))]
+fn default_handler(error: &InitError) -> ! {
+    default_failure(None, error)
+}
+
+// weak, so the handler of `set_failure_handler` takes precedence
+#[cfg(all(feature = "failure-handler", target_arch = "arm"))]
+core::arch::global_asm!(
+    ".weak __linker_sections_on_error",
+    ".thumb_set __linker_sections_on_error, {default}",
+    default = sym default_handler,
+);
+
+/// Read past the end of [`MappedSource`] or a byte slice [`SectionSource`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourceOutOfBounds;
+
+impl SectionSource for &[u8] {
+    type Error = SourceOutOfBounds;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let end = offset.checked_add(buf.len()).ok_or(SourceOutOfBounds)?;
+        buf.copy_from_slice(self.get(offset..end).ok_or(SourceOutOfBounds)?);
+
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+}
+
+/// Memory mapped load data as a [`SectionSource`], e.g. to initialize memory mapped and other
+/// sections by the same code, see [`Section::mapped_source`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MappedSource {
+    start: *const u8,
+    len: usize,
+}
+
+impl MappedSource {
+    /// Creates source of `len` bytes at `start`.
+    ///
+    /// # Safety
+    ///
+    /// The memory `start..start + len` must be readable as long as the source is read.
+    pub const unsafe fn new(start: *const u8, len: usize) -> Self {
+        Self { start, len }
+    }
+}
+
+impl SectionSource for MappedSource {
+    type Error = SourceOutOfBounds;
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let end = offset.checked_add(buf.len()).ok_or(SourceOutOfBounds)?;
+        if end > self.len {
+            return Err(SourceOutOfBounds);
+        }
+
+        // SAFETY: The memory is readable as required by `MappedSource::new`
+        unsafe { copy_bytes(buf.as_mut_ptr(), self.start.wrapping_add(offset), buf.len()) };
+
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Linker sections to be initialized later, see [`defer_sections_init`].
+#[must_use = "sections are not initialized until `DeferredInit::run` is called"]
+pub struct DeferredInit<const N: usize> {
+    sections: [Section; N],
+    unlocked: [bool; N],
+}
+
+impl<const N: usize> DeferredInit<N> {
+    /// Creates handle initializing given sections, the section memory is not touched.
+    pub const fn new(sections: [Section; N]) -> Self {
+        Self {
+            sections,
+            unlocked: [false; N],
+        }
+    }
+
+    /// Sets the sections not to be copied in a critical section by [`DeferredInit::run`] and
+    /// [`DeferredInit::run_with`], `true` for each such section, see [`defer_sections_init`].
+    pub fn with_unlocked(mut self, unlocked: [bool; N]) -> Self {
+        self.unlocked = unlocked;
+        self
+    }
+
+    /// Initializes all the sections in the given order.
+    ///
+    /// With `critical-section` feature enabled, each section is copied in its own critical
+    /// section, unless it is set unlocked by [`DeferredInit::with_unlocked`].
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], the memory of all the sections must be accessible.
+    pub unsafe fn run(self) {
+        let this = ManuallyDrop::new(self);
+
+        for (section, &unlocked) in this.sections.iter().zip(&this.unlocked) {
+            if unlocked {
+                unsafe { section.init() };
+            } else {
+                locked(|| unsafe { section.init() });
+            }
+        }
+    }
+
+    /// Initializes all the sections in the given order in chunks of `chunk_bytes`, calling
+    /// `yield_fn` between the chunks, see [`section_init_chunked`].
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init_chunked`], the memory of all the sections must be accessible.
+    pub unsafe fn run_chunked(self, chunk_bytes: usize, mut yield_fn: impl FnMut()) {
+        let this = ManuallyDrop::new(self);
+
+        for section in &this.sections {
+            unsafe { section.init_chunked(chunk_bytes, &mut yield_fn) };
+        }
+    }
+
+    /// Initializes all the sections in the given order in chunks of `chunk_bytes`, calling
+    /// `progress` after each chunk, e.g. to drive a progress bar while a large external memory is
+    /// initialized.
+    ///
+    /// The progress is reported per section and over all the sections, see [`InitProgress`], so
+    /// the chunk size sets the granularity. Empty sections are not reported. The `progress` could
+    /// be a plain `fn(InitProgress)` or a closure, e.g. `&mut dyn FnMut(InitProgress)`.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init_chunked`], the memory of all the sections must be accessible.
+    pub unsafe fn run_with_progress(
+        self,
+        chunk_bytes: usize,
+        mut progress: impl FnMut(InitProgress),
+    ) {
+        let this = ManuallyDrop::new(self);
+        let total = this.sections.iter().map(Section::len_bytes).sum();
+        let mut before = 0;
+
+        for (index, section) in this.sections.iter().enumerate() {
+            let (start, end, load) = (section.start(), section.end(), section.load());
+
+            unsafe {
+                section_init_chunks(
+                    start,
+                    end,
+                    load,
+                    chunk_bytes,
+                    |section_done, section_total| {
+                        progress(InitProgress {
+                            section: index,
+                            section_done,
+                            section_total,
+                            done: before + section_done,
+                            total,
+                        })
+                    },
+                )
+            };
+
+            before += section.len_bytes();
+        }
+    }
+
+    /// Initializes all the sections in the given order with data read from `sources`, one per
+    /// section, instead of their LMAs, see [`SectionSource`].
+    ///
+    /// With `critical-section` feature enabled, each section is copied in its own critical
+    /// section the same way as by [`DeferredInit::run`]. A read error is returned as soon as it
+    /// happens, the sections following the failed one are not touched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source is shorter than its section.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init_from`], the memory of all the sections must be accessible.
+    pub unsafe fn run_from<S: SectionSource>(self, mut sources: [S; N]) -> Result<(), S::Error> {
+        let this = ManuallyDrop::new(self);
+
+        for ((section, &unlocked), source) in
+            this.sections.iter().zip(&this.unlocked).zip(&mut sources)
+        {
+            if unlocked {
+                unsafe { section.init_from(source) }?;
+            } else {
+                locked(|| unsafe { section.init_from(source) })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Initializes all the sections in the given order with data read from `sources`, one per
+    /// section, in chunks of `chunk_bytes`, calling `progress` after each chunk the same way as
+    /// [`DeferredInit::run_with_progress`] does.
+    ///
+    /// A read error is returned as soon as it happens, the sections following the failed one are
+    /// not touched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a source is shorter than its section.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init_chunked_from`], the memory of all the sections must be
+    /// accessible.
+    pub unsafe fn run_with_progress_from<S: SectionSource>(
+        self,
+        mut sources: [S; N],
+        chunk_bytes: usize,
+        mut progress: impl FnMut(InitProgress),
+    ) -> Result<(), S::Error> {
+        let this = ManuallyDrop::new(self);
+        let total = this.sections.iter().map(Section::len_bytes).sum();
+        let mut before = 0;
+
+        for (index, (section, source)) in this.sections.iter().zip(&mut sources).enumerate() {
+            unsafe {
+                section_init_source_chunks(
+                    section.start(),
+                    section.end(),
+                    source,
+                    chunk_bytes,
+                    |section_done, section_total| {
+                        progress(InitProgress {
+                            section: index,
+                            section_done,
+                            section_total,
+                            done: before + section_done,
+                            total,
+                        })
+                    },
+                )
+            }?;
+
+            before += section.len_bytes();
+        }
+
+        Ok(())
+    }
+
+    /// Initializes all the sections in the given order, the word aligned bodies are copied by
+    /// `copier`, e.g. by DMA, see [`SectionCopier`].
+    ///
+    /// With `critical-section` feature enabled, each section is copied in its own critical
+    /// section the same way as by [`DeferredInit::run`]. A copier returning before the copy is
+    /// done is protected only until it returns.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init_with`], the memory of all the sections must be accessible.
+    pub unsafe fn run_with<C: SectionCopier + ?Sized>(self, copier: &mut C) {
+        let this = ManuallyDrop::new(self);
+
+        for (section, &unlocked) in this.sections.iter().zip(&this.unlocked) {
+            if unlocked {
+                unsafe { section.init_with(copier) };
+            } else {
+                locked(|| unsafe { section.init_with(copier) });
+            }
+        }
+    }
+}
+
+/// Progress of [`DeferredInit::run_with_progress`], reported after each chunk copied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InitProgress {
+    /// Index of the section being initialized.
+    pub section: usize,
+    /// Bytes of the section initialized so far.
+    pub section_done: usize,
+    /// Section length in bytes.
+    pub section_total: usize,
+    /// Bytes of all the sections initialized so far.
+    pub done: usize,
+    /// Length of all the sections in bytes.
+    pub total: usize,
+}
+
+impl<const N: usize> Drop for DeferredInit<N> {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) {
+            panic!("deferred sections dropped without being initialized");
+        }
+    }
+}
+
+/// Status of [`SectionInitFsm::poll`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitStatus {
+    /// Not all the sections are initialized yet, `done` of `total` bytes are.
+    InProgress {
+        /// Bytes of all the sections initialized so far.
+        done: usize,
+        /// Length of all the sections in bytes.
+        total: usize,
+    },
+    /// All the sections are initialized.
+    Done,
+}
+
+/// Non-blocking initialization of linker sections, copying a limited number of words per
+/// [`SectionInitFsm::poll`] call, e.g. to interleave the initialization of a large external
+/// memory with async tasks instead of blocking the executor.
+///
+/// ```
+/// let mut sdram = SectionInitFsm::new([section!(sdram_data), section!(sdram_assets)]);
+///
+/// while unsafe { sdram.poll(256) } != InitStatus::Done {
+///     yield_now().await;
+/// }
+/// ```
+///
+/// The state only holds the section addresses and the position, so no allocation is needed and
+/// it could be moved to a task of a single-core executor, it is [`Send`].
+#[derive(Debug)]
+pub struct SectionInitFsm<const N: usize> {
+    sections: [Section; N],
+    section: usize,
+    offset: usize,
+    done: usize,
+    total: usize,
+}
+
+impl<const N: usize> SectionInitFsm<N> {
+    /// Creates state machine initializing given sections in the given order, the section memory
+    /// is not touched.
+    pub fn new(sections: [Section; N]) -> Self {
+        let total = sections.iter().map(Section::len_bytes).sum();
+
+        Self {
+            sections,
+            section: 0,
+            offset: 0,
+            done: 0,
+            total,
+        }
+    }
+
+    /// Returns the status without copying anything.
+    pub fn status(&self) -> InitStatus {
+        if self.done == self.total {
+            InitStatus::Done
+        } else {
+            InitStatus::InProgress {
+                done: self.done,
+                total: self.total,
+            }
+        }
+    }
+
+    /// Copies at most `budget_words` [`Word`]s, continuing where the previous call stopped,
+    /// possibly across section boundaries, returns the status after the copy.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], the memory of all the sections must be accessible and no
+    /// section shall be accessed until the [`InitStatus::Done`] is returned.
+    pub unsafe fn poll(&mut self, budget_words: usize) -> InitStatus {
+        let mut budget = budget_words.saturating_mul(WORD);
+
+        while budget > 0 && self.section < N {
+            let section = &self.sections[self.section];
+            let len = section.len_bytes();
+            let chunk = budget.min(len - self.offset);
+
+            let dst = section.start().cast::<u8>().wrapping_add(self.offset);
+            let src = section.load().cast::<u8>().wrapping_add(self.offset);
+            unsafe { section_init(dst.cast(), dst.wrapping_add(chunk).cast(), src.cast()) };
+
+            self.offset += chunk;
+            self.done += chunk;
+            budget -= chunk;
+
+            if self.offset == len {
+                self.section += 1;
+                self.offset = 0;
+            }
+        }
+
+        self.status()
+    }
+}
+
+/// Linker section retaining its content across resets, see [`persistent_section`].
+///
+/// The last word of the section is a CRC32 trailer of the preceding section content.
+#[derive(Clone, Copy)]
+pub struct PersistentSection {
+    section: Section,
+    flag: &'static PersistentFlag,
+}
+
+impl PersistentSection {
+    /// Creates persistent section, the section memory is not touched.
+    #[doc(hidden)]
+    pub const fn new(section: Section, flag: &'static PersistentFlag) -> Self {
+        Self { section, flag }
+    }
+
+    /// Returns the section including its CRC trailer.
+    pub const fn section(&self) -> Section {
+        self.section
+    }
+
+    /// Returns `true` if the CRC trailer matches the section content.
+    ///
+    /// # Safety
+    ///
+    /// - The section addresses must be aligned to [`Word`] and the section must hold at least the
+    ///   trailer word.
+    /// - The section memory must be readable.
+    pub unsafe fn is_valid(&self) -> bool {
+        let trailer = self.trailer();
+
+        unsafe { trailer.read_volatile() == Word::from(section_crc(self.section.start, trailer)) }
+    }
+
+    /// Initializes section memory with defaults from section LMA if the CRC trailer does not
+    /// match, then rewrites the trailer. Returns `true` if the defaults were loaded.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Section::init`], the section must hold at least the trailer word.
+    pub unsafe fn init(&self) -> bool {
+        let loaded = unsafe { !self.is_valid() };
+        if loaded {
+            unsafe {
+                self.section.init();
+                self.update_crc();
+            }
+        }
+
+        self.flag.set(loaded);
+
+        loaded
+    }
+
+    /// Returns `true` if the defaults were loaded by [`PersistentSection::init`] since the last
+    /// reset.
+    pub fn defaults_loaded(&self) -> bool {
+        self.flag.get()
+    }
+
+    /// Rewrites the CRC trailer, shall be called after the section content is modified, otherwise
+    /// the defaults are loaded on the next boot.
+    ///
+    /// # Safety
+    ///
+    /// - The section addresses must be aligned to [`Word`] and the section must hold at least the
+    ///   trailer word.
+    /// - No other context shall modify the section during the call.
+    pub unsafe fn update_crc(&self) {
+        let trailer = self.trailer();
+
+        unsafe { trailer.write_volatile(Word::from(section_crc(self.section.start, trailer))) };
+    }
+
+    /// Returns pointer to the CRC trailer word.
+    fn trailer(&self) -> *mut Word {
+        self.section.end.cast_mut().wrapping_sub(1)
+    }
+}
+
+/// Flag of [`PersistentSection`] telling whether the defaults were loaded.
+#[doc(hidden)]
+pub struct PersistentFlag(AtomicU32);
+
+impl PersistentFlag {
+    /// Value of the flag once the defaults are loaded.
+    const LOADED: u32 = 0xDEF0_17ED;
+
+    /// Creates flag, the initial value is irrelevant when placed in `.uninit` section.
+    pub const fn new() -> Self {
+        Self(AtomicU32::new(0))
+    }
+
+    fn set(&self, loaded: bool) {
+        self.0
+            .store(if loaded { Self::LOADED } else { 0 }, Ordering::Release);
+    }
+
+    fn get(&self) -> bool {
+        self.0.load(Ordering::Acquire) == Self::LOADED
+    }
+}
+
+impl Default for PersistentFlag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Flag recording that a section marked `verify(<flag>)` matched its load data after the copy,
+/// see [`init_sections`].
+///
+/// When the sections are initialized in pre-init, the flag shall be placed outside `.bss` and
+/// `.data`, e.g. in `.uninit` section, otherwise the runtime overwrites it after the section is
+/// verified. The flag is cleared if the section does not match, before the mismatch is reported.
+///
+/// ```
+/// #[unsafe(link_section = ".uninit.verified")]
+/// static DATA_VERIFIED: VerifiedFlag = VerifiedFlag::new();
+/// ```
+pub struct VerifiedFlag(AtomicU32);
+
+impl VerifiedFlag {
+    /// Value of the flag once the section is verified.
+    const VERIFIED: u32 = 0x7E21_F1ED;
+
+    /// Creates flag, the initial value is irrelevant when placed in `.uninit` section.
+    pub const fn new() -> Self {
+        Self(AtomicU32::new(0))
+    }
+
+    /// Returns whether the section matched its load data after the copy.
+    pub fn is_verified(&self) -> bool {
+        self.0.load(Ordering::Acquire) == Self::VERIFIED
+    }
+
+    #[cfg_attr(not(feature = "verify"), allow(dead_code))]
+    fn set(&self, verified: bool) {
+        self.0
+            .store(if verified { Self::VERIFIED } else { 0 }, Ordering::Release);
+    }
+}
+
+impl Default for VerifiedFlag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Guard word of [`init_sections_once`].
+#[doc(hidden)]
+pub struct OnceGuard(AtomicU32);
+
+impl OnceGuard {
+    /// Value of the guard word once the sections are initialized.
+    const ACQUIRED: u32 = 0x5EC7_1417;
+
+    /// Creates guard, the initial value is irrelevant when placed in `.uninit` section.
+    pub const fn new() -> Self {
+        Self(AtomicU32::new(0))
+    }
+
+    /// Marks the guard as acquired, returns `true` if it was not acquired before.
+    pub fn acquire(&self) -> bool {
+        // plain load and store, since atomic swap is not available on all cortex-m cores
+        let acquired = self.0.load(Ordering::Acquire) == Self::ACQUIRED;
+        self.0.store(Self::ACQUIRED, Ordering::Release);
+
+        !acquired
+    }
+}
+
+impl Default for OnceGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Number of sections recorded by the double init guard, the further ones are not checked.
+#[cfg(double_init_guard)]
+const INIT_GUARD_SLOTS: usize = 32;
+
+/// Starts of the sections initialized since the boot, see
+/// [Double initialization](crate#double-initialization).
+#[cfg(double_init_guard)]
+struct InitGuard([AtomicUsize; INIT_GUARD_SLOTS]);
+
+#[cfg(double_init_guard)]
+impl InitGuard {
+    /// Creates guard, the initial value is irrelevant when placed in `.uninit` section.
+    const fn new() -> Self {
+        Self([const { AtomicUsize::new(0) }; INIT_GUARD_SLOTS])
+    }
+
+    /// Records the section starting at `start`, returns `false` if it was recorded before.
+    fn enter(&self, start: usize) -> bool {
+        // plain loads and stores, since atomic swap is not available on all cortex-m cores
+        for slot in &self.0 {
+            match slot.load(Ordering::Acquire) {
+                0 => {
+                    slot.store(start, Ordering::Release);
+                    return true;
+                }
+                recorded if recorded == start => return false,
+                _ => {}
+            }
+        }
+
+        true
+    }
+
+    /// Forgets all the sections recorded.
+    fn reset(&self) {
+        for slot in &self.0 {
+            slot.store(0, Ordering::Release);
+        }
+    }
+}
+
+#[cfg(double_init_guard)]
+#[cfg_attr(
+    target_os = "none",
+    unsafe(link_section = ".uninit.linker_sections_guard")
+)]
+static INIT_GUARD: InitGuard = InitGuard::new();
+
+/// Guard of the accessor defined by [`declare_noinit_section`].
+#[doc(hidden)]
+pub struct TakeGuard(AtomicBool);
+
+impl TakeGuard {
+    /// Creates guard, placed in `.bss` so it is cleared on every boot.
+    pub const fn new() -> Self {
+        Self(AtomicBool::new(false))
+    }
+
+    /// Marks the guard as taken, returns `true` if it was not taken before.
+    #[cfg(feature = "critical-section")]
+    pub fn take(&self) -> bool {
+        critical_section::with(|_| {
+            let taken = self.0.load(Ordering::Relaxed);
+            self.0.store(true, Ordering::Relaxed);
+
+            !taken
+        })
+    }
+
+    /// Marks the guard as taken, returns `true` if it was not taken before.
+    #[cfg(all(not(feature = "critical-section"), target_has_atomic = "8"))]
+    pub fn take(&self) -> bool {
+        !self.0.swap(true, Ordering::AcqRel)
+    }
+
+    /// Marks the guard as taken, returns `true` if it was not taken before.
+    #[cfg(all(not(feature = "critical-section"), not(target_has_atomic = "8")))]
+    pub fn take(&self) -> bool {
+        // plain load and store, since atomic swap is not available on all cortex-m cores
+        let taken = self.0.load(Ordering::Acquire);
+        self.0.store(true, Ordering::Release);
+
+        !taken
+    }
+}
+
+impl Default for TakeGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns start address and size of the heap section, see [`heap_section`].
+#[doc(hidden)]
+pub fn heap_region(start: *const u32, end: *const u32) -> (usize, usize) {
+    let (start, end) = (start as usize, end as usize);
+
+    assert!(start < end, "heap section is empty");
+    assert!(
+        start % 4 == 0 && end % 4 == 0,
+        "heap section is not 4-byte aligned"
+    );
+
+    (start, end - start)
+}
+
+/// Returns `true` if the section name starts with a dot and contains no whitespace, see
+/// [`section_static`].
+#[doc(hidden)]
+pub const fn section_name_valid(name: &str) -> bool {
+    let name = name.as_bytes();
+    if name.len() < 2 || name[0] != b'.' {
+        return false;
+    }
+
+    let mut index = 1;
+    while index < name.len() {
+        if name[index].is_ascii_whitespace() {
+            return false;
+        }
+        index += 1;
+    }
+
+    true
+}
+
+/// Checks the type fits into the section given by [`declare_noinit_section`], with
+/// `debug_assertions` enabled only.
+#[doc(hidden)]
+#[inline(always)]
+pub fn noinit_check<T>(start: *const T, end: *const u8) {
+    if cfg!(debug_assertions) {
+        let len = (end as usize).saturating_sub(start as usize);
+
+        assert!(
+            core::mem::size_of::<T>() <= len,
+            "type does not fit into the noinit section"
+        );
+        assert!(
+            start.is_aligned(),
+            "noinit section is not aligned for the type"
+        );
+    }
+}
+
+/// CMSIS copy table entry, see [`init_cmsis_tables`].
+#[repr(C)]
+pub struct CopyTableEntry {
+    /// Section LMA's start
+    pub src: *const u32,
+    /// Section VMA's start
+    pub dst: *mut u32,
+    /// Section length in [`LengthUnit`]
+    pub len: u32,
+}
+
+/// CMSIS zero table entry, see [`init_cmsis_tables`].
+#[repr(C)]
+pub struct ZeroTableEntry {
+    /// Section VMA's start
+    pub dst: *mut u32,
+    /// Section length in [`LengthUnit`]
+    pub len: u32,
+}
+
+// SAFETY: The entry is immutable and it is only read during section initialization
+unsafe impl Sync for CopyTableEntry {}
+// SAFETY: The entry is immutable and it is only read during section initialization
+unsafe impl Sync for ZeroTableEntry {}
+
+/// Unit of CMSIS table entry length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// Length in 4-byte words, as CMSIS 5 specifies
+    Words,
+    /// Length in bytes, as some vendor linker scripts do
+    Bytes,
+}
+
+impl LengthUnit {
+    /// Returns length in bytes.
+    fn bytes(self, len: u32) -> usize {
+        match self {
+            Self::Words => len as usize * 4,
+            Self::Bytes => len as usize,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn copy_table_init(
+    table: *const CopyTableEntry,
+    table_end: *const CopyTableEntry,
+    unit: LengthUnit,
+) {
+    let len = unsafe { table_len(table, table_end) };
+
+    for i in 0..len {
+        let entry = unsafe { &*table.add(i) };
+
+        unsafe { section_init_len(entry.dst.cast(), unit.bytes(entry.len), entry.src.cast()) };
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn zero_table_init(
+    table: *const ZeroTableEntry,
+    table_end: *const ZeroTableEntry,
+    unit: LengthUnit,
+) {
+    let len = unsafe { table_len(table, table_end) };
+
+    for i in 0..len {
+        let entry = unsafe { &*table.add(i) };
+        let end = entry.dst.cast::<u8>().wrapping_add(unit.bytes(entry.len));
+
+        unsafe { zero_words(entry.dst, end.cast()) };
+    }
+}
+
+/// Zeroes memory `dst..end` by 4-byte words, the unit of CMSIS tables regardless of [`Word`].
+unsafe fn zero_words(dst: *mut u32, end: *const u32) {
+    #[cfg(asserts)]
+    {
+        // zero table entries describe 4-byte words
+        init_assert!(
+            align,
+            (dst as usize).is_multiple_of(4),
+            InitError::MisalignedDst(dst as usize)
+        );
+        init_assert!(
+            align,
+            (end as usize).is_multiple_of(4),
+            InitError::MisalignedEnd(end as usize)
+        );
+        init_assert!(
+            order,
+            dst as *const u32 <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+    }
+
+    let mut dst = dst;
+    while (dst as *const u32) < end {
+        // a plain write loop could be lowered to a `memset` call
+        if cfg!(feature = "no-memcpy") {
+            unsafe { dst.write_volatile(0) };
+        } else {
+            unsafe { dst.write(0) };
+        }
+        dst = unsafe { dst.add(1) };
+    }
+}
+
+/// Returns number of table entries.
+unsafe fn table_len<T>(table: *const T, table_end: *const T) -> usize {
+    // computed on the addresses, the symbols delimiting the table are not its objects
+    let len = table_end.addr().checked_sub(table.addr());
+
+    #[cfg(asserts)]
+    {
+        // tables are emitted by the linker as 4-byte words
+        init_assert!(
+            align,
+            (table as usize).is_multiple_of(4),
+            InitError::MisalignedSrc(table as usize)
+        );
+        init_assert!(
+            align,
+            (table_end as usize).is_multiple_of(4),
+            InitError::MisalignedEnd(table_end as usize)
+        );
+
+        // table end shall not precede table start
+        init_assert!(
+            order,
+            len.is_some(),
+            InitError::Reversed {
+                start: table as usize,
+                end: table_end as usize
+            }
+        );
+
+        // table shall hold whole entries only
+        #[cfg(assert_bounds)]
+        {
+            let len = len.unwrap_or(0);
+            init_assert!(
+                len.is_multiple_of(core::mem::size_of::<T>()),
+                InitError::LengthMismatch {
+                    expected: len - len % core::mem::size_of::<T>(),
+                    actual: len
+                }
+            );
+        }
+    }
+
+    len.unwrap_or(0) / core::mem::size_of::<T>()
+}
+
+#[doc(hidden)]
+pub unsafe fn section_table_init(
+    table: *const SectionDescriptor,
+    table_end: *const SectionDescriptor,
+) {
+    let len = unsafe { table_len(table, table_end) };
+
+    for i in 0..len {
+        let descriptor = unsafe { &*table.add(i) };
+
+        unsafe { section_init(descriptor.start, descriptor.end, descriptor.load) };
+    }
+}
+
+/// Initializes section memory `start..end` with data starting at `load` without touching the
+/// stack, e.g. on parts whose stack memory is not usable before some pre-init setup.
+///
+/// The function is `#[unsafe(naked)]` with the register contract
+/// - `r0` holds `start`, `r1` holds `end` and `r2` holds `load` on entry,
+/// - only `r0` to `r3` and the flags are changed, neither the stack pointer nor the stack memory
+///   is used,
+/// - it returns by `bx lr`, so it could be called by `bl` from naked code keeping its own return
+///   address in another register, see [`define_sections_init_no_stack`].
+///
+/// The memory is copied word by word by Thumb-1 instructions only, so it runs on ARMv6-M as well
+/// as on ARMv7-M and ARMv8-M. Available with `no-stack` feature on ARM targets only.
+///
+/// # Safety
+///
+/// Same as for [`init_region()`], besides the addresses must be word aligned and neither
+/// alignment nor overlap is checked.
+#[cfg(all(feature = "no-stack", target_arch = "arm"))]
+#[unsafe(naked)]
+pub unsafe extern "C" fn section_init_no_stack(
+    start: *mut Word,
+    end: *const Word,
+    load: *const Word,
+) {
+    core::arch::naked_asm!(
+        "1:",
+        "cmp r0, r1",
+        "bhs 2f",
+        "ldm r2!, {{r3}}",
+        "stm r0!, {{r3}}",
+        "b 1b",
+        "2:",
+        "bx lr",
+    );
+}
+
+#[doc(hidden)]
+pub unsafe fn section_init(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_reporting(dst, end, src, None) };
+}
+
+/// Same as [`section_init`], but a malformed section is reported with its `name` with
+/// `semihosting-errors` feature enabled.
+#[doc(hidden)]
+pub unsafe fn section_init_named(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    name: &'static str,
+) {
+    unsafe { section_init_reporting(dst, end, src, Some(name)) };
+}
+
+#[inline(always)]
+unsafe fn section_init_reporting(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    name: Option<&'static str>,
+) {
+    // not using defmt::asserts since defmt is not initialized at the moment this function being executed
+    #[cfg(asserts)]
+    if let Err(error) = section_check(dst, end, src, Checks::ENABLED) {
+        section_failed(name, error);
+    }
+
+    #[cfg(not(asserts))]
+    let _ = name;
+
+    unsafe { section_init_unverified(dst, end, src) };
+}
+
+/// Initializes section memory `dst..end` with data starting at `src`, returns the error instead
+/// of panicking if the section is malformed.
+///
+/// The section is checked the same way as with `asserts` feature enabled, whether the feature is
+/// enabled or not, i.e. the section end shall not precede its start and the section memory shall
+/// not overlap its load data, unless `overlap-ok` feature is enabled, and the section start and
+/// its load data start shall be equally aligned to the word. With `strict-alignment` feature
+/// enabled, they are checked to be word aligned, with `strict-length` feature enabled, the section
+/// length is checked to be whole words. Nothing is copied if the check fails, so it is meant for
+/// pre-init, where a panic could not be reported yet, see [`try_init_sections`].
+///
+/// ```
+/// if let Err(error) = unsafe { checked_section_init(start, end, load) } {
+///     INIT_ERROR.record(error);
+/// }
+/// ```
+///
+/// # Safety
+///
+/// - The addresses must point to memory with required access (read for `src`, write for `dst`).
+/// - No reference to any variable placed in the section shall be alive during the call.
+pub unsafe fn checked_section_init(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+) -> Result<(), InitError> {
+    section_check(dst, end, src, Checks::ALL)?;
+    unsafe { section_init_unverified(dst, end, src) };
+
+    Ok(())
+}
+
+/// Checks of section memory and its load data to be done.
+#[derive(Clone, Copy)]
+struct Checks {
+    align: bool,
+    order: bool,
+    overlap: bool,
+}
+
+impl Checks {
+    /// All the checks, done by [`checked_section_init`].
+    const ALL: Self = Self {
+        align: true,
+        order: true,
+        overlap: true,
+    };
+
+    /// The checks enabled by `assert-*` features, or `asserts` feature enabling all of them.
+    #[cfg_attr(not(asserts), allow(dead_code))]
+    const ENABLED: Self = Self {
+        align: cfg!(assert_align),
+        order: cfg!(assert_order),
+        overlap: cfg!(assert_overlap),
+    };
+}
+
+/// Checks section memory `dst..end` and its load data at `src` by `checks`, see
+/// [`checked_section_init`].
+fn section_check(
+    dst: *const Word,
+    end: *const Word,
+    src: *const Word,
+    checks: Checks,
+) -> Result<(), InitError> {
+    let (dst, end, src) = (dst.addr(), end.addr(), src.addr());
+
+    // an empty section's symbols may share addresses with its neighbors, and nothing is copied
+    if dst == end {
+        return Ok(());
+    }
+
+    // the start addresses must be word aligned, unless unaligned sections are allowed
+    if checks.align && cfg!(feature = "strict-alignment") {
+        if !dst.is_multiple_of(WORD) {
+            return Err(InitError::MisalignedDst(dst));
+        }
+        if !src.is_multiple_of(WORD) {
+            return Err(InitError::MisalignedSrc(src));
+        }
+    }
+
+    // the words are copied whole only if both addresses are word aligned at the same time
+    if checks.align && !(dst ^ src).is_multiple_of(WORD) {
+        return Err(InitError::AlignmentMismatch { src, dst });
+    }
+
+    if checks.order && end < dst {
+        return Err(InitError::Reversed { start: dst, end });
+    }
+
+    // the tail bytes not forming a whole word are copied one by one, unless the length is strict
+    let len = end.saturating_sub(dst);
+    if checks.align && cfg!(feature = "strict-length") && !len.is_multiple_of(WORD) {
+        return Err(InitError::UnalignedLength {
+            start: dst,
+            remainder: len % WORD,
+        });
+    }
+
+    // the data already in place is not copied, so it does not overlap
+    if checks.overlap && !cfg!(feature = "overlap-ok") && src != dst && overlaps(dst, src, len) {
+        return Err(InitError::Overlap { dst, src, len });
+    }
+
+    Ok(())
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` checked to lie within the
+/// flash bounds, if enabled, but not checked by [`section_check`].
+unsafe fn section_init_unverified(dst: *mut Word, end: *const Word, src: *const Word) {
+    let len = (end as usize).saturating_sub(dst as usize);
+
+    // the data already in place is not copied, so it is not checked either
+    if !core::ptr::eq(src, dst) && !within_flash_bounds(src as usize, len) {
+        return;
+    }
+
+    unsafe { section_copy(dst, end, src) };
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` as [`section_init`] does,
+/// but `src` need not be within the flash bounds, e.g. a stack buffer read from a flash driver.
+pub(crate) unsafe fn section_init_unbounded(dst: *mut Word, end: *const Word, src: *const Word) {
+    #[cfg(asserts)]
+    if let Err(error) = section_check(dst, end, src, Checks::ENABLED) {
+        init_failed(error);
+    }
+
+    unsafe { section_copy(dst, end, src) };
+}
+
+/// Copies section memory `dst..end` from `src`, a section whose end precedes its start is left
+/// untouched, the addresses are not checked otherwise.
+unsafe fn section_copy(dst: *mut Word, end: *const Word, src: *const Word) {
+    let len = (end as usize).saturating_sub(dst as usize);
+
+    // the data is already in place, e.g. the firmware was loaded into RAM by a debugger
+    if len == 0 || core::ptr::eq(src, dst) || !within_ram_bounds(Section::new(dst, end, src)) {
+        return;
+    }
+
+    if cfg!(feature = "overlap-ok") && overlaps(dst as usize, src as usize, len) {
+        unsafe { section_move(Section::new(dst, end, src)) };
+        return;
+    }
+
+    // volatile loops are never lowered to a `memcpy` call, which might rely on initialized RAM
+    if cfg!(feature = "no-memcpy") {
+        unsafe { section_init_in_place(Section::new(dst, end, src)) };
+    } else {
+        unsafe { copy_bytes(dst.cast(), src.cast(), len) };
+    }
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` as [`section_init`] does,
+/// but the word aligned body is copied by `copier`, the rest by the CPU.
+unsafe fn section_init_with<C: SectionCopier + ?Sized>(
+    copier: &mut C,
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+) {
+    #[cfg(feature = "strict-alignment")]
+    {
+        // src must be word aligned, unless unaligned sections are allowed
+        init_assert!(
+            align,
+            (src as usize).is_multiple_of(WORD),
+            InitError::MisalignedSrc(src as usize)
+        );
+    }
+
+    let len = unsafe { section_len_bytes(dst, end) };
+
+    #[cfg(feature = "strict-length")]
+    {
+        // the length must be whole words, unless the tail bytes are allowed
+        init_assert!(
+            align,
+            len.is_multiple_of(WORD),
+            InitError::UnalignedLength {
+                start: dst as usize,
+                remainder: len % WORD
+            }
+        );
+    }
+
+    if !within_flash_bounds(src as usize, len) || !within_ram_bounds(Section::new(dst, end, src)) {
+        return;
+    }
+
+    #[cfg(asserts)]
+    {
+        // check for memory region overlap, the copier need not handle it
+        init_assert!(
+            overlap,
+            !overlaps(dst as usize, src as usize, len),
+            InitError::Overlap {
+                dst: dst as usize,
+                src: src as usize,
+                len
+            }
+        );
+    }
+
+    #[cfg(asserts)]
+    {
+        // the words are copied whole only if both addresses are word aligned at the same time
+        init_assert!(
+            align,
+            len == 0 || (dst as usize ^ src as usize).is_multiple_of(WORD),
+            InitError::AlignmentMismatch {
+                src: src as usize,
+                dst: dst as usize
+            }
+        );
+    }
+
+    let dst = dst.cast::<u8>();
+    let src = src.cast::<u8>();
+
+    if !(dst as usize ^ src as usize).is_multiple_of(WORD) {
+        // the addresses are never word aligned at the same time, so the copier gets nothing
+        unsafe { copy_bytes(dst, src, len) };
+        return;
+    }
+
+    let head = ((WORD - dst as usize % WORD) % WORD).min(len);
+    let body = (len - head) / WORD * WORD;
+
+    unsafe {
+        copy_bytes(dst, src, head);
+        if body > 0 {
+            copier.copy(dst.add(head), src.add(head), body);
+        }
+        copy_bytes(
+            dst.add(head + body),
+            src.add(head + body),
+            len - head - body,
+        );
+    }
+}
+
+/// Copies `len` bytes from `src` to `dst`, the aligned body in words, the rest by `memcpy`.
+#[inline(always)]
+unsafe fn copy_bytes(dst: *mut u8, src: *const u8, len: usize) {
+    if !(dst as usize ^ src as usize).is_multiple_of(WORD) {
+        // the addresses are never word aligned at the same time, so no word could be copied
+        unsafe { core::ptr::copy_nonoverlapping(src, dst, len) };
+        return;
+    }
+
+    // the head bytes up to the first word aligned address and the remaining bytes not forming
+    // a whole word, if any, are copied byte by byte, the aligned body in words
+    let head = ((WORD - dst as usize % WORD) % WORD).min(len);
+    let words = (len - head) / WORD;
+    let body = head + words * WORD;
+
+    unsafe {
+        core::ptr::copy_nonoverlapping(src, dst, head);
+        // the pointers after a head shorter than the section are word aligned only
+        if words > 0 {
+            let src = core::slice::from_raw_parts(src.add(head).cast::<Word>(), words);
+            let dst = core::slice::from_raw_parts_mut(dst.add(head).cast::<Word>(), words);
+
+            init_from_slices(dst, src);
+        }
+        core::ptr::copy_nonoverlapping(src.add(body), dst.add(body), len - body);
+    }
+}
+
+/// Initializes section memory by word copy loop inlined into the caller, so no code outside the
+/// caller's section is called.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_in_place(section: Section) {
+    let mut dst = section.start.cast::<u8>();
+    let mut src = section.load.cast::<u8>();
+    let end = section.end.cast::<u8>();
+
+    // words are copied only if the addresses could be word aligned at the same time
+    if (dst as usize ^ src as usize) & (WORD - 1) == 0 {
+        while dst as usize & (WORD - 1) != 0 && (dst as *const u8) < end {
+            unsafe {
+                dst.write_volatile(src.read_volatile());
+                dst = dst.add(1);
+                src = src.add(1);
+            }
+        }
+
+        let words = (end as usize).saturating_sub(dst as usize) / WORD;
+        if words > 0 {
+            let burst = unsafe { copy_words_burst(dst.cast(), src.cast(), words) } * WORD;
+            unsafe {
+                dst = dst.add(burst);
+                src = src.add(burst);
+            }
+        }
+
+        while (end as usize).saturating_sub(dst as usize) >= WORD {
+            unsafe {
+                dst.cast::<Word>()
+                    .write_volatile(src.cast::<Word>().read_volatile());
+                dst = dst.add(WORD);
+                src = src.add(WORD);
+            }
+        }
+    }
+
+    while (dst as *const u8) < end {
+        unsafe {
+            dst.write_volatile(src.read_volatile());
+            dst = dst.add(1);
+            src = src.add(1);
+        }
+    }
+}
+
+/// Initializes section memory as [`section_init_in_place`] does, but by descending addresses from
+/// the section end, so the load data may overlap the section memory below the section start.
+#[inline(always)]
+unsafe fn section_init_in_place_backward(section: Section) {
+    let start = section.start.cast::<u8>();
+    let mut dst = section.end.cast::<u8>().cast_mut();
+    let mut src = section
+        .load
+        .cast::<u8>()
+        .wrapping_add((dst as usize).wrapping_sub(start as usize));
+
+    // words are copied only if the addresses could be word aligned at the same time
+    if (dst as usize ^ src as usize) & (WORD - 1) == 0 {
+        while dst as usize & (WORD - 1) != 0 && dst > start {
+            unsafe {
+                dst = dst.sub(1);
+                src = src.sub(1);
+                dst.write_volatile(src.read_volatile());
+            }
+        }
+
+        while dst as usize >= start as usize + WORD {
+            unsafe {
+                dst = dst.sub(WORD);
+                src = src.sub(WORD);
+                dst.cast::<Word>()
+                    .write_volatile(src.cast::<Word>().read_volatile());
+            }
+        }
+    }
+
+    while dst > start {
+        unsafe {
+            dst = dst.sub(1);
+            src = src.sub(1);
+            dst.write_volatile(src.read_volatile());
+        }
+    }
+}
+
+/// Initializes section memory with the load data possibly overlapping it, as `core::ptr::copy`
+/// does, but word by word by volatile loops inlined into the caller.
+#[inline(always)]
+unsafe fn section_move(section: Section) {
+    // each word is read before it gets overwritten by copying away from the overlap
+    match (section.start as usize).cmp(&(section.load as usize)) {
+        core::cmp::Ordering::Less => unsafe { section_init_in_place(section) },
+        core::cmp::Ordering::Greater => unsafe { section_init_in_place_backward(section) },
+        core::cmp::Ordering::Equal => {}
+    }
+}
+
+/// Returns whether regions of `len` bytes at `dst` and `src` share any byte, the regions are
+/// half-open, so the adjacent ones do not overlap.
+#[inline(always)]
+fn overlaps(dst: usize, src: usize, len: usize) -> bool {
+    src < dst + len && dst < src + len
+}
+
+/// Returns the first address of `address..address + len` out of all the `ranges` of bounds, if
+/// any. The ranges need not be sorted, the adjacent ones are contiguous, e.g. two RAM banks.
+#[cfg(feature = "memory-bounds")]
+fn bounds_violation(address: usize, len: usize, ranges: &[(usize, usize)]) -> Option<usize> {
+    let (mut address, mut remaining) = (address, len);
+
+    while remaining > 0 {
+        let range = ranges
+            .iter()
+            .find(|&&(start, end)| start <= address && address < end);
+        let Some(&(_, end)) = range else {
+            return Some(address);
+        };
+
+        // the data continues in the range starting at this range end, if any
+        if remaining <= end - address {
+            return None;
+        }
+        remaining -= end - address;
+        address = end;
+    }
+
+    None
+}
+
+/// Returns whether load data `src..src + len` lies within the flash bounds declared by
+/// [`set_flash_bounds`], calls the out-of-bounds handler with the first address out of them
+/// otherwise.
+#[cfg(feature = "memory-bounds")]
+fn within_flash_bounds(src: usize, len: usize) -> bool {
+    unsafe extern "C" {
+        static __sflash_bounds: Symbol;
+        static __eflash_bounds: Symbol;
+    }
+    unsafe extern "Rust" {
+        fn __linker_sections_out_of_flash_bounds(address: usize);
+    }
+
+    let start = core::ptr::addr_of!(__sflash_bounds).addr();
+    let end = core::ptr::addr_of!(__eflash_bounds).addr();
+
+    match bounds_violation(src, len, &[(start, end)]) {
+        Some(address) => {
+            unsafe { __linker_sections_out_of_flash_bounds(address) };
+            false
+        }
+        None => true,
+    }
+}
+
+/// Returns `true`, the flash bounds are checked with `memory-bounds` feature enabled only.
+#[cfg(not(feature = "memory-bounds"))]
+#[inline(always)]
+fn within_flash_bounds(_src: usize, _len: usize) -> bool {
+    true
+}
+
+/// Default handler of load data out of the flash bounds given no handler, see
+/// [`set_flash_bounds`].
+#[cfg(feature = "memory-bounds")]
+#[doc(hidden)]
+pub fn out_of_flash_bounds(address: usize) {
+    init_failed(InitError::OutOfFlashBounds(address));
+}
+
+/// Returns whether `section` memory lies within the RAM bounds declared by [`set_ram_bounds`],
+/// calls the out-of-bounds handler with the section and the first address out of them otherwise.
+#[cfg(feature = "memory-bounds")]
+fn within_ram_bounds(section: Section) -> bool {
+    unsafe extern "Rust" {
+        fn __linker_sections_within_ram_bounds(section: Section) -> bool;
+    }
+
+    unsafe { __linker_sections_within_ram_bounds(section) }
+}
+
+/// Returns `true`, the RAM bounds are checked with `memory-bounds` feature enabled only.
+#[cfg(not(feature = "memory-bounds"))]
+#[inline(always)]
+fn within_ram_bounds(_section: Section) -> bool {
+    true
+}
+
+/// Returns whether `section` memory lies within the `ranges`, calls `handler` with the section
+/// and the first address out of them otherwise, see [`set_ram_bounds`].
+#[cfg(feature = "memory-bounds")]
+#[doc(hidden)]
+pub fn check_ram_bounds(
+    section: Section,
+    ranges: &[(usize, usize)],
+    handler: fn(Section, usize),
+) -> bool {
+    let start = section.start().addr();
+    let len = section.end().addr().saturating_sub(start);
+
+    match bounds_violation(start, len, ranges) {
+        Some(address) => {
+            handler(section, address);
+            false
+        }
+        None => true,
+    }
+}
+
+/// Default handler of section memory out of the RAM bounds given no handler, see
+/// [`set_ram_bounds`].
+#[cfg(feature = "memory-bounds")]
+#[doc(hidden)]
+pub fn out_of_ram_bounds(section: Section, address: usize) {
+    init_failed(InitError::OutOfRamBounds {
+        start: section.start().addr(),
+        address,
+    });
+}
+
+/// Copies `words` words from `src` to `dst` by bursts of 8 words, returns the number of words
+/// copied, i.e. `words` rounded down to a multiple of 8.
+#[cfg(all(feature = "fast-copy", target_arch = "arm"))]
+#[inline(always)]
+unsafe fn copy_words_burst(dst: *mut Word, src: *const Word, words: usize) -> usize {
+    let bursts = words / 8;
+
+    if bursts > 0 {
+        // the data registers avoid the frame pointer (r7 in Thumb, r11 in ARM state) and the base
+        // pointer r6 reserved by the compiler, the callee-saved ones are saved by the compiler
+        // because they are declared clobbered
+        unsafe {
+            core::arch::asm!(
+                "2:",
+                "ldmia {src}!, {{r3, r4, r5, r8, r9, r10, r12, lr}}",
+                "stmia {dst}!, {{r3, r4, r5, r8, r9, r10, r12, lr}}",
+                "subs {bursts}, {bursts}, #1",
+                "bne 2b",
+                src = inout(reg) src => _,
+                dst = inout(reg) dst => _,
+                bursts = inout(reg) bursts => _,
+                out("r3") _,
+                out("r4") _,
+                out("r5") _,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r12") _,
+                out("lr") _,
+                options(nostack),
+            );
+        }
+    }
+
+    bursts * 8
+}
+
+/// Copies no words, the words are copied by the caller's plain loop.
+#[cfg(not(all(feature = "fast-copy", target_arch = "arm")))]
+#[inline(always)]
+unsafe fn copy_words_burst(_dst: *mut Word, _src: *const Word, _words: usize) -> usize {
+    0
+}
+
+/// Element copied by a single volatile load and a single volatile store of the element width.
+///
+/// The accesses must be volatile, otherwise the compiler is free to merge adjacent ones into
+/// wider accesses, e.g. a loop of halfword copies into word copies, or to call `memcpy`.
+trait VolatileCopy {
+    /// Copies one element from `src` to `dst`.
+    unsafe fn copy(dst: *mut Self, src: *const Self);
+}
+
+impl<W: Copy> VolatileCopy for W {
+    #[inline(always)]
+    unsafe fn copy(dst: *mut Self, src: *const Self) {
+        unsafe { dst.write_volatile(src.read_volatile()) };
+    }
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` by volatile accesses of `T`
+/// width only, e.g. for memory on a bus faulting on other access widths. Any [`Copy`] type is
+/// [`VolatileCopy`], the width specific entry points are its instantiations, so only the widths
+/// used get emitted.
+///
+/// The elements not fitting whole below `end` are left untouched. With `asserts` feature enabled,
+/// the addresses are checked to be aligned to `T` and the regions not to overlap, with
+/// `overlap-ok` feature enabled the destination not to be inside the source.
+#[inline(always)]
+unsafe fn section_init_generic<T: VolatileCopy>(dst: *mut T, end: *const T, src: *const T) {
+    let len = (end as usize).saturating_sub(dst as usize) / core::mem::size_of::<T>();
+
+    let section = Section::new(dst.cast(), end.cast(), src.cast());
+    if !within_flash_bounds(src as usize, len * core::mem::size_of::<T>())
+        || !within_ram_bounds(section)
+    {
+        return;
+    }
+
+    #[cfg(asserts)]
+    {
+        // the addresses must be aligned to the access width
+        init_assert!(
+            align,
+            dst.is_aligned(),
+            InitError::MisalignedDst(dst as usize)
+        );
+        init_assert!(
+            align,
+            end.is_aligned(),
+            InitError::MisalignedEnd(end as usize)
+        );
+        init_assert!(
+            align,
+            src.is_aligned(),
+            InitError::MisalignedSrc(src as usize)
+        );
+
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const T <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+
+        // check for memory region overlap, the copy by ascending addresses tolerates the
+        // destination below the source with `overlap-ok` feature enabled
+        #[cfg(assert_overlap)]
+        {
+            let len = len * core::mem::size_of::<T>();
+            let overlap = overlaps(dst as usize, src as usize, len);
+            if cfg!(feature = "overlap-ok") {
+                init_assert!(
+                    dst as usize <= src as usize || !overlap,
+                    InitError::Overlap {
+                        dst: dst as usize,
+                        src: src as usize,
+                        len
+                    }
+                );
+            } else {
+                init_assert!(
+                    !overlap,
+                    InitError::Overlap {
+                        dst: dst as usize,
+                        src: src as usize,
+                        len
+                    }
+                );
+            }
+        }
+    }
+
+    let mut i = 0;
+    while i < len {
+        unsafe { T::copy(dst.add(i), src.add(i)) };
+        i += 1;
+    }
+}
+
+/// Integer whose bytes could be reversed.
+trait SwapBytes: Copy {
+    fn swap_bytes(self) -> Self;
+}
+
+impl SwapBytes for u16 {
+    #[inline(always)]
+    fn swap_bytes(self) -> Self {
+        u16::swap_bytes(self)
+    }
+}
+
+impl SwapBytes for u32 {
+    #[inline(always)]
+    fn swap_bytes(self) -> Self {
+        u32::swap_bytes(self)
+    }
+}
+
+/// Integer byte-reversed by [`VolatileCopy`], the element is not [`Copy`], so it does not
+/// conflict with the plain copy of the integer.
+#[repr(transparent)]
+struct Swapped<T>(T);
+
+impl<T: SwapBytes> VolatileCopy for Swapped<T> {
+    #[inline(always)]
+    unsafe fn copy(dst: *mut Self, src: *const Self) {
+        let value = unsafe { src.cast::<T>().read_volatile() };
+
+        unsafe { dst.cast::<T>().write_volatile(value.swap_bytes()) };
+    }
+}
+
+/// Element copied by [`VolatileCopy`] which could be read back to verify the copy.
+trait VolatileVerify: VolatileCopy {
+    /// Returns whether `dst` reads back as `src`, `dst` is read by a single volatile load.
+    unsafe fn matches(dst: *const Self, src: *const Self) -> bool;
+}
+
+impl<W: Copy + PartialEq> VolatileVerify for W {
+    #[inline(always)]
+    unsafe fn matches(dst: *const Self, src: *const Self) -> bool {
+        unsafe { dst.read_volatile() == src.read_volatile() }
+    }
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` as
+/// [`section_init_generic`] does, but reads each element back after the copy and copies it
+/// again up to `retries` times if it does not match, e.g. for memory dropping writes.
+///
+/// Returns the address of the first element not matching after all the retries, the elements
+/// following it are left untouched.
+#[inline(always)]
+unsafe fn section_init_verified<T: VolatileVerify>(
+    dst: *mut T,
+    end: *const T,
+    src: *const T,
+    retries: usize,
+) -> Option<usize> {
+    let len = (end as usize).saturating_sub(dst as usize) / core::mem::size_of::<T>();
+
+    let section = Section::new(dst.cast(), end.cast(), src.cast());
+    if !within_flash_bounds(src as usize, len * core::mem::size_of::<T>())
+        || !within_ram_bounds(section)
+    {
+        return None;
+    }
+
+    #[cfg(asserts)]
+    {
+        // the addresses must be aligned to the access width
+        init_assert!(
+            align,
+            dst.is_aligned(),
+            InitError::MisalignedDst(dst as usize)
+        );
+        init_assert!(
+            align,
+            end.is_aligned(),
+            InitError::MisalignedEnd(end as usize)
+        );
+        init_assert!(
+            align,
+            src.is_aligned(),
+            InitError::MisalignedSrc(src as usize)
+        );
+
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const T <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+
+        // check for memory region overlap, the copy by ascending addresses tolerates the
+        // destination below the source with `overlap-ok` feature enabled
+        #[cfg(assert_overlap)]
+        {
+            let len = len * core::mem::size_of::<T>();
+            let overlap = overlaps(dst as usize, src as usize, len);
+            if cfg!(feature = "overlap-ok") {
+                init_assert!(
+                    dst as usize <= src as usize || !overlap,
+                    InitError::Overlap {
+                        dst: dst as usize,
+                        src: src as usize,
+                        len
+                    }
+                );
+            } else {
+                init_assert!(
+                    !overlap,
+                    InitError::Overlap {
+                        dst: dst as usize,
+                        src: src as usize,
+                        len
+                    }
+                );
+            }
+        }
+    }
+
+    let mut i = 0;
+    while i < len {
+        let (dst, src) = unsafe { (dst.add(i), src.add(i)) };
+
+        let mut attempts = 0;
+        loop {
+            unsafe { T::copy(dst, src) };
+
+            if unsafe { T::matches(dst, src) } {
+                break;
+            }
+            if attempts == retries {
+                return Some(dst as usize);
+            }
+            attempts += 1;
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+/// Initializes section memory by volatile accesses of `T` width verified by reading back, see
+/// [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_verify<T: Copy + PartialEq>(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    retries: usize,
+) -> Option<usize> {
+    unsafe { section_init_verified::<T>(dst.cast(), end.cast(), src.cast(), retries) }
+}
+
+/// Compares section memory `dst..end` with its load data at `src` after the copy, see `verify`
+/// marker of [`init_sections`]. The first mismatch is reported the same as a failed assert of
+/// section `name`, the `flag`, if given, records the section verified otherwise.
+#[cfg(feature = "verify")]
+#[doc(hidden)]
+pub unsafe fn section_verify(
+    dst: *const Word,
+    end: *const Word,
+    src: *const Word,
+    name: &'static str,
+    flag: Option<&VerifiedFlag>,
+) {
+    let result = unsafe { section_compare(dst, end, src) };
+
+    if let Some(flag) = flag {
+        flag.set(result.is_ok());
+    }
+    if let Err(error) = result {
+        section_failed(Some(name), error);
+    }
+}
+
+/// Compares section memory `dst..end` with its load data at `src` by volatile reads, the aligned
+/// body borrowed as slices the same as [`copy_bytes`] does, returns the first mismatch.
+#[cfg(feature = "verify")]
+unsafe fn section_compare(
+    dst: *const Word,
+    end: *const Word,
+    src: *const Word,
+) -> Result<(), InitError> {
+    let len = (end as usize).saturating_sub(dst as usize);
+    let (dst, src) = (dst.cast::<u8>(), src.cast::<u8>());
+
+    // the words are compared only if the addresses could be word aligned at the same time
+    let head = if (dst as usize ^ src as usize).is_multiple_of(WORD) {
+        ((WORD - dst as usize % WORD) % WORD).min(len)
+    } else {
+        len
+    };
+    let words = (len - head) / WORD;
+    let body = head + words * WORD;
+
+    unsafe {
+        compare_bytes(dst, src, head)?;
+        if words > 0 {
+            let dst = core::slice::from_raw_parts(dst.add(head).cast::<Word>(), words);
+            let src = core::slice::from_raw_parts(src.add(head).cast::<Word>(), words);
+
+            compare_slices(dst, src)?;
+        }
+        compare_bytes(dst.add(body), src.add(body), len - body)
+    }
+}
+
+/// Compares the words of section memory `dst` with its load data `src` by volatile reads, the
+/// counterpart of [`init_from_slices`], returns the first mismatch.
+#[cfg(feature = "verify")]
+fn compare_slices(dst: &[Word], src: &[Word]) -> Result<(), InitError> {
+    // indexed loop calling no `memcmp`, which might rely on initialized RAM, nor iterator adapters
+    let mut index = 0;
+    while index < src.len() {
+        // SAFETY: The references are valid for reads, the volatile reads are never elided
+        let (actual, expected) = unsafe {
+            (
+                core::ptr::read_volatile(&dst[index]),
+                core::ptr::read_volatile(&src[index]),
+            )
+        };
+        if actual != expected {
+            return Err(InitError::VerifyMismatch {
+                address: (&raw const dst[index]).addr(),
+                expected,
+                actual,
+            });
+        }
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Compares `len` bytes of section memory at `dst` with its load data at `src` by volatile
+/// reads, returns the first mismatch.
+#[cfg(feature = "verify")]
+unsafe fn compare_bytes(dst: *const u8, src: *const u8, len: usize) -> Result<(), InitError> {
+    let mut index = 0;
+    while index < len {
+        let (actual, expected) = unsafe {
+            (
+                dst.add(index).read_volatile(),
+                src.add(index).read_volatile(),
+            )
+        };
+        if actual != expected {
+            return Err(InitError::VerifyMismatch {
+                address: dst.wrapping_add(index).addr(),
+                expected: Word::from(expected),
+                actual: Word::from(actual),
+            });
+        }
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Initializes section memory by 8-bit accesses only, see [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_u8(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_generic::<u8>(dst.cast(), end.cast(), src.cast()) };
+}
+
+/// Initializes section memory by 16-bit accesses only, see [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_u16(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_generic::<u16>(dst.cast(), end.cast(), src.cast()) };
+}
+
+/// Initializes section memory by 32-bit accesses only, see [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_u32(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_generic::<u32>(dst.cast(), end.cast(), src.cast()) };
+}
+
+/// Initializes section memory by 64-bit accesses only, see [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_u64(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_generic::<u64>(dst.cast(), end.cast(), src.cast()) };
+}
+
+/// Initializes section memory by 16-bit accesses with the bytes of each halfword reversed, see
+/// [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_swap16(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_generic::<Swapped<u16>>(dst.cast(), end.cast(), src.cast()) };
+}
+
+/// Initializes section memory by 32-bit accesses with the bytes of each word reversed, see
+/// [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_swap32(dst: *mut Word, end: *const Word, src: *const Word) {
+    unsafe { section_init_generic::<Swapped<u32>>(dst.cast(), end.cast(), src.cast()) };
+}
+
+/// Initializes memory region `dst..end` with packed bytes starting at `src` by 32-bit volatile
+/// stores only, e.g. for memory faulting on narrower writes, see [`init_sections`].
+///
+/// Each four source bytes are read, which need not be aligned, and stored as a single word in
+/// memory order. The trailing 1 to 3 bytes are stored as a final word padded by zeros, so the word
+/// containing `end` gets written up to its end. With `asserts` feature enabled, `dst` is checked
+/// to be aligned to 32 bits and the regions not to overlap.
+///
+/// # Safety
+///
+/// - The addresses must point to memory with required access (read for `src`, write for `dst`),
+///   the destination including the padding of the final word.
+/// - The `dst` must be aligned to 32 bits and less or equal to `end`.
+/// - The source and destination regions must not overlap.
+#[inline(always)]
+pub unsafe fn section_init_widen(dst: *mut u32, end: *const u8, src: *const u8) {
+    let len = (end as usize).saturating_sub(dst as usize);
+
+    let section = Section::new(dst.cast(), end.cast(), src.cast());
+    if !within_flash_bounds(src as usize, len) || !within_ram_bounds(section) {
+        return;
+    }
+
+    #[cfg(asserts)]
+    {
+        // the stores are always whole words
+        init_assert!(
+            align,
+            dst.is_aligned(),
+            InitError::MisalignedDst(dst as usize)
+        );
+
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const u8 <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+
+        // check for memory region overlap
+        init_assert!(
+            overlap,
+            !overlaps(dst as usize, src as usize, len),
+            InitError::Overlap {
+                dst: dst as usize,
+                src: src as usize,
+                len
+            }
+        );
+    }
+
+    let mut i = 0;
+    while i < len {
+        let mut bytes = [0u8; 4];
+        let count = (len - i).min(4);
+
+        if count == 4 {
+            bytes = unsafe { src.add(i).cast::<[u8; 4]>().read_unaligned() };
+        } else {
+            // the final word is padded by zeros, the source is not read beyond its end
+            for (j, byte) in bytes.iter_mut().take(count).enumerate() {
+                *byte = unsafe { src.add(i + j).read() };
+            }
+        }
+
+        unsafe { dst.add(i / 4).write_volatile(u32::from_ne_bytes(bytes)) };
+        i += 4;
+    }
+}
+
+/// Initializes section memory by 16-bit accesses spaced by `stride` halfwords, see
+/// [`init_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_init_u16_strided(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    stride: usize,
+) {
+    // the section start is aligned to the halfword only, so `section_len_bytes` is not used
+    let halfwords = (end as usize).saturating_sub(dst as usize) / 2;
+
+    unsafe { section_init_strided(dst, end, src, halfwords.div_ceil(stride), stride) };
+}
+
+/// Initializes `halfwords` halfwords at `dst` spaced by `dst_stride` halfwords with consecutive
+/// halfwords starting at `src`, i.e. the `i`-th halfword is written to `dst + i * dst_stride`.
+///
+/// This is the layout of packet memory seen by the CPU on some MCUs, e.g. STM32F1 USB packet
+/// memory, where each halfword occupies a 32-bit slot (`dst_stride` of 2). Each halfword is copied
+/// by a single volatile 16-bit load and store. With `asserts` feature enabled, the alignment is
+/// checked and the last halfword written is checked to fit below `end`.
+///
+/// # Safety
+///
+/// - The addresses must be 2-byte aligned.
+/// - The addresses must point to memory with required access (read for `src`, write for `dst`).
+/// - The source and destination regions must not overlap.
+#[inline(always)]
+pub unsafe fn section_init_strided(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    halfwords: usize,
+    dst_stride: usize,
+) {
+    if !within_flash_bounds(src as usize, halfwords * 2)
+        || !within_ram_bounds(Section::new(dst, end, src))
+    {
+        return;
+    }
+
+    #[cfg(asserts)]
+    {
+        // the addresses must be aligned to the access width
+        init_assert!(
+            align,
+            (dst as usize).is_multiple_of(2),
+            InitError::MisalignedDst(dst as usize)
+        );
+        init_assert!(
+            align,
+            (src as usize).is_multiple_of(2),
+            InitError::MisalignedSrc(src as usize)
+        );
+
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const Word <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+
+        // the strided range shall fit into the section
+        #[cfg(assert_bounds)]
+        {
+            let len = (end as usize).saturating_sub(dst as usize) / 2;
+            init_assert!(
+                halfwords == 0 || (halfwords - 1) * dst_stride < len,
+                InitError::OutOfRange {
+                    address: dst as usize + (halfwords - 1) * dst_stride * 2,
+                    end: end as usize
+                }
+            );
+        }
+    }
+    #[cfg(not(assert_bounds))]
+    let _ = end;
+
+    let dst = dst.cast::<u16>();
+    let src = src.cast::<u16>();
+
+    let mut i = 0;
+    while i < halfwords {
+        unsafe { u16::copy(dst.add(i * dst_stride), src.add(i)) };
+        i += 1;
+    }
+}
+
+/// Space left unpainted below the stack pointer by [`paint_stack`].
+const STACK_PAINT_GUARD: usize = 64;
+
+/// Returns the current stack pointer.
+#[doc(hidden)]
+#[inline(always)]
+pub fn stack_pointer() -> *const u32 {
+    #[cfg(target_arch = "arm")]
+    {
+        let sp: *const u32;
+        unsafe { core::arch::asm!("mov {}, sp", out(reg) sp, options(nomem, nostack)) };
+        sp
+    }
+
+    #[cfg(not(target_arch = "arm"))]
+    {
+        let marker = 0u32;
+        core::hint::black_box(core::ptr::addr_of!(marker))
+    }
+}
+
+/// Fills stack memory `bottom..top` with `pattern` up to a guard below `sp`, see [`paint_stack`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn stack_paint(
+    bottom: *mut u32,
+    top: *const u32,
+    sp: *const u32,
+    pattern: u32,
+) -> usize {
+    let limit = (sp as usize).saturating_sub(STACK_PAINT_GUARD) & !3;
+    let end = limit.min(top as usize);
+
+    let mut dst = bottom;
+    while (dst as usize) < end {
+        unsafe {
+            dst.write_volatile(pattern);
+            dst = dst.add(1);
+        }
+    }
+
+    (dst as usize).saturating_sub(bottom as usize)
+}
+
+/// Returns peak stack usage in bytes of stack `bottom..top` painted by [`paint_stack`].
+///
+/// The stack is scanned from the bottom for the first word not matching `pattern`, everything
+/// above is considered used. The stack grows down, `bottom` is the lowest address.
+///
+/// ```
+/// unsafe extern "C" {
+///     static _stack_start: u32;
+///     static _stack_end: u32;
+/// }
+///
+/// let used = unsafe {
+///     stack_watermark(&raw const _stack_end, &raw const _stack_start, 0xCCCC_CCCC)
+/// };
+/// ```
+///
+/// # Safety
+///
+/// - The addresses must be 4-byte aligned.
+/// - The stack memory must be readable and `bottom` must be less or equal to `top`.
+pub unsafe fn stack_watermark(bottom: *const u32, top: *const u32, pattern: u32) -> usize {
+    let mut src = bottom;
+    while src < top && unsafe { src.read_volatile() } == pattern {
+        src = unsafe { src.add(1) };
+    }
+
+    top as usize - src as usize
+}
+
+/// Initializes memory region `dst..end` with data starting at `src`.
+///
+/// The region is copied in [`Word`]s, the bytes not forming aligned words byte by byte, with
+/// `asserts` feature enabled the alignment and overlap is checked the same way as for linker
+/// sections.
+///
+/// # Safety
+///
+/// - The addresses must point to memory with required access (read for `src`, write for `dst`).
+/// - The `dst` must be less or equal to `end`.
+/// - The source and destination regions must not overlap, unless `overlap-ok` feature is enabled.
+pub unsafe fn init_region(dst: usize, end: usize, src: usize) {
+    unsafe { section_init_unbounded(dst as *mut Word, end as *const Word, src as *const Word) };
+}
+
+/// Initializes `dst` with the words of `src`, e.g. a section memory and its load data already
+/// borrowed as slices.
+///
+/// It is the word copy of [`init_sections`] and [`init_region()`], which borrow the aligned body
+/// of a section as slices once its length is known: four words per iteration while at least four
+/// remain, the remainder word by word, preceded by bursts of eight words with `fast-copy` feature
+/// enabled on ARM.
+///
+/// # Panics
+///
+/// Panics if the slices differ in length, as `copy_from_slice` does.
+#[inline(always)]
+pub fn init_from_slices(dst: &mut [Word], src: &[Word]) {
+    assert_eq!(dst.len(), src.len(), "slices differ in length");
+
+    // SAFETY: The slices are valid for the whole length and they never overlap
+    let burst = unsafe { copy_words_burst(dst.as_mut_ptr(), src.as_ptr(), src.len()) };
+
+    // indexed loops, so debug builds call no iterator adapters
+    let mut index = burst;
+    while src.len() - index >= 4 {
+        dst[index..index + 4].copy_from_slice(&src[index..index + 4]);
+        index += 4;
+    }
+
+    while index < src.len() {
+        dst[index] = src[index];
+        index += 1;
+    }
+}
+
+/// Relocates sections within memory holding their load data, e.g. an image loaded into RAM.
+///
+/// The sections are reordered so that no section is moved before all the sections whose load
+/// data its memory overlaps, each section is then copied away from its own overlap, see
+/// [`relocate_sections`]. The sections which do not depend on each other keep their order.
+///
+/// # Panics
+///
+/// Panics if no order exists, i.e. the sections' memory overlaps each other's load data in a
+/// cycle, e.g. two sections swapping places. Nothing is copied then.
+///
+/// # Safety
+///
+/// - The addresses need not be aligned, but only if the section start and LMA are equal modulo
+///   4, the section is copied in words.
+/// - The addresses must point to memory with required access (read for LMA, write for VMA).
+/// - The section start must be less or equal to section end.
+/// - No reference to any variable placed in the sections or their load data shall be alive
+///   during the call.
+pub unsafe fn relocate(sections: &mut [Section]) {
+    // the order is planned in place before anything is copied, so a cycle leaves memory intact
+    for next in 0..sections.len() {
+        let ready = (next..sections.len()).find(|&candidate| {
+            let dst = sections[candidate].start as usize;
+            let len = sections[candidate].len_bytes();
+
+            // the memory shall not overwrite load data of a section which is not moved yet
+            (next..sections.len()).all(|pending| {
+                let load = sections[pending].load as usize;
+                let load_len = sections[pending].len_bytes();
+
+                pending == candidate || len == 0 || dst >= load + load_len || load >= dst + len
+            })
+        });
+
+        let Some(ready) = ready else {
+            panic!("sections overlap each other's load data in a cycle");
+        };
+
+        // the candidates behind the ready one are shifted, so the independent keep their order
+        sections[next..=ready].rotate_right(1);
+    }
+
+    for section in sections.iter() {
+        unsafe { section_move(*section) };
+    }
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` in chunks of `chunk_bytes`,
+/// calling `yield_fn` between the chunks, e.g. to feed a watchdog or to let other tasks run while
+/// a large memory is initialized at runtime.
+///
+/// The chunk size is rounded down to whole [`Word`]s, but is at least one word. The `yield_fn` is
+/// not called before the first chunk nor after the last one, so it is not called at all if the
+/// section fits a single chunk.
+///
+/// # Safety
+///
+/// Same as for [`init_region()`], the memory shall not be accessed by `yield_fn` or by any other
+/// context running while it is called.
+pub unsafe fn section_init_chunked(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    chunk_bytes: usize,
+    mut yield_fn: impl FnMut(),
+) {
+    unsafe {
+        section_init_chunks(dst, end, src, chunk_bytes, |done, len| {
+            if done < len {
+                yield_fn();
+            }
+        })
+    };
+}
+
+/// Initializes section memory `dst..end` with data starting at `src`, calling `feed` after every
+/// `words` [`Word`]s copied and after the last chunk, or once if the section is empty, see
+/// [`init_sections_with_feed`].
+#[doc(hidden)]
+pub unsafe fn section_init_fed(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    words: usize,
+    feed: fn(),
+) {
+    let mut fed = false;
+
+    unsafe {
+        section_init_chunks(dst, end, src, words.saturating_mul(WORD), |_, _| {
+            feed();
+            fed = true;
+        })
+    };
+
+    // the feed is called even for an empty section, so the timing does not depend on the content
+    if !fed {
+        feed();
+    }
+}
+
+/// Initializes section memory `dst..end` with data starting at `src` in chunks of `chunk_bytes`
+/// as [`section_init_chunked`] does, calling `chunk_done` after each chunk with the bytes copied
+/// so far and the section length.
+unsafe fn section_init_chunks(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    chunk_bytes: usize,
+    mut chunk_done: impl FnMut(usize, usize),
+) {
+    #[cfg(asserts)]
+    {
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const Word <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+    }
+
+    let len = (end as usize).saturating_sub(dst as usize);
+    let chunk = (chunk_bytes - chunk_bytes % WORD).max(WORD);
+
+    let mut offset = 0;
+    while offset < len {
+        let chunk_len = chunk.min(len - offset);
+        let chunk_dst = dst.cast::<u8>().wrapping_add(offset).cast::<Word>();
+        let chunk_end = chunk_dst
+            .cast::<u8>()
+            .wrapping_add(chunk_len)
+            .cast::<Word>();
+        let chunk_src = src.cast::<u8>().wrapping_add(offset).cast::<Word>();
+
+        unsafe { section_init(chunk_dst, chunk_end, chunk_src) };
+        offset += chunk_len;
+
+        chunk_done(offset, len);
+    }
+}
+
+/// Bytes read from a [`SectionSource`] at once, the stack buffer size of
+/// [`section_init_from_source`].
+pub const SOURCE_CHUNK: usize = 64;
+
+/// Initializes section memory `dst..end` with data read from `source` in chunks of
+/// `chunk_bytes`, calling `yield_fn` between the chunks the same way as [`section_init_chunked`]
+/// does.
+///
+/// Each chunk is read in pieces of at most [`SOURCE_CHUNK`] bytes. A read error is returned as
+/// soon as it happens, the memory is then initialized up to the piece failed exclusive and the
+/// rest of it is not touched, see [`SectionSource`].
+///
+/// # Panics
+///
+/// Panics if `source` is shorter than the section.
+///
+/// # Safety
+///
+/// Same as for [`section_init_chunked`], except there is no load data in memory.
+pub unsafe fn section_init_from_source<S: SectionSource + ?Sized>(
+    dst: *mut Word,
+    end: *const Word,
+    source: &mut S,
+    chunk_bytes: usize,
+    mut yield_fn: impl FnMut(),
+) -> Result<(), S::Error> {
+    unsafe {
+        section_init_source_chunks(dst, end, source, chunk_bytes, |done, len| {
+            if done < len {
+                yield_fn();
+            }
+        })
+    }
+}
+
+/// Initializes section memory `dst..end` with data read from `source` in chunks of
+/// `chunk_bytes` as [`section_init_from_source`] does, calling `chunk_done` after each chunk with
+/// the bytes copied so far and the section length.
+unsafe fn section_init_source_chunks<S: SectionSource + ?Sized>(
+    dst: *mut Word,
+    end: *const Word,
+    source: &mut S,
+    chunk_bytes: usize,
+    mut chunk_done: impl FnMut(usize, usize),
+) -> Result<(), S::Error> {
+    #[cfg(asserts)]
+    {
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const Word <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+    }
+
+    let len = (end as usize).saturating_sub(dst as usize);
+    let chunk = (chunk_bytes - chunk_bytes % WORD).max(WORD);
+    assert!(
+        len <= source.len(),
+        "section source shorter than the section"
+    );
+
+    let mut buffer = [0 as Word; SOURCE_CHUNK / WORD];
+    let mut offset = 0;
+    while offset < len {
+        let chunk_end = offset + chunk.min(len - offset);
+
+        while offset < chunk_end {
+            let piece = (chunk_end - offset).min(SOURCE_CHUNK);
+            // SAFETY: The buffer is `SOURCE_CHUNK` bytes long and `piece` does not exceed it
+            let bytes =
+                unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast::<u8>(), piece) };
+            source.read(offset, bytes)?;
+
+            let piece_dst = dst.cast::<u8>().wrapping_add(offset).cast::<Word>();
+            let piece_end = piece_dst.cast::<u8>().wrapping_add(piece).cast::<Word>();
+            unsafe { section_init_unbounded(piece_dst, piece_end, buffer.as_ptr()) };
+            offset += piece;
+        }
+
+        chunk_done(offset, len);
+    }
+
+    Ok(())
+}
+
+/// Initializes the first `len_bytes` of section memory `dst..end` with data starting at `src`,
+/// returns the number of bytes copied.
+///
+/// The length is rounded up to whole [`Word`]s and clamped to the section size.
+///
+/// # Safety
+///
+/// Same as for [`init_region()`].
+pub unsafe fn section_init_partial(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+    len_bytes: usize,
+) -> usize {
+    let capacity = unsafe { section_len_bytes(dst, end) };
+    let len = len_bytes.min(capacity).next_multiple_of(WORD).min(capacity);
+
+    unsafe { section_init(dst, dst.cast::<u8>().add(len).cast(), src) };
+
+    len
+}
+
+#[doc(hidden)]
+pub unsafe fn section_init_len(dst: *mut Word, len: usize, src: *const Word) {
+    let end = dst.cast::<u8>().wrapping_add(len).cast::<Word>();
+
+    unsafe { section_init(dst, end, src) };
+}
+
+/// Initializes section memory `dst..end` with data of the `fragments`, given by their LMA start
+/// and end, copied one after another, see [`init_sections_fragments`].
+#[doc(hidden)]
+pub unsafe fn section_init_fragments(
+    mut dst: *mut Word,
+    end: *const Word,
+    fragments: &[(*const Word, *const Word)],
+) {
+    #[cfg(assert_order)]
+    for &(load, load_end) in fragments {
+        // fragment start shall be less or equal to fragment end
+        init_assert!(
+            load <= load_end,
+            InitError::Reversed {
+                start: load as usize,
+                end: load_end as usize
+            }
+        );
+    }
+
+    #[cfg(assert_bounds)]
+    {
+        let total = fragments.iter().fold(0, |total, &(load, load_end)| {
+            total + (load_end as usize).saturating_sub(load as usize)
+        });
+
+        // the fragments shall fill the section exactly
+        let len = (end as usize).wrapping_sub(dst as usize);
+        init_assert!(
+            total == len,
+            InitError::LengthMismatch {
+                expected: len,
+                actual: total
+            }
+        );
+    }
+
+    #[cfg(not(assert_bounds))]
+    let _ = end;
+
+    for &(load, load_end) in fragments {
+        let len = (load_end as usize).saturating_sub(load as usize);
+        let fragment_end = dst.cast::<u8>().wrapping_add(len).cast::<Word>();
+
+        unsafe { section_init(dst, fragment_end, load) };
+        dst = fragment_end;
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn section_zero(dst: *mut Word, end: *const Word) {
+    unsafe { section_fill(dst, end, 0) };
+}
+
+/// Data cache line size of Cortex-M7.
+const DCACHE_LINE: usize = 32;
+
+/// Returns the address and size of the data cache lines covering `start..end`.
+fn dcache_lines(start: usize, end: usize) -> (usize, usize) {
+    let first = start & !(DCACHE_LINE - 1);
+    let last = end.next_multiple_of(DCACHE_LINE);
+
+    (first, last.saturating_sub(first))
+}
+
+/// Cleans and invalidates data cache lines covering `start..end` by cache line operations inlined
+/// into the caller, followed by a DSB, see [`init_sections`].
+///
+/// The sequence is the same as by `SCB::clean_invalidate_dcache_by_address` of `cortex-m`, but
+/// the cache maintenance registers are written directly, so the peripherals need not be taken.
+/// Without `dcache-maintenance` feature or on ARMv6-M targets, which have no cache, it does
+/// nothing.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_clean_dcache(start: *const Word, end: *const Word) {
+    let (addr, size) = dcache_lines(start as usize, end as usize);
+
+    // ARMv6-M is the only ARM profile without compare-and-swap, so it is told by the atomics
+    #[cfg(all(
+        feature = "dcache-maintenance",
+        target_arch = "arm",
+        target_has_atomic = "32"
+    ))]
+    if size > 0 {
+        let cbp = cortex_m::peripheral::CBP::PTR;
+
+        cortex_m::asm::dsb();
+        for line in (addr..addr + size).step_by(DCACHE_LINE) {
+            // SAFETY: The register is write-only and stateless, so no race is possible
+            unsafe { (*cbp).dccimvac.write(line as u32) };
+        }
+        cortex_m::asm::dsb();
+        cortex_m::asm::isb();
+    }
+
+    #[cfg(not(all(
+        feature = "dcache-maintenance",
+        target_arch = "arm",
+        target_has_atomic = "32"
+    )))]
+    let _ = (addr, size);
+}
+
+/// Largest instruction cache of Cortex-M7, invalidating more lines one by one is slower than
+/// invalidating the whole cache.
+const ICACHE_SIZE_MAX: usize = 64 * 1024;
+
+/// Invalidates instruction cache lines covering `start..end` by cache line operations inlined
+/// into the caller, or the whole instruction cache if the range is larger than any cache, followed
+/// by a DSB and an ISB, see [`init_sections`].
+///
+/// The instruction cache line size of Cortex-M7 is the same as the data cache one. Without
+/// `icache-maintenance` feature or on ARMv6-M targets, which have no cache, only the barriers are
+/// issued, so the prefetched instructions are discarded.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_sync_code(start: *const Word, end: *const Word) {
+    let (addr, size) = dcache_lines(start as usize, end as usize);
+    let whole = size > ICACHE_SIZE_MAX;
+
+    #[cfg(all(
+        feature = "icache-maintenance",
+        target_arch = "arm",
+        target_has_atomic = "32"
+    ))]
+    {
+        let cbp = cortex_m::peripheral::CBP::PTR;
+
+        cortex_m::asm::dsb();
+        if whole {
+            // SAFETY: The register is write-only and stateless, so no race is possible
+            unsafe { (*cbp).iciallu.write(0) };
+        } else {
+            for line in (addr..addr + size).step_by(DCACHE_LINE) {
+                // SAFETY: The register is write-only and stateless, so no race is possible
+                unsafe { (*cbp).icimvau.write(line as u32) };
+            }
+        }
+        barrier();
+    }
+
+    #[cfg(not(all(
+        feature = "icache-maintenance",
+        target_arch = "arm",
+        target_has_atomic = "32"
+    )))]
+    {
+        let _ = (addr, size, whole);
+
+        #[cfg(target_arch = "arm")]
+        barrier();
+    }
+}
+
+/// Waits for the memory writes to complete and flushes the fetched instructions, by DSB and ISB
+/// on ARM and FENCE on RISC-V, on other architectures it is a compiler fence only.
+#[cfg(any(feature = "barriers", target_arch = "arm"))]
+#[inline(always)]
+fn barrier() {
+    #[cfg(target_arch = "arm")]
+    // SAFETY: The barriers only wait for the writes to complete and flush the pipeline
+    unsafe {
+        core::arch::asm!("dsb", "isb", options(nostack, preserves_flags))
+    };
+
+    #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+    // SAFETY: The fence only orders the writes before the following memory accesses
+    unsafe {
+        core::arch::asm!("fence", options(nostack, preserves_flags))
+    };
+
+    #[cfg(not(any(target_arch = "arm", target_arch = "riscv32", target_arch = "riscv64")))]
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+/// Runs `init` of `section` unless the canary `cell` holds `magic`, then writes `magic` there, see
+/// `canary` marker of [`init_sections`]. Returns `true` if the section was kept as is.
+///
+/// # Safety
+///
+/// The cell must be valid for reads and writes and aligned to [`Word`], outside the section,
+/// with `assert-overlap` feature enabled it is checked.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_canary(
+    section: &Section,
+    cell: *mut Word,
+    magic: Word,
+    init: impl FnOnce(),
+) -> bool {
+    #[cfg(assert_overlap)]
+    {
+        let (start, end) = (section.start().addr(), section.end().addr());
+        init_assert!(
+            cell.addr() >= end || cell.addr() + WORD <= start,
+            InitError::CanaryInSection {
+                cell: cell.addr(),
+                start,
+                end
+            }
+        );
+    }
+    #[cfg(not(assert_overlap))]
+    let _ = section;
+
+    let kept = unsafe { cell.read_volatile() } == magic;
+    if !kept {
+        init();
+
+        // the section is written completely before the canary tells so, e.g. to an external
+        // memory through a write buffer
+        #[cfg(any(feature = "barriers", target_arch = "arm"))]
+        barrier();
+        #[cfg(not(any(feature = "barriers", target_arch = "arm")))]
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+
+        unsafe { cell.write_volatile(magic) };
+    }
+
+    kept
+}
+
+/// Clears the canary `cell`, so the section guarded by it is initialized again, see
+/// [`clear_canary`].
+///
+/// # Safety
+///
+/// The cell must be valid for writes and aligned to [`Word`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn canary_clear(cell: *mut Word) {
+    unsafe { cell.write_volatile(0) };
+}
+
+/// Forgets the sections recorded by the double init guard, to be called first in pre-init, see
+/// [Double initialization](crate#double-initialization). It does nothing unless the guard is
+/// enabled.
+#[inline(always)]
+pub fn init_guard_reset() {
+    #[cfg(double_init_guard)]
+    INIT_GUARD.reset();
+}
+
+/// Records section `name` spanning `start..end` initialized, reports [`InitError::DoubleInit`]
+/// if it was initialized before since [`init_guard_reset`]. Empty sections, e.g. the missing
+/// optional ones, are not recorded.
+#[doc(hidden)]
+#[inline(always)]
+pub fn section_init_guard(name: &'static str, start: usize, end: usize) {
+    #[cfg(double_init_guard)]
+    if start != end && !INIT_GUARD.enter(start) {
+        section_failed(Some(name), InitError::DoubleInit(start));
+    }
+
+    #[cfg(not(double_init_guard))]
+    let _ = (name, start, end);
+}
+
+/// Issues the barriers once all the sections of a macro call are initialized, unless all of them
+/// are marked `no_barrier`, see [`init_sections`]. Without `barriers` feature it does nothing.
+#[doc(hidden)]
+#[inline(always)]
+pub fn sections_barrier() {
+    #[cfg(feature = "barriers")]
+    barrier();
+}
+
+/// Initializes section memory `dst..end` by decompressing LZ4 blob at `src`, see the `lz4`
+/// module.
+///
+/// The blob header is read first, its block shall not be longer than the greatest block of the
+/// section length, so a corrupted header is not taken for a block reaching past the flash.
+#[cfg(feature = "compress-lz4")]
+#[doc(hidden)]
+pub unsafe fn section_init_lz4(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+) -> Result<(), lz4::Error> {
+    let len = unsafe { section_len_bytes(dst, end) };
+
+    if !within_ram_bounds(Section::new(dst, end, src)) {
+        return Err(lz4::Error::OutputOverrun);
+    }
+    if !within_flash_bounds(src as usize, lz4::HEADER_LEN) {
+        return Err(lz4::Error::Truncated);
+    }
+
+    let header = unsafe { core::slice::from_raw_parts(src.cast::<u8>(), lz4::HEADER_LEN) };
+    let (_, block) = lz4::blob_header(header)?;
+    if block > lz4::block_bound(len) {
+        return Err(lz4::Error::LengthMismatch);
+    }
+    if !within_flash_bounds(src as usize, lz4::HEADER_LEN + block) {
+        return Err(lz4::Error::Truncated);
+    }
+
+    let blob = unsafe { core::slice::from_raw_parts(src.cast::<u8>(), lz4::HEADER_LEN + block) };
+    let dst = unsafe { core::slice::from_raw_parts_mut(dst.cast::<u8>(), len) };
+
+    lz4::decompress_blob(dst, blob)
+}
+
+/// Initializes section memory `dst..end` by decoding heatshrink blob at `src`, see the
+/// `heatshrink` module.
+///
+/// The blob header is read first, its stream shall not be longer than the longest stream of the
+/// section length, so a corrupted header is not taken for a stream reaching past the flash.
+#[cfg(feature = "compress-heatshrink")]
+#[doc(hidden)]
+pub unsafe fn section_init_heatshrink(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+) -> Result<(), heatshrink::Error> {
+    let len = unsafe { section_len_bytes(dst, end) };
+
+    if !within_ram_bounds(Section::new(dst, end, src)) {
+        return Err(heatshrink::Error::OutputOverrun);
+    }
+    if !within_flash_bounds(src as usize, heatshrink::HEADER_LEN) {
+        return Err(heatshrink::Error::Truncated);
+    }
+
+    let header = unsafe { core::slice::from_raw_parts(src.cast::<u8>(), heatshrink::HEADER_LEN) };
+    let (_, stream, _) = heatshrink::blob_header(header)?;
+    if stream > heatshrink::stream_bound(len) {
+        return Err(heatshrink::Error::LengthMismatch);
+    }
+    if !within_flash_bounds(src as usize, heatshrink::HEADER_LEN + stream) {
+        return Err(heatshrink::Error::Truncated);
+    }
+
+    let blob =
+        unsafe { core::slice::from_raw_parts(src.cast::<u8>(), heatshrink::HEADER_LEN + stream) };
+    let dst = unsafe { core::slice::from_raw_parts_mut(dst.cast::<u8>(), len) };
+
+    heatshrink::decode_blob(dst, blob)
+}
+
+/// Initializes section memory `dst..end` by decoding RLE blob at `src`, see the `rle` module.
+///
+/// The blob header is read first, its runs shall not be longer than the longest runs of the
+/// section length, so a corrupted header is not taken for runs reaching past the flash.
+#[cfg(feature = "compress-rle")]
+#[doc(hidden)]
+pub unsafe fn section_init_rle(
+    dst: *mut Word,
+    end: *const Word,
+    src: *const Word,
+) -> Result<(), rle::Error> {
+    let len = unsafe { section_len_bytes(dst, end) };
+
+    if !within_ram_bounds(Section::new(dst, end, src)) {
+        return Err(rle::Error::OutputOverrun);
+    }
+    if !within_flash_bounds(src as usize, rle::HEADER_LEN) {
+        return Err(rle::Error::Truncated);
+    }
+
+    let header = unsafe { core::slice::from_raw_parts(src.cast::<u8>(), rle::HEADER_LEN) };
+    let (_, runs) = rle::blob_header(header)?;
+    if runs > rle::runs_bound(len) {
+        return Err(rle::Error::LengthMismatch);
+    }
+    if !within_flash_bounds(src as usize, rle::HEADER_LEN + runs) {
+        return Err(rle::Error::Truncated);
+    }
+
+    let blob = unsafe { core::slice::from_raw_parts(src.cast::<u8>(), rle::HEADER_LEN + runs) };
+    let dst = unsafe { core::slice::from_raw_parts_mut(dst.cast::<u8>(), len) };
+
+    rle::decode_blob(dst, blob)
+}
+
+/// Initializes section memory `dst..end` by zeroing it and writing the words of sparse table at
+/// `src`, or by copying the raw image held by the table instead, see the `sparse` module.
+#[cfg(feature = "sparse-init")]
+#[doc(hidden)]
+pub unsafe fn section_init_sparse(dst: *mut Word, end: *const Word, src: *const Word) {
+    let words = unsafe { section_len_bytes(dst, end) } / 4;
+    let table = src.cast::<u32>();
+    let entry = |index: usize| u32::from_le(unsafe { table.add(index).read() });
+    let pair_within_flash = |index: usize| within_flash_bounds(table.wrapping_add(index).addr(), 8);
+    let dst_words = dst.cast::<u32>();
+
+    if !pair_within_flash(0) || !within_ram_bounds(Section::new(dst, end, src)) {
+        return;
+    }
+
+    if entry(0) == sparse::RAW {
+        let len = entry(1) as usize;
+
+        // the raw image shall fill the section exactly
+        #[cfg(assert_bounds)]
+        init_assert!(
+            len == words,
+            InitError::LengthMismatch {
+                expected: words * 4,
+                actual: len * 4
+            }
+        );
+
+        let copied = len.min(words);
+        if !within_flash_bounds(src as usize, 8 + copied * 4) {
+            return;
+        }
+        unsafe {
+            section_init(
+                dst,
+                dst_words.wrapping_add(copied).cast(),
+                table.add(2).cast(),
+            );
+        }
+        for index in copied..words {
+            unsafe { dst_words.add(index).write_volatile(0) };
+        }
+        return;
+    }
+
+    unsafe { section_zero(dst, end) };
+
+    let mut index = 0;
+    loop {
+        if !pair_within_flash(index) {
+            return;
+        }
+        let offset = entry(index);
+        if offset == sparse::END {
+            break;
+        }
+        let offset = offset as usize;
+
+        // the word shall lie within the section
+        #[cfg(assert_bounds)]
+        init_assert!(
+            offset < words,
+            InitError::OutOfRange {
+                address: dst_words.wrapping_add(offset).addr(),
+                end: end.addr()
+            }
+        );
+
+        if offset < words {
+            unsafe { dst_words.add(offset).write_volatile(entry(index + 1)) };
+        }
+        index += 2;
+    }
+}
+
+/// Returns the cycle counter value before a section is initialized, `None` if it is not
+/// recorded, see the `bench` module (`bench-dwt` or `bench-systick` feature).
+#[doc(hidden)]
+#[inline(always)]
+pub fn section_bench_start() -> Option<u32> {
+    #[cfg(any(feature = "bench-dwt", feature = "bench-systick"))]
+    return bench::start();
+
+    #[cfg(not(any(feature = "bench-dwt", feature = "bench-systick")))]
+    None
+}
+
+/// Records the cycles spent initializing section `name` since `start` with `bench-dwt` or
+/// `bench-systick` feature enabled.
+#[doc(hidden)]
+#[inline(always)]
+pub fn section_bench_record(name: &'static str, start: Option<u32>) {
+    #[cfg(any(feature = "bench-dwt", feature = "bench-systick"))]
+    bench::record(name, start);
+
+    #[cfg(not(any(feature = "bench-dwt", feature = "bench-systick")))]
+    let _ = (name, start);
+}
+
+/// Returns the cycle counter value before a step of the section measured since `start`, e.g.
+/// its checksum, `None` if it is not recorded.
+#[doc(hidden)]
+#[inline(always)]
+pub fn section_bench_split(start: Option<u32>) -> Option<u32> {
+    #[cfg(any(feature = "bench-dwt", feature = "bench-systick"))]
+    return bench::split(start);
+
+    #[cfg(not(any(feature = "bench-dwt", feature = "bench-systick")))]
+    start
+}
+
+/// Records the cycles spent by the step `name` of a section since `split` with `bench-dwt` or
+/// `bench-systick` feature enabled.
+#[doc(hidden)]
+#[inline(always)]
+pub fn section_bench_record_split(name: &'static str, split: Option<u32>) {
+    #[cfg(any(feature = "bench-dwt", feature = "bench-systick"))]
+    bench::record_split(name, split);
+
+    #[cfg(not(any(feature = "bench-dwt", feature = "bench-systick")))]
+    let _ = (name, split);
+}
+
+/// Zeroes memory from section end up to `tail_end` by word loop inlined into the caller.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_zero_tail(section_end: *const Word, tail_end: *const Word) {
+    #[cfg(asserts)]
+    {
+        // zeroed region end shall not precede the initialized section end
+        init_assert!(
+            order,
+            section_end <= tail_end,
+            InitError::Reversed {
+                start: section_end as usize,
+                end: tail_end as usize
+            }
+        );
+
+        // the region end must be word aligned because of word oriented memory access
+        init_assert!(
+            align,
+            (tail_end as usize).is_multiple_of(WORD),
+            InitError::MisalignedEnd(tail_end as usize)
+        );
+    }
+
+    let mut dst = section_end.cast_mut();
+    while (dst as *const Word) < tail_end {
+        unsafe {
+            dst.write_volatile(0);
+            dst = dst.add(1);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn section_fill(dst: *mut Word, end: *const Word, value: Word) {
+    let len = unsafe { section_len(dst, end) };
+
+    if !within_ram_bounds(Section::new(dst, end, core::ptr::null())) {
+        return;
+    }
+
+    for i in 0..len {
+        // a plain write loop could be lowered to a `memset` call
+        if cfg!(feature = "no-memcpy") {
+            unsafe { dst.add(i).write_volatile(value) };
+        } else {
+            unsafe { dst.add(i).write(value) };
+        }
+    }
+}
+
+/// Fills section memory `dst..end` with `value` repeated by volatile writes of `T` width only,
+/// e.g. for ECC protected memory which must be written by whole ECC words before it is read.
+///
+/// The memory is never read. With `asserts` feature enabled, the addresses are checked to be
+/// aligned to `T`, so the section length is a multiple of `T` width.
+#[inline(always)]
+unsafe fn section_fill_generic<T: Copy>(dst: *mut T, end: *const T, value: Word) {
+    #[cfg(asserts)]
+    {
+        // the addresses must be aligned to the access width
+        init_assert!(
+            align,
+            dst.is_aligned(),
+            InitError::MisalignedDst(dst as usize)
+        );
+        init_assert!(
+            align,
+            end.is_aligned(),
+            InitError::MisalignedEnd(end as usize)
+        );
+
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            dst as *const T <= end,
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+    }
+
+    // the pattern repeats each word, so any `T` up to two words wide is read from the same
+    // offset into two pattern words as it is written to the section memory
+    let pattern = [value; 2];
+    let width = core::mem::size_of::<T>();
+    let len = (end as usize).saturating_sub(dst as usize) / width;
+
+    if !within_ram_bounds(Section::new(dst.cast(), end.cast(), core::ptr::null())) {
+        return;
+    }
+
+    let mut i = 0;
+    while i < len {
+        unsafe {
+            let element = pattern
+                .as_ptr()
+                .cast::<u8>()
+                .add(i * width % WORD)
+                .cast::<T>();
+            dst.add(i).write_volatile(element.read_unaligned());
+        }
+        i += 1;
+    }
+}
+
+/// Fills section memory by 32-bit accesses only, see [`fill_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_fill_u32(dst: *mut Word, end: *const Word, value: Word) {
+    unsafe { section_fill_generic::<u32>(dst.cast(), end.cast(), value) };
+}
+
+/// Fills section memory by 64-bit accesses only, see [`fill_sections`].
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn section_fill_u64(dst: *mut Word, end: *const Word, value: Word) {
+    unsafe { section_fill_generic::<u64>(dst.cast(), end.cast(), value) };
+}
+
+/// Returns CRC32 (IEEE 802.3) of section memory, computed bitwise to keep the code small.
+unsafe fn section_crc(dst: *mut Word, end: *const Word) -> u32 {
+    let len = unsafe { section_len(dst, end) };
+
+    let mut crc = !0u32;
+    for index in 0..len {
+        let word = unsafe { dst.add(index).read_volatile() };
+
+        for byte in word.to_le_bytes() {
+            crc = crc32_update(crc, byte);
+        }
+    }
+
+    !crc
+}
+
+/// Returns CRC32 `crc` updated by `byte`, bitwise by the reflected IEEE 802.3 polynomial
+/// `0xEDB8_8320` with no table, to keep the code small.
+#[inline(always)]
+const fn crc32_update(mut crc: u32, byte: u8) -> u32 {
+    crc ^= byte as u32;
+
+    let mut bit = 0;
+    while bit < 8 {
+        crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        bit += 1;
+    }
+
+    crc
+}
+
+/// Returns CRC32 of `data` the same as the `crc32_verify` marker of [`init_sections`] computes
+/// over the section memory, e.g. for a build script or a post-link tool storing it at
+/// `__crc<section>`.
+///
+/// It is the CRC-32 of IEEE 802.3, zlib and PNG, i.e. the reflected polynomial `0xEDB8_8320`,
+/// the initial value and the final XOR `0xFFFF_FFFF`, the CRC of ASCII `123456789` being
+/// `0xCBF4_3926`.
+#[cfg(any(feature = "std", feature = "crc32-verify"))]
+pub fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| crc32_update(crc, byte))
+}
+
+/// Returns CRC32 of memory `start..end` read by bytes, volatile so the flash or the RAM just
+/// written is read indeed.
+#[cfg(feature = "crc32-verify")]
+unsafe fn memory_crc32(start: *const u8, end: *const u8) -> u32 {
+    let len = (end as usize).saturating_sub(start as usize);
+
+    // indexed loop, so debug builds call no iterator adapters
+    let mut crc = !0;
+    let mut index = 0;
+    while index < len {
+        crc = crc32_update(crc, unsafe { start.add(index).read_volatile() });
+        index += 1;
+    }
+
+    !crc
+}
+
+/// Computes CRC32 of flash image `start..end` and compares it with the one stored at `crc`, see
+/// [`verify_image`]. The mismatch is reported the same as a failed assert.
+#[cfg(feature = "crc32-verify")]
+#[doc(hidden)]
+pub unsafe fn image_verify(start: *const u8, end: *const u8, crc: *const u32) {
+    let (expected, actual) = unsafe { (crc.read_volatile(), memory_crc32(start, end)) };
+    if actual != expected {
+        section_failed(
+            None,
+            InitError::ImageCrcMismatch {
+                start: start.addr(),
+                end: end.addr(),
+                expected,
+                actual,
+            },
+        );
+    }
+}
+
+/// Computes CRC32 of section memory `dst..end` once copied and compares it with the one stored
+/// at `crc`, see `crc32_verify` marker of [`init_sections`]. The mismatch is reported the same as
+/// a failed assert of section `name`.
+#[cfg(feature = "crc32-verify")]
+#[doc(hidden)]
+pub unsafe fn section_crc32_verify(
+    dst: *const Word,
+    end: *const Word,
+    crc: *const u32,
+    name: &'static str,
+) {
+    let (expected, actual) = unsafe { (crc.read_volatile(), memory_crc32(dst.cast(), end.cast())) };
+    if actual != expected {
+        section_failed(
+            Some(name),
+            InitError::CrcMismatch {
+                start: dst.addr(),
+                expected,
+                actual,
+            },
+        );
+    }
+}
+
+/// Number of halfwords summed before both Fletcher-32 sums are reduced, the greatest keeping
+/// them within 32 bits.
+#[cfg(any(feature = "std", feature = "fletcher32-verify"))]
+const FLETCHER32_BLOCK: usize = 359;
+
+/// Returns Fletcher-32 sums of `count` halfwords read by `halfword`, reduced modulo 65535 once per
+/// [`FLETCHER32_BLOCK`], so no division is needed.
+#[cfg(any(feature = "std", feature = "fletcher32-verify"))]
+#[inline(always)]
+fn fletcher32_sums(count: usize, mut halfword: impl FnMut(usize) -> u16) -> (u32, u32) {
+    let (mut sum1, mut sum2) = (0u32, 0u32);
+
+    // indexed loops, so debug builds call no iterator adapters
+    let mut index = 0;
+    while index < count {
+        let block_end = count.min(index + FLETCHER32_BLOCK);
+        while index < block_end {
+            sum1 += u32::from(halfword(index));
+            sum2 += sum1;
+            index += 1;
+        }
+
+        sum1 = fletcher32_reduce(sum1);
+        sum2 = fletcher32_reduce(sum2);
+    }
+
+    (sum1, sum2)
+}
+
+/// Returns `sum` modulo 65535 by folding the upper halfword twice.
+#[cfg(any(feature = "std", feature = "fletcher32-verify"))]
+const fn fletcher32_reduce(sum: u32) -> u32 {
+    let sum = (sum & 0xFFFF) + (sum >> 16);
+    let sum = (sum & 0xFFFF) + (sum >> 16);
+
+    if sum == 0xFFFF {
+        0
+    } else {
+        sum
+    }
+}
+
+/// Returns Fletcher-32 of the `sums` of the whole halfwords followed by the odd `tail` byte.
+#[cfg(any(feature = "std", feature = "fletcher32-verify"))]
+const fn fletcher32_finish((mut sum1, mut sum2): (u32, u32), tail: Option<u8>) -> u32 {
+    if let Some(byte) = tail {
+        sum1 = fletcher32_reduce(sum1 + byte as u32);
+        sum2 = fletcher32_reduce(sum2 + sum1);
+    }
+
+    sum2 << 16 | sum1
+}
+
+/// Returns Fletcher-32 of `data` the same as the `verify(fletcher32)` marker of
+/// [`init_sections`] computes over the section memory, e.g. for a build script or a post-link
+/// tool storing it at `__fletcher<section>`.
+///
+/// The data are summed as little-endian halfwords modulo 65535, both sums starting at zero, the
+/// odd last byte padded by a zero byte, and the second sum forms the upper halfword. The
+/// Fletcher-32 of ASCII `abcde` is `0xF04F_C729`.
+#[cfg(any(feature = "std", feature = "fletcher32-verify"))]
+pub fn fletcher32(data: &[u8]) -> u32 {
+    let sums = fletcher32_sums(data.len() / 2, |index| {
+        u16::from_le_bytes([data[2 * index], data[2 * index + 1]])
+    });
+
+    fletcher32_finish(
+        sums,
+        (!data.len().is_multiple_of(2)).then(|| data[data.len() - 1]),
+    )
+}
+
+/// Computes Fletcher-32 of section memory `dst..end` once copied and compares it with the one
+/// stored at `fletcher`, see `verify(fletcher32)` marker of [`init_sections`]. The mismatch is
+/// reported the same as a failed assert of section `name`.
+#[cfg(feature = "fletcher32-verify")]
+#[doc(hidden)]
+pub unsafe fn section_fletcher32_verify(
+    dst: *const Word,
+    end: *const Word,
+    fletcher: *const u32,
+    name: &'static str,
+) {
+    let len = (end as usize).saturating_sub(dst as usize);
+    let bytes = dst.cast::<u8>();
+
+    // the halfwords are read at once if aligned, as the section start usually is
+    let sums = if dst.cast::<u16>().is_aligned() {
+        let halfwords = dst.cast::<u16>();
+        fletcher32_sums(len / 2, |index| {
+            u16::from_le(unsafe { halfwords.add(index).read_volatile() })
+        })
+    } else {
+        fletcher32_sums(len / 2, |index| unsafe {
+            u16::from_le_bytes([
+                bytes.add(2 * index).read_volatile(),
+                bytes.add(2 * index + 1).read_volatile(),
+            ])
+        })
+    };
+    let tail = (!len.is_multiple_of(2)).then(|| unsafe { bytes.add(len - 1).read_volatile() });
+
+    let (expected, actual) = (
+        unsafe { fletcher.read_volatile() },
+        fletcher32_finish(sums, tail),
+    );
+    if actual != expected {
+        section_failed(
+            Some(name),
+            InitError::FletcherMismatch {
+                start: dst.addr(),
+                expected,
+                actual,
+            },
+        );
+    }
+}
+
+/// Returns section length in bytes, neither the section start nor end need be word aligned.
+///
+/// The length is computed on the addresses, since the section start and end symbols are distinct
+/// objects, and a section ending before its start is empty unless rejected by `asserts` feature.
+unsafe fn section_len_bytes(dst: *mut Word, end: *const Word) -> usize {
+    let len = end.addr().checked_sub(dst.addr());
+
+    #[cfg(asserts)]
+    {
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            len.is_some(),
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+    }
+
+    #[cfg(feature = "strict-alignment")]
+    {
+        // dst must be word aligned, unless unaligned sections are allowed
+        init_assert!(
+            align,
+            (dst as usize).is_multiple_of(WORD),
+            InitError::MisalignedDst(dst as usize)
+        );
+    }
+
+    len.unwrap_or(0)
+}
+
+/// Returns section length in words, computed on the addresses as by [`section_len_bytes`].
+unsafe fn section_len(dst: *mut Word, end: *const Word) -> usize {
+    let len = end.addr().checked_sub(dst.addr());
+
+    #[cfg(asserts)]
+    {
+        // section start shall be less or equal to section end
+        init_assert!(
+            order,
+            len.is_some(),
+            InitError::Reversed {
+                start: dst as usize,
+                end: end as usize
+            }
+        );
+
+        // dst must be word aligned because of word oriented memory access
+        init_assert!(
+            align,
+            (dst as usize).is_multiple_of(WORD),
+            InitError::MisalignedDst(dst as usize)
+        );
+
+        // to calculate section length, section end must be word aligned
+        init_assert!(
+            align,
+            (end as usize).is_multiple_of(WORD),
+            InitError::MisalignedEnd(end as usize)
+        );
+    }
+
+    len.unwrap_or(0) / WORD
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn section_len_bytes() {
+        let mut dst = [0; 8];
+        let src = [0; 8];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+
+        assert_eq!(section.len_bytes(), 8 * WORD);
+    }
+
+    /// Returns two separate allocations ordered by their addresses.
+    fn separate_allocations() -> (std::boxed::Box<[Word; 4]>, std::boxed::Box<[Word; 4]>) {
+        let (a, b) = (std::boxed::Box::new([0; 4]), std::boxed::Box::new([0; 4]));
+
+        if a.as_ptr() < b.as_ptr() {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    #[test]
+    fn section_len_separate_symbols() {
+        let (mut low, high) = separate_allocations();
+        let dst = low.as_mut_ptr();
+        let end = high.as_ptr();
+
+        // the start and end symbols are separate objects, nothing is offset between them
+        let len = end.addr() - dst.addr();
+        assert_eq!(unsafe { super::section_len_bytes(dst, end) }, len);
+        assert_eq!(unsafe { section_len(dst, end) }, len / WORD);
+    }
+
+    #[test]
+    #[cfg(not(assert_order))]
+    fn section_len_reversed_empty() {
+        let (low, mut high) = separate_allocations();
+
+        assert_eq!(
+            unsafe { super::section_len_bytes(high.as_mut_ptr(), low.as_ptr()) },
+            0
+        );
+        assert_eq!(unsafe { section_len(high.as_mut_ptr(), low.as_ptr()) }, 0);
+    }
+
+    #[test]
+    #[cfg(assert_order)]
+    #[should_panic]
+    fn section_len_reversed() {
+        let (low, mut high) = separate_allocations();
+
+        unsafe { super::section_len_bytes(high.as_mut_ptr(), low.as_ptr()) };
+    }
+
+    #[test]
+    fn section_empty() {
+        let mut dst = [0; 0];
+        let src = [0; 0];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+        unsafe { section.init() };
+
+        assert_eq!(section.len_bytes(), 0);
+    }
+
+    #[test]
+    fn section_init_empty() {
+        let (mut memory, _) = test_words::<4>();
+        let expected = memory;
+        let start = memory.as_mut_ptr().wrapping_add(2);
+
+        // the load data is the neighbor's section, which must be neither read nor rejected
+        unsafe { section_init(start, start, memory.as_ptr().wrapping_add(1)) };
+
+        assert_eq!(memory, expected);
+    }
+
+    #[test]
+    fn section_init_empty_dangling_load() {
+        let mut dst = [0; 0];
+        let range = dst.as_mut_ptr_range();
+
+        // misaligned load address without provenance, nothing is formed from it
+        let src = core::ptr::without_provenance::<Word>(WORD + 1);
+        unsafe { section_init(range.start, range.end, src) };
+    }
+
+    #[test]
+    fn section_init_symbols() {
+        let (mut dst, src) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+
+        // the symbols are taken as the linker provides them, zero-sized and apart from the data
+        let start = symbol_ptr_mut(range.start.cast());
+        let end = symbol_ptr(range.end.cast());
+        let load = symbol_ptr(src.as_ptr().cast());
+        unsafe { section_init(start, end, load) };
+
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn init_from_slices_copies() {
+        // all the chunk remainders, with and without bursts of eight words
+        for len in 0..=21 {
+            let (mut dst, src) = test_words::<24>();
+            let expected = [&src[..len], &dst[len..]].concat();
+
+            init_from_slices(&mut dst[..len], &src[..len]);
+
+            assert_eq!(dst[..], expected[..], "{len} words");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "slices differ in length")]
+    fn init_from_slices_length_mismatch() {
+        let (mut dst, src) = test_words::<4>();
+
+        init_from_slices(&mut dst[..3], &src);
+    }
+
+    /// Returns bytes compressing to both literal runs and matches, their lengths over 270 bytes
+    /// needing extension bytes.
+    #[cfg(feature = "compress-lz4")]
+    fn lz4_samples() -> std::vec::Vec<std::vec::Vec<u8>> {
+        let mut state = 0x1234_5678u32;
+        let mut random = |len: usize| {
+            (0..len)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (state >> 16) as u8
+                })
+                .collect::<std::vec::Vec<u8>>()
+        };
+
+        let mut mixed = random(300);
+        mixed.extend(std::iter::repeat_n(0x55, 700));
+        mixed.extend(random(20));
+        mixed.extend(b"linker sections ".repeat(40));
+
+        std::vec![
+            std::vec![],
+            b"abc".to_vec(),
+            b"abcabcabcabc".to_vec(),
+            b"abcabcabcabca".to_vec(),
+            std::vec![0; 1000],
+            random(3000),
+            mixed,
+        ]
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn lz4_round_trip() {
+        for data in lz4_samples() {
+            let mut block = std::vec![0; lz4::block_bound(data.len())];
+            let len = lz4::compress(&data, &mut block).unwrap();
+
+            let mut decompressed = std::vec![0; data.len()];
+            assert_eq!(
+                lz4::decompress(&mut decompressed, &block[..len]),
+                Ok(data.len())
+            );
+            assert_eq!(decompressed, data);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn lz4_compresses() {
+        let data = b"linker sections ".repeat(64);
+        let mut block = std::vec![0; lz4::block_bound(data.len())];
+
+        assert!(lz4::compress(&data, &mut block).unwrap() < data.len() / 8);
+        assert_eq!(lz4::compress(&data, &mut block[..16]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn lz4_reference_block() {
+        // literals "abc" with 9 bytes match at offset 3, then 5 last literals
+        let block = [
+            0x35, b'a', b'b', b'c', 3, 0, 0x50, b'v', b'w', b'x', b'y', b'z',
+        ];
+        let mut dst = [0; 17];
+
+        assert_eq!(lz4::decompress(&mut dst, &block), Ok(17));
+        assert_eq!(&dst, b"abcabcabcabcvwxyz");
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn lz4_malformed() {
+        let mut dst = [0; 32];
+
+        assert_eq!(lz4::decompress(&mut dst, &[]), Err(lz4::Error::Truncated));
+        assert_eq!(
+            lz4::decompress(&mut dst, &[0x20, b'a']),
+            Err(lz4::Error::Truncated)
+        );
+        assert_eq!(
+            lz4::decompress(&mut dst, &[0x10, b'a', 0]),
+            Err(lz4::Error::Truncated)
+        );
+        assert_eq!(
+            lz4::decompress(&mut dst, &[0x10, b'a', 0, 0]),
+            Err(lz4::Error::InvalidOffset)
+        );
+        assert_eq!(
+            lz4::decompress(&mut dst, &[0x10, b'a', 2, 0]),
+            Err(lz4::Error::InvalidOffset)
+        );
+        assert_eq!(
+            lz4::decompress(&mut dst, &[0xF0, 0xFF, 0xFF]),
+            Err(lz4::Error::Truncated)
+        );
+        assert_eq!(
+            lz4::decompress(&mut dst, &[0x1F, b'a', 1, 0, 0xFF, 0]),
+            Err(lz4::Error::OutputOverrun)
+        );
+        assert_eq!(
+            lz4::decompress(&mut dst[..2], &[0x30, b'a', b'b', b'c']),
+            Err(lz4::Error::OutputOverrun)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn lz4_malformed_bounded() {
+        let data = lz4_samples().pop().unwrap();
+        let mut block = std::vec![0; lz4::block_bound(data.len())];
+        let len = lz4::compress(&data, &mut block).unwrap();
+        let mut dst = std::vec![0; data.len()];
+
+        // neither truncated nor corrupted blocks are read or written out of bounds
+        for end in 0..len {
+            assert_ne!(lz4::decompress(&mut dst, &block[..end]), Ok(data.len()));
+        }
+        for index in 0..len {
+            let mut corrupted = block[..len].to_vec();
+            corrupted[index] ^= 0xA5;
+            let _ = lz4::decompress(&mut dst, &corrupted);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn section_init_lz4() {
+        let (mut dst, _) = test_words::<64>();
+        let data = b"linker sections ".repeat(64 * WORD / 16);
+        let mut blob = std::vec![0; lz4::blob_bound(data.len()).div_ceil(WORD)];
+        let blob_bytes = unsafe {
+            core::slice::from_raw_parts_mut(blob.as_mut_ptr().cast::<u8>(), blob.len() * WORD)
+        };
+        lz4::compress_blob(&data, blob_bytes).unwrap();
+
+        let range = dst.as_mut_ptr_range();
+        let result = unsafe { super::section_init_lz4(range.start, range.end, blob.as_ptr()) };
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(word_bytes(&dst), data);
+
+        // the section is shorter than the uncompressed data
+        let end = range.end.wrapping_sub(1);
+        let result = unsafe { super::section_init_lz4(range.start, end, blob.as_ptr()) };
+        assert_eq!(result, Err(lz4::Error::LengthMismatch));
+    }
+
+    #[test]
+    #[cfg(feature = "compress-lz4")]
+    fn section_init_lz4_block_length_corrupted() {
+        let (mut dst, _) = test_words::<4>();
+        let mut blob: [Word; 2] = [0; 2];
+        let header = [0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0x7F];
+        unsafe { blob.as_mut_ptr().cast::<[u8; 8]>().write(header) };
+
+        // the block length reaching past the greatest block is rejected before any read
+        let range = dst.as_mut_ptr_range();
+        let result = unsafe { super::section_init_lz4(range.start, range.end, blob.as_ptr()) };
+
+        assert_eq!(result, Err(lz4::Error::LengthMismatch));
+    }
+
+    /// Returns bytes of zeroed structs with a few fields set, runs of all the lengths encoded.
+    #[cfg(all(feature = "compress-rle", feature = "std"))]
+    fn rle_samples() -> std::vec::Vec<std::vec::Vec<u8>> {
+        let mut sparse = std::vec![0; 40_000];
+        for (index, field) in [3, 4, 5, 100, 101, 130, 300, 33_000]
+            .into_iter()
+            .enumerate()
+        {
+            sparse[field] = index as u8 + 1;
+        }
+
+        std::vec![
+            std::vec![],
+            std::vec![7],
+            std::vec![7, 7, 7],
+            (0..=255).collect(),
+            [std::vec![1, 2], std::vec![9; 32_770], std::vec![9; 4]].concat(),
+            sparse,
+        ]
+    }
+
+    #[test]
+    #[cfg(all(feature = "compress-rle", feature = "std"))]
+    fn rle_round_trip() {
+        for data in rle_samples() {
+            let runs = rle::encode(&data);
+            assert!(runs.len() <= rle::runs_bound(data.len()));
+
+            let mut decoded = std::vec![0xA5; data.len()];
+            assert_eq!(rle::decode(&mut decoded, &runs), Ok(data.len()));
+            assert_eq!(decoded, data);
+        }
+
+        // a zeroed struct shrinks to a few bytes per field set
+        let sparse = rle_samples().pop().unwrap();
+        assert!(rle::encode(&sparse).len() < 64);
+    }
+
+    #[test]
+    #[cfg(feature = "compress-rle")]
+    fn rle_reference_runs() {
+        let runs = [1, b'a', b'b', 0x80, 2, 0, 0x81, 0, b'z', 0, b'c'];
+        let mut dst = [0; 267];
+
+        // 2 literals, 5 zeros, 259 repeated bytes and a literal
+        assert_eq!(rle::decode(&mut dst, &runs), Ok(267));
+        assert_eq!(&dst[..7], b"ab\0\0\0\0\0");
+        assert!(dst[7..266].iter().all(|&byte| byte == b'z'));
+        assert_eq!(dst[266], b'c');
+    }
+
+    #[test]
+    #[cfg(feature = "compress-rle")]
+    fn rle_malformed() {
+        let mut dst = [0; 8];
+
+        assert_eq!(rle::decode(&mut dst, &[]), Ok(0));
+        assert_eq!(
+            rle::decode(&mut dst, &[2, b'a']),
+            Err(rle::Error::Truncated)
+        );
+        assert_eq!(
+            rle::decode(&mut dst, &[0x80, 0]),
+            Err(rle::Error::Truncated)
+        );
+        assert_eq!(
+            rle::decode(&mut dst, &[0x80, 6, 0]),
+            Err(rle::Error::OutputOverrun)
+        );
+        assert_eq!(
+            rle::decode(&mut dst, &[8, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(rle::Error::OutputOverrun)
+        );
+
+        let mut blob = std::vec![9, 0, 0, 0, 3, 0, 0, 0, 0x80, 6, 0];
+        assert_eq!(
+            rle::decode_blob(&mut dst, &blob),
+            Err(rle::Error::LengthMismatch)
+        );
+        blob[0] = 8;
+        assert_eq!(
+            rle::decode_blob(&mut dst, &blob[..10]),
+            Err(rle::Error::Truncated)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "compress-rle", feature = "std"))]
+    fn section_init_rle() {
+        let (mut dst, _) = test_words::<64>();
+        let mut data = std::vec![0; 64 * WORD];
+        data[10] = 0x5A;
+        data[64 * WORD - 1] = 0xA5;
+
+        let encoded = rle::encode_blob(&data);
+        let mut blob = std::vec![0 as Word; encoded.len().div_ceil(WORD)];
+        let blob_bytes = unsafe {
+            core::slice::from_raw_parts_mut(blob.as_mut_ptr().cast::<u8>(), blob.len() * WORD)
+        };
+        blob_bytes[..encoded.len()].copy_from_slice(&encoded);
+
+        let range = dst.as_mut_ptr_range();
+        let result = unsafe { super::section_init_rle(range.start, range.end, blob.as_ptr()) };
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(word_bytes(&dst), data);
+
+        // the runs would overflow the section end
+        let end = range.end.wrapping_sub(1);
+        let result = unsafe { super::section_init_rle(range.start, end, blob.as_ptr()) };
+        assert_eq!(result, Err(rle::Error::LengthMismatch));
+    }
+
+    /// Returns sparse table of `entries` in word aligned memory.
+    #[cfg(feature = "sparse-init")]
+    fn sparse_table(entries: &[u32]) -> std::vec::Vec<Word> {
+        let bytes: std::vec::Vec<u8> = entries
+            .iter()
+            .flat_map(|entry| entry.to_le_bytes())
+            .collect();
+        let mut table = std::vec![0 as Word; bytes.len().div_ceil(WORD)];
+        let table_bytes = unsafe {
+            core::slice::from_raw_parts_mut(table.as_mut_ptr().cast::<u8>(), table.len() * WORD)
+        };
+        table_bytes[..bytes.len()].copy_from_slice(&bytes);
+
+        table
+    }
+
+    #[test]
+    #[cfg(feature = "sparse-init")]
+    fn section_init_sparse_tables() {
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+        let words = 4 * WORD / 4;
+
+        // the empty table leaves the section zeroed
+        let table = sparse_table(&[sparse::END, 0]);
+        unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) };
+        assert_eq!(dst, [0; 4]);
+
+        // the dense table writes every word, in any order
+        let entries: std::vec::Vec<u32> = (0..words as u32)
+            .rev()
+            .flat_map(|offset| [offset, 0x100 + offset])
+            .chain([sparse::END, 0])
+            .collect();
+        let table = sparse_table(&entries);
+        unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) };
+        let expected: std::vec::Vec<u8> = (0..words as u32)
+            .flat_map(|offset| (0x100 + offset).to_le_bytes())
+            .collect();
+        assert_eq!(word_bytes(&dst), expected);
+
+        // the raw image is copied as it is
+        let entries: std::vec::Vec<u32> = [sparse::RAW, words as u32]
+            .into_iter()
+            .chain((0..words as u32).map(|offset| 0x200 + offset))
+            .collect();
+        let table = sparse_table(&entries);
+        unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) };
+        assert_eq!(word_bytes(&dst), word_bytes(&sparse_table(&entries[2..])));
+    }
+
+    #[test]
+    #[cfg(all(feature = "sparse-init", not(assert_bounds)))]
+    fn section_init_sparse_skips_out_of_range() {
+        let (mut dst, _) = test_words::<5>();
+        let range = dst.as_mut_ptr_range();
+        let end = range.end.wrapping_sub(1);
+
+        // the word at the section end is not written, so the word past it keeps its content
+        let words = 4 * WORD / 4;
+        let table = sparse_table(&[1, 0x5A, words as u32, 0xA5, sparse::END, 0]);
+        unsafe { section_init_sparse(range.start, end, table.as_ptr()) };
+        assert_eq!(dst[4], Word::from_le_bytes([0xA5; WORD]));
+        assert_eq!(word_bytes(&dst[..4])[4..8], 0x5Au32.to_le_bytes());
+    }
+
+    #[test]
+    #[cfg(all(feature = "sparse-init", feature = "std"))]
+    fn sparse_encode_blob() {
+        let mut image = std::vec![0; 16 * 4];
+        image[4 * 3] = 0x5A;
+        image[4 * 15 + 3] = 0xA5;
+
+        // two nonzero words of sixteen make the sparse table
+        let blob = sparse::encode_blob(&image, sparse::DEFAULT_THRESHOLD_PERCENT);
+        assert_eq!(blob, sparse::encode(&image));
+        assert_eq!(blob.len(), 3 * 8);
+
+        // all of them nonzero make the raw image
+        image.fill(0x11);
+        let blob = sparse::encode_blob(&image, sparse::DEFAULT_THRESHOLD_PERCENT);
+        assert_eq!(blob, sparse::encode_raw(&image));
+        assert_eq!(blob.len(), 8 + image.len());
+
+        // both initialize the section to the image
+        for threshold in [0, 100] {
+            image[4 * 7..4 * 8].fill(0);
+            let (mut dst, _) = test_words::<{ 16 * 4 / WORD }>();
+            let range = dst.as_mut_ptr_range();
+            let blob = sparse::encode_blob(&image, threshold);
+            let entries: std::vec::Vec<u32> = blob
+                .chunks_exact(4)
+                .map(|entry| u32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]))
+                .collect();
+            let table = sparse_table(&entries);
+
+            unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) };
+            assert_eq!(word_bytes(&dst), image);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "sparse-init", assert_bounds))]
+    #[should_panic]
+    fn section_init_sparse_out_of_range() {
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+
+        // the word at the section end
+        let table = sparse_table(&[0, 0x5A, 4 * WORD as u32 / 4, 0xA5, sparse::END, 0]);
+        unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) };
+    }
+
+    #[test]
+    #[cfg(all(feature = "sparse-init", assert_bounds))]
+    #[should_panic]
+    fn section_init_sparse_raw_length_mismatch() {
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+
+        // the raw image one word shorter than the section
+        let table = sparse_table(&[sparse::RAW, 4 * WORD as u32 / 4 - 1, 1, 2, 3, 4, 5, 6, 7]);
+        unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) };
+    }
+
+    /// Returns samples of repeated patterns, text and incompressible bytes.
+    #[cfg(all(feature = "compress-heatshrink", feature = "std"))]
+    fn heatshrink_samples() -> std::vec::Vec<std::vec::Vec<u8>> {
+        let text = b"heatshrink decodes the section bit by bit, heatshrink saves flash. ";
+        let noise = (0..2000u32)
+            .map(|index| (index.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+
+        std::vec![
+            std::vec![],
+            std::vec![7],
+            std::vec![0; 3000],
+            text.repeat(30),
+            noise,
+        ]
+    }
+
+    #[test]
+    #[cfg(all(feature = "compress-heatshrink", feature = "std"))]
+    fn heatshrink_round_trip() {
+        for (window_sz2, lookahead_sz2) in [(4, 3), (8, 4), (10, 5), (15, 14)] {
+            let parameters = heatshrink::Parameters::new(window_sz2, lookahead_sz2).unwrap();
+
+            for data in heatshrink_samples() {
+                let stream = heatshrink::encode(&data, window_sz2, lookahead_sz2);
+                assert!(stream.len() <= heatshrink::stream_bound(data.len()));
+
+                let mut decoded = std::vec![0xA5; data.len()];
+                assert_eq!(
+                    heatshrink::decode(&mut decoded, &stream, parameters),
+                    Ok(data.len())
+                );
+                assert_eq!(decoded, data);
+
+                let blob = heatshrink::encode_blob(&data, window_sz2, lookahead_sz2);
+                decoded.fill(0xA5);
+                assert_eq!(heatshrink::decode_blob(&mut decoded, &blob), Ok(()));
+                assert_eq!(decoded, data);
+            }
+        }
+
+        // repeated text shrinks well within the window
+        let text = heatshrink_samples().swap_remove(3);
+        assert!(heatshrink::encode(&text, 8, 4).len() < text.len() / 4);
+    }
+
+    #[test]
+    #[cfg(feature = "compress-heatshrink")]
+    fn heatshrink_reference_stream() {
+        // literals 'a' and 'b', a reference of offset 2 and count 5, zero padding
+        let stream = [0xB0, 0xD8, 0x83, 0x00];
+        let parameters = heatshrink::Parameters::new(4, 3).unwrap();
+        let mut dst = [0; 7];
+
+        assert_eq!(heatshrink::decode(&mut dst, &stream, parameters), Ok(7));
+        assert_eq!(&dst, b"abababa");
+    }
+
+    #[test]
+    #[cfg(feature = "compress-heatshrink")]
+    fn heatshrink_malformed() {
+        let parameters = heatshrink::Parameters::new(4, 3).unwrap();
+        let mut dst = [0; 4];
+
+        assert_eq!(heatshrink::decode(&mut dst, &[], parameters), Ok(0));
+        // a reference before anything decoded
+        assert_eq!(
+            heatshrink::decode(&mut dst, &[0x00, 0x00], parameters),
+            Err(heatshrink::Error::InvalidOffset)
+        );
+        // literal 'a' followed by a reference of count 8
+        assert_eq!(
+            heatshrink::decode(&mut dst, &[0xB0, 0x83, 0x80], parameters),
+            Err(heatshrink::Error::OutputOverrun)
+        );
+        assert_eq!(
+            heatshrink::decode(&mut dst[..1], &[0xB0, 0xD8, 0x80], parameters),
+            Err(heatshrink::Error::OutputOverrun)
+        );
+
+        assert_eq!(
+            heatshrink::Parameters::new(16, 4),
+            Err(heatshrink::Error::InvalidParameters)
+        );
+        assert_eq!(
+            heatshrink::Parameters::new(8, 8),
+            Err(heatshrink::Error::InvalidParameters)
+        );
+
+        let mut blob = std::vec![2, 0, 0, 0, 3, 0, 0, 0, 4, 3, 0, 0, 0xB0, 0xD8, 0x80];
+        let mut dst = [0; 2];
+        assert_eq!(heatshrink::decode_blob(&mut dst, &blob), Ok(()));
+        assert_eq!(&dst, b"ab");
+        assert_eq!(
+            heatshrink::decode_blob(&mut dst, &blob[..14]),
+            Err(heatshrink::Error::Truncated)
+        );
+        assert_eq!(
+            heatshrink::decode_blob(&mut dst[..1], &blob),
+            Err(heatshrink::Error::LengthMismatch)
+        );
+
+        // other settings or a raw stream without header fail instead of decoding garbage
+        blob[9] = 4;
+        assert_eq!(
+            heatshrink::decode_blob(&mut dst, &blob),
+            Err(heatshrink::Error::InvalidParameters)
+        );
+        blob[9] = 3;
+        blob[11] = 1;
+        assert_eq!(
+            heatshrink::decode_blob(&mut dst, &blob),
+            Err(heatshrink::Error::InvalidParameters)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "compress-heatshrink", feature = "std"))]
+    fn heatshrink_malformed_bounded() {
+        let data = heatshrink_samples().swap_remove(3);
+        let stream = heatshrink::encode(&data, 8, 4);
+        let parameters = heatshrink::Parameters::new(8, 4).unwrap();
+        let mut dst = std::vec![0; data.len()];
+
+        for end in 0..stream.len() {
+            assert_ne!(
+                heatshrink::decode(&mut dst, &stream[..end], parameters),
+                Ok(data.len())
+            );
+        }
+        for index in 0..stream.len() {
+            let mut corrupted = stream.clone();
+            corrupted[index] ^= 0x5A;
+            let _ = heatshrink::decode(&mut dst, &corrupted, parameters);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "compress-heatshrink", feature = "std"))]
+    fn section_init_heatshrink() {
+        let (mut dst, _) = test_words::<64>();
+        let data: std::vec::Vec<u8> = (0..64 * WORD).map(|index| (index % 24) as u8).collect();
+
+        let encoded = heatshrink::encode_blob(&data, 8, 4);
+        let mut blob = std::vec![0 as Word; encoded.len().div_ceil(WORD)];
+        let blob_bytes = unsafe {
+            core::slice::from_raw_parts_mut(blob.as_mut_ptr().cast::<u8>(), blob.len() * WORD)
+        };
+        blob_bytes[..encoded.len()].copy_from_slice(&encoded);
+
+        let range = dst.as_mut_ptr_range();
+        let result =
+            unsafe { super::section_init_heatshrink(range.start, range.end, blob.as_ptr()) };
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(word_bytes(&dst), data);
+
+        // the decoded length differs from the section length
+        let end = range.end.wrapping_sub(1);
+        let result = unsafe { super::section_init_heatshrink(range.start, end, blob.as_ptr()) };
+        assert_eq!(result, Err(heatshrink::Error::LengthMismatch));
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    fn bounds_within() {
+        let ranges = [(0x100, 0x200)];
+
+        assert_eq!(bounds_violation(0x100, 0x10, &ranges), None);
+        assert_eq!(bounds_violation(0x1F0, 0x10, &ranges), None);
+        assert_eq!(bounds_violation(0x100, 0x100, &ranges), None);
+
+        // empty data is never accessed
+        assert_eq!(bounds_violation(0x300, 0, &ranges), None);
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    fn bounds_straddling() {
+        let ranges = [(0x100, 0x200)];
+
+        assert_eq!(bounds_violation(0xF0, 0x20, &ranges), Some(0xF0));
+        assert_eq!(bounds_violation(0x1F0, 0x20, &ranges), Some(0x200));
+        assert_eq!(bounds_violation(0x1F0, usize::MAX, &ranges), Some(0x200));
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    fn bounds_outside() {
+        let ranges = [(0x100, 0x200)];
+
+        assert_eq!(bounds_violation(0x80, 0x10, &ranges), Some(0x80));
+        assert_eq!(bounds_violation(0x200, 4, &ranges), Some(0x200));
+        assert_eq!(bounds_violation(0x300, 4, &ranges), Some(0x300));
+        assert_eq!(bounds_violation(0x300, 4, &[]), Some(0x300));
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    fn bounds_disjoint_ranges() {
+        // two banks, the second one followed by an adjacent third one
+        let ranges = [(0x400, 0x500), (0x100, 0x200), (0x500, 0x600)];
+
+        assert_eq!(bounds_violation(0x100, 0x100, &ranges), None);
+        assert_eq!(bounds_violation(0x480, 0x100, &ranges), None);
+        assert_eq!(bounds_violation(0x400, 0x200, &ranges), None);
+
+        // the gap between the banks is out of the bounds
+        assert_eq!(bounds_violation(0x1F0, 0x20, &ranges), Some(0x200));
+        assert_eq!(bounds_violation(0x300, 0x10, &ranges), Some(0x300));
+        assert_eq!(bounds_violation(0x580, 0x100, &ranges), Some(0x600));
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    #[should_panic(expected = "section load data out of flash bounds at 0xffffffffffffffff")]
+    fn section_init_out_of_flash_bounds() {
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+
+        // the test flash bounds end at `usize::MAX`, the load data straddles the end
+        let src = core::ptr::without_provenance::<Word>(usize::MAX - 2 * WORD + 1);
+        unsafe { section_init(range.start, range.end, src) };
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    #[should_panic(expected = "section at 0x800 out of RAM bounds at 0x800")]
+    fn section_fill_out_of_ram_bounds() {
+        // the test RAM bounds start at 0x1000, the section straddles the start
+        let dst = core::ptr::without_provenance_mut::<Word>(0x800);
+        let end = core::ptr::without_provenance::<Word>(0x1800);
+        unsafe { section_fill(dst, end, 0) };
+    }
+
+    #[test]
+    #[cfg(feature = "memory-bounds")]
+    fn section_ram_bounds_handler() {
+        static REPORTED: std::sync::Mutex<Option<(Section, usize)>> = std::sync::Mutex::new(None);
+
+        let section = Section::new(
+            core::ptr::without_provenance_mut(0x1800),
+            core::ptr::without_provenance(0x2800),
+            core::ptr::null(),
+        );
+        let handler = |section, address| *REPORTED.lock().unwrap() = Some((section, address));
+
+        assert!(check_ram_bounds(
+            section,
+            &[(0x1000, 0x2000), (0x2000, 0x3000)],
+            handler
+        ));
+        assert_eq!(*REPORTED.lock().unwrap(), None);
+
+        assert!(!check_ram_bounds(
+            section,
+            &[(0x1000, 0x2000), (0x3000, 0x4000)],
+            handler
+        ));
+        assert_eq!(*REPORTED.lock().unwrap(), Some((section, 0x2000)));
+    }
+
+    /// NOR flash over a vector, failing reads past `fail_at`.
+    #[cfg(feature = "nor-flash")]
+    struct VecFlash<const READ_SIZE: usize> {
+        data: std::vec::Vec<u8>,
+        reads: std::vec::Vec<(u32, usize)>,
+        fail_at: usize,
+    }
+
+    #[cfg(feature = "nor-flash")]
+    impl<const READ_SIZE: usize> VecFlash<READ_SIZE> {
+        fn new(len: usize) -> Self {
+            Self {
+                data: (0..len).map(|index| (index * 7) as u8).collect(),
+                reads: std::vec::Vec::new(),
+                fail_at: usize::MAX,
+            }
+        }
+    }
+
+    #[cfg(feature = "nor-flash")]
+    impl<const READ_SIZE: usize> nor::ReadNorFlash for VecFlash<READ_SIZE> {
+        type Error = u32;
+        const READ_SIZE: usize = READ_SIZE;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), u32> {
+            let start = offset as usize;
+            assert_eq!(start % READ_SIZE, 0);
+            assert_eq!(bytes.len() % READ_SIZE, 0);
+            if start + bytes.len() > self.fail_at.min(self.data.len()) {
+                return Err(offset);
+            }
+
+            self.reads.push((offset, bytes.len()));
+            bytes.copy_from_slice(&self.data[start..start + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "nor-flash")]
+    fn init_section_from_nor_copies() {
+        let mut flash = VecFlash::<1>::new(1024);
+        let (mut dst, _) = test_words::<50>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        let result = unsafe { nor::init_section_from_nor(&mut flash, 100, section) };
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(word_bytes(&dst), flash.data[100..100 + 50 * WORD]);
+        // read in chunks through the stack buffer
+        assert!(flash.reads.iter().all(|&(_, len)| len <= nor::READ_CHUNK));
+        assert_eq!(flash.reads.len(), (50 * WORD).div_ceil(nor::READ_CHUNK));
+    }
+
+    #[test]
+    #[cfg(feature = "nor-flash")]
+    fn init_section_from_nor_read_size() {
+        let mut flash = VecFlash::<16>::new(1024);
+        let (mut dst, _) = test_words::<16>();
+        let bytes =
+            unsafe { core::slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<u8>(), 16 * WORD) };
+        let range = bytes[..40].as_mut_ptr_range();
+        let section = Section::new(range.start.cast(), range.end.cast(), core::ptr::null());
+
+        let result = unsafe { nor::init_section_from_nor(&mut flash, 32, section) };
+
+        // the last read is rounded up, the section end is not written past
+        assert_eq!(result, Ok(()));
+        assert_eq!(bytes[..40], flash.data[32..72]);
+        assert!(bytes[40..].iter().all(|&byte| byte == 0xA5));
+        assert_eq!(flash.reads, [(32, 48)]);
+    }
+
+    #[test]
+    #[cfg(feature = "nor-flash")]
+    fn init_section_from_nor_error() {
+        let mut flash = VecFlash::<1>::new(1024);
+        flash.fail_at = nor::READ_CHUNK + 10;
+        let (mut dst, _) = test_words::<64>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        let result = unsafe { nor::init_section_from_nor(&mut flash, 0, section) };
+
+        // the chunk read before the error is kept, the rest is not touched
+        assert_eq!(result, Err(nor::READ_CHUNK as u32));
+        let bytes = word_bytes(&dst);
+        assert_eq!(bytes[..nor::READ_CHUNK], flash.data[..nor::READ_CHUNK]);
+        assert!(bytes[nor::READ_CHUNK..].iter().all(|&byte| byte == 0xA5));
+    }
+
+    #[test]
+    #[cfg(feature = "nor-flash")]
+    #[should_panic(expected = "flash read size does not divide the read chunk")]
+    fn init_section_from_nor_read_size_mismatch() {
+        let mut flash = VecFlash::<48>::new(1024);
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        let _ = unsafe { nor::init_section_from_nor(&mut flash, 0, section) };
+    }
+
+    /// Async NOR flash over [`VecFlash`], each read pending once before it completes.
+    #[cfg(feature = "async")]
+    struct AsyncVecFlash<const READ_SIZE: usize> {
+        flash: VecFlash<READ_SIZE>,
+        pending: usize,
+    }
+
+    #[cfg(feature = "async")]
+    impl<const READ_SIZE: usize> nor::AsyncReadNorFlash for AsyncVecFlash<READ_SIZE> {
+        type Error = u32;
+        const READ_SIZE: usize = READ_SIZE;
+
+        async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), u32> {
+            let mut yielded = false;
+            core::future::poll_fn(|cx| {
+                if yielded {
+                    core::task::Poll::Ready(())
+                } else {
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    core::task::Poll::Pending
+                }
+            })
+            .await;
+            self.pending += 1;
+
+            nor::ReadNorFlash::read(&mut self.flash, offset, bytes)
+        }
+
+        fn capacity(&self) -> usize {
+            self.flash.data.len()
+        }
+    }
+
+    /// Polls `future` to completion, returns its output and the number of polls pending.
+    #[cfg(feature = "async")]
+    fn block_on<T>(future: impl core::future::Future<Output = T>) -> (T, usize) {
+        let mut cx = core::task::Context::from_waker(core::task::Waker::noop());
+        let mut future = core::pin::pin!(future);
+        let mut pending = 0;
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                core::task::Poll::Ready(output) => return (output, pending),
+                core::task::Poll::Pending => pending += 1,
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn init_section_from_nor_async_copies() {
+        let mut flash = AsyncVecFlash {
+            flash: VecFlash::<4>::new(1024),
+            pending: 0,
+        };
+        let (mut dst, _) = test_words::<50>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        let (result, pending) =
+            block_on(unsafe { nor::init_section_from_nor_async(&mut flash, 100, section) });
+
+        // each chunk read is awaited
+        assert_eq!(result, Ok(()));
+        assert_eq!(word_bytes(&dst), flash.flash.data[100..100 + 50 * WORD]);
+        assert_eq!(pending, (50 * WORD).div_ceil(nor::READ_CHUNK));
+        assert_eq!(flash.pending, pending);
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn section_init_fsm_poll_nor_async() {
+        let mut flash = AsyncVecFlash {
+            flash: VecFlash::<1>::new(1024),
+            pending: 0,
+        };
+        let (mut dst, _) = test_words::<{ 160 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+        let middle = range.start.wrapping_byte_add(96);
+        let mut fsm = SectionInitFsm::new([
+            Section::new(range.start, middle, core::ptr::null()),
+            Section::new(middle, middle, core::ptr::null()),
+            Section::new(middle, range.end, core::ptr::null()),
+        ]);
+        let offsets = [0, 500, 300];
+
+        // a chunk and the short tail of the first section, the empty one is skipped by the last
+        let progress = [64, 96, 160];
+        for done in progress {
+            let (status, _) = block_on(unsafe { fsm.poll_nor_async(&mut flash, &offsets, 1) });
+            let expected = if done == 160 {
+                InitStatus::Done
+            } else {
+                InitStatus::InProgress { done, total: 160 }
+            };
+            assert_eq!(status, Ok(expected));
+        }
+
+        let bytes = word_bytes(&dst);
+        assert_eq!(bytes[..96], flash.flash.data[..96]);
+        assert_eq!(bytes[96..], flash.flash.data[300..364]);
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn section_init_fsm_poll_nor_async_error() {
+        let mut flash = AsyncVecFlash {
+            flash: VecFlash::<1>::new(1024),
+            pending: 0,
+        };
+        flash.flash.fail_at = nor::READ_CHUNK + 10;
+        let (mut dst, _) = test_words::<{ 128 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+        let mut fsm =
+            SectionInitFsm::new([Section::new(range.start, range.end, core::ptr::null())]);
+
+        let (status, _) = block_on(unsafe { fsm.poll_nor_async(&mut flash, &[0], 4) });
+        assert_eq!(status, Err(nor::READ_CHUNK as u32));
+        assert_eq!(
+            fsm.status(),
+            InitStatus::InProgress {
+                done: nor::READ_CHUNK,
+                total: 128
+            }
+        );
+
+        // the failed chunk is read again
+        flash.flash.fail_at = usize::MAX;
+        let (status, _) = block_on(unsafe { fsm.poll_nor_async(&mut flash, &[0], 4) });
+        assert_eq!(status, Ok(InitStatus::Done));
+        assert_eq!(word_bytes(&dst), flash.flash.data[..128]);
+    }
+
+    /// Source over a vector, failing reads past `fail_at`, recording the reads.
+    struct VecSource {
+        data: std::vec::Vec<u8>,
+        reads: std::vec::Vec<(usize, usize)>,
+        fail_at: usize,
+    }
+
+    impl VecSource {
+        fn new(len: usize) -> Self {
+            Self {
+                data: (0..len).map(|index| (index * 7) as u8).collect(),
+                reads: std::vec::Vec::new(),
+                fail_at: usize::MAX,
+            }
+        }
+    }
+
+    impl SectionSource for VecSource {
+        type Error = usize;
+
+        fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), usize> {
+            if offset + buf.len() > self.fail_at {
+                return Err(offset);
+            }
+
+            self.reads.push((offset, buf.len()));
+            buf.copy_from_slice(&self.data[offset..offset + buf.len()]);
+            Ok(())
+        }
+
+        fn len(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    #[test]
+    fn section_init_from_source_copies() {
+        let mut source = VecSource::new(200);
+        let (mut dst, _) = test_words::<{ 160 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        assert_eq!(unsafe { section.init_from(&mut source) }, Ok(()));
+
+        // read in pieces through the stack buffer
+        assert_eq!(word_bytes(&dst), source.data[..160]);
+        assert_eq!(source.reads, [(0, 64), (64, 64), (128, 32)]);
+    }
+
+    #[test]
+    fn section_init_from_source_chunked() {
+        let mut source = VecSource::new(160);
+        let (mut dst, _) = test_words::<{ 160 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+        let mut yields = 0;
+
+        let result = unsafe { section.init_chunked_from(&mut source, 100, || yields += 1) };
+
+        // the chunk is rounded down to words, the pieces do not cross the chunks
+        assert_eq!(result, Ok(()));
+        assert_eq!(word_bytes(&dst), source.data);
+        assert_eq!(yields, 1);
+        assert_eq!(source.reads, [(0, 64), (64, 32), (96, 64)]);
+    }
+
+    #[test]
+    fn section_init_from_source_error() {
+        let mut source = VecSource::new(160);
+        source.fail_at = 100;
+        let (mut dst, _) = test_words::<{ 160 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        let result = unsafe { section.init_from(&mut source) };
+
+        // the pieces read before the error are kept, the rest is not touched
+        assert_eq!(result, Err(64));
+        let bytes = word_bytes(&dst);
+        assert_eq!(bytes[..64], source.data[..64]);
+        assert!(bytes[64..].iter().all(|&byte| byte == 0xA5));
+    }
+
+    #[test]
+    #[should_panic(expected = "section source shorter than the section")]
+    fn section_init_from_source_short() {
+        let data = [0; 8];
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, core::ptr::null());
+
+        let _ = unsafe { section.init_from(&mut &data[..]) };
+    }
+
+    #[test]
+    fn section_sources() {
+        let (mut dst, src) = test_words::<16>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, src.as_ptr());
+
+        let mut mapped = unsafe { section.mapped_source() };
+        assert_eq!(mapped.len(), 16 * WORD);
+        assert_eq!(unsafe { section.init_from(&mut mapped) }, Ok(()));
+        assert_eq!(dst, src);
+
+        let mut buf = [0; 4];
+        assert_eq!(mapped.read(16 * WORD - 3, &mut buf), Err(SourceOutOfBounds));
+        assert_eq!(mapped.read(usize::MAX, &mut buf), Err(SourceOutOfBounds));
+
+        let bytes = word_bytes(&src);
+        let mut slice = &bytes[..];
+        assert_eq!(slice.read(8, &mut buf), Ok(()));
+        assert_eq!(buf, bytes[8..12]);
+        assert_eq!(slice.read(16 * WORD - 3, &mut buf), Err(SourceOutOfBounds));
+    }
+
+    #[test]
+    fn deferred_init_run_from() {
+        let (mut first, _) = test_words::<{ 96 / WORD }>();
+        let (mut second, _) = test_words::<{ 96 / WORD }>();
+        let (mut third, _) = test_words::<{ 96 / WORD }>();
+        let sections = [&mut first, &mut second, &mut third].map(|dst| {
+            let range = dst.as_mut_ptr_range();
+            Section::new(range.start, range.end, core::ptr::null())
+        });
+        let mut sources = [VecSource::new(96), VecSource::new(96), VecSource::new(96)];
+        sources[1].fail_at = 80;
+
+        let result = unsafe { DeferredInit::new(sections).run_from(sources.each_mut()) };
+
+        // the sections following the failed one are not touched
+        assert_eq!(result, Err(64));
+        assert_eq!(word_bytes(&first), sources[0].data);
+        assert_eq!(word_bytes(&second)[..64], sources[1].data[..64]);
+        assert!(word_bytes(&second)[64..].iter().all(|&byte| byte == 0xA5));
+        assert!(word_bytes(&third).iter().all(|&byte| byte == 0xA5));
+    }
+
+    #[test]
+    fn deferred_init_run_with_progress_from() {
+        let (mut first, _) = test_words::<{ 96 / WORD }>();
+        let (mut second, _) = test_words::<{ 32 / WORD }>();
+        let sections = [first.as_mut_ptr_range(), second.as_mut_ptr_range()]
+            .map(|range| Section::new(range.start, range.end, core::ptr::null()));
+        let mut sources = [VecSource::new(96), VecSource::new(32)];
+        let mut reports = std::vec::Vec::new();
+
+        let result = unsafe {
+            DeferredInit::new(sections).run_with_progress_from(sources.each_mut(), 64, |progress| {
+                reports.push((progress.section, progress.section_done, progress.done))
+            })
+        };
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(reports, [(0, 64, 64), (0, 96, 96), (1, 32, 128)]);
+        assert_eq!(word_bytes(&first), sources[0].data);
+        assert_eq!(word_bytes(&second), sources[1].data);
+    }
+
+    #[test]
+    fn section_init_copies_load_data() {
+        let mut dst = [0; 4];
+        let src = [0xDEAD_BEEF, 0xCAFE_BABE, 1, 2];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+        unsafe { section.init() };
+
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn section_init_if_true() {
+        let mut dst = [0; 4];
+        let src = [1, 2, 3, 4];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+        let initialized = unsafe { section.init_if(true) };
+
+        assert!(initialized);
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn section_init_if_false() {
+        let mut dst = [9; 4];
+        let src = [1, 2, 3, 4];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+        let initialized = unsafe { section.init_if(false) };
+
+        assert!(!initialized);
+        assert_eq!(dst, [9; 4]);
+    }
+
+    #[test]
+    fn section_init_in_place_copies_load_data() {
+        let mut dst = [0; 4];
+        let src = [1, 2, 3, 4];
+        let range = dst.as_mut_ptr_range();
+
+        unsafe { section_init_in_place(Section::new(range.start, range.end, src.as_ptr())) };
+
+        assert_eq!(dst, src);
+    }
+
+    /// Returns words filled with `0xA5` bytes and words holding their own byte offsets.
+    fn test_words<const N: usize>() -> ([Word; N], [Word; N]) {
+        let src = core::array::from_fn(|i| {
+            Word::from_le_bytes(core::array::from_fn(|j| (i * WORD + j) as u8))
+        });
+
+        ([Word::from_le_bytes([0xA5; WORD]); N], src)
+    }
+
+    /// Returns bytes of `words` in memory order.
+    fn word_bytes(words: &[Word]) -> std::vec::Vec<u8> {
+        words.iter().flat_map(|word| word.to_le_bytes()).collect()
+    }
+
+    /// Initializes section of `len` bytes by `init`, returns the whole destination buffer bytes.
+    fn init_unaligned_len(len: usize, init: unsafe fn(Section)) -> std::vec::Vec<u8> {
+        let (mut dst, src) = test_words::<{ 72 / WORD }>();
+        let start = dst.as_mut_ptr();
+        let end = start.cast::<u8>().wrapping_add(len).cast::<Word>();
+
+        unsafe { init(Section::new(start, end, src.as_ptr())) };
+
+        word_bytes(&dst)
+    }
+
+    fn assert_unaligned_len_copied(init: unsafe fn(Section)) {
+        for len in 0..64 {
+            let bytes = init_unaligned_len(len, init);
+
+            let expected: std::vec::Vec<u8> = (0..72usize)
+                .map(|i| if i < len { i as u8 } else { 0xA5 })
+                .collect();
+            assert_eq!(bytes, expected, "section of {len} bytes");
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-length"))]
+    fn section_init_unaligned_len() {
+        assert_unaligned_len_copied(|section| unsafe { section.init() });
+    }
+
+    #[test]
+    fn section_init_in_place_unaligned_len() {
+        assert_unaligned_len_copied(|section| unsafe { section_init_in_place(section) });
+    }
+
+    /// Initializes section of `len` bytes at `dst_offset` with data at `src_offset` by `init` for
+    /// all combinations of the offsets within a word, or the equal offsets only if `matched`,
+    /// checks against reference byte copy.
+    fn assert_unaligned_copied(init: unsafe fn(Section), matched: bool) {
+        let offsets = (0..WORD).flat_map(|dst| (0..WORD).map(move |src| (dst, src)));
+        for (dst_offset, src_offset) in offsets.filter(|(dst, src)| !matched || dst == src) {
+            for len in 0..=13 {
+                let (mut dst, src) = test_words::<{ 24 / WORD }>();
+
+                let start = dst.as_mut_ptr().cast::<u8>().wrapping_add(dst_offset);
+                let section = Section::new(
+                    start.cast(),
+                    start.wrapping_add(len).cast(),
+                    src.as_ptr().cast::<u8>().wrapping_add(src_offset).cast(),
+                );
+                unsafe { init(section) };
+
+                let bytes = word_bytes(&dst);
+                let expected: std::vec::Vec<u8> = (0..24usize)
+                    .map(|i| match i.checked_sub(dst_offset) {
+                        Some(copied) if copied < len => (src_offset + copied) as u8,
+                        _ => 0xA5,
+                    })
+                    .collect();
+                assert_eq!(
+                    bytes, expected,
+                    "dst +{dst_offset}, src +{src_offset}, {len} bytes"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "strict-alignment", feature = "strict-length")))]
+    fn section_init_unaligned() {
+        // the mismatched offsets fail the alignment check
+        assert_unaligned_copied(|section| unsafe { section.init() }, cfg!(assert_align));
+    }
+
+    #[test]
+    #[cfg(assert_align)]
+    #[should_panic]
+    fn section_init_alignment_mismatch() {
+        let (mut dst, src) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+
+        unsafe { section_init(range.start, range.end, src.as_ptr().wrapping_byte_add(1)) };
+    }
+
+    #[test]
+    fn section_init_in_place_unaligned() {
+        assert_unaligned_copied(|section| unsafe { section_init_in_place(section) }, false);
+    }
+
+    /// Words of the vector shared by the copy paths, several bursts of `fast-copy` and the rest.
+    const COPY_VECTOR_WORDS: usize = 8 * 3 + 5;
+
+    /// Initializes the leading words of a section by `init` for all lengths up to the shared
+    /// vector, checks against the vector.
+    fn assert_copy_vector(init: unsafe fn(Section)) {
+        let vector: [Word; COPY_VECTOR_WORDS] =
+            core::array::from_fn(|i| (i as Word).wrapping_mul(0x9E37_79B9) ^ 0x5A);
+
+        for words in 0..=COPY_VECTOR_WORDS {
+            let mut dst = [0; COPY_VECTOR_WORDS];
+            let start = dst.as_mut_ptr();
+
+            unsafe {
+                init(Section::new(
+                    start,
+                    start.wrapping_add(words),
+                    vector.as_ptr(),
+                ))
+            };
+
+            assert_eq!(dst[..words], vector[..words], "{words} words");
+            assert!(dst[words..].iter().all(|&word| word == 0), "{words} words");
+        }
+    }
+
+    #[test]
+    fn section_init_copy_vector() {
+        assert_copy_vector(|section| unsafe { section.init() });
+    }
+
+    #[test]
+    fn section_init_in_place_copy_vector() {
+        assert_copy_vector(|section| unsafe { section_init_in_place(section) });
+    }
+
+    /// Initializes 10 bytes of 16-byte buffer by `init`, returns the whole buffer bytes.
+    fn init_width(init: unsafe fn(*mut Word, *const Word, *const Word)) -> std::vec::Vec<u8> {
+        let (mut dst, src) = test_words::<{ 16 / WORD }>();
+        let start = dst.as_mut_ptr();
+
+        unsafe {
+            init(
+                start,
+                start.cast::<u8>().wrapping_add(10).cast(),
+                src.as_ptr(),
+            )
+        };
+
+        word_bytes(&dst)
+    }
+
+    #[test]
+    fn section_init_width_u8() {
+        assert_eq!(
+            init_width(section_init_u8),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5]
+        );
+    }
+
+    #[test]
+    fn section_init_width_u16() {
+        assert_eq!(
+            init_width(section_init_u16),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5]
+        );
+    }
+
+    #[test]
+    #[cfg(not(assert_align))]
+    fn section_init_width_u32() {
+        // the end is not aligned to the width, so the last partial word is left untouched
+        assert_eq!(
+            init_width(section_init_u32),
+            [0, 1, 2, 3, 4, 5, 6, 7, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5]
+        );
+    }
+
+    #[test]
+    #[cfg(assert_align)]
+    #[should_panic]
+    fn section_init_width_u32_misaligned() {
+        init_width(section_init_u32);
+    }
+
+    #[test]
+    fn section_init_width_u64() {
+        let (mut dst, src) = test_words::<{ 16 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+
+        unsafe { section_init_u64(range.start, range.end, src.as_ptr()) };
+
+        assert_eq!(word_bytes(&dst), word_bytes(&src));
+    }
+
+    #[test]
+    fn section_init_width_swap16() {
+        assert_eq!(
+            init_width(section_init_swap16),
+            [1, 0, 3, 2, 5, 4, 7, 6, 9, 8, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5]
+        );
+    }
+
+    #[test]
+    fn section_init_width_swap32() {
+        let (mut dst, src) = test_words::<{ 16 / WORD }>();
+        let range = dst.as_mut_ptr_range();
+
+        unsafe { section_init_swap32(range.start, range.end, src.as_ptr()) };
+
+        let reference: std::vec::Vec<u8> = word_bytes(&src)
+            .chunks(4)
+            .flat_map(|word| word.iter().rev().copied())
+            .collect();
+        assert_eq!(word_bytes(&dst), reference);
+    }
+
+    #[test]
+    #[cfg(not(assert_align))]
+    fn section_init_width_swap32_tail() {
+        // the end is not aligned to the width, so the last partial word is left untouched
+        assert_eq!(
+            init_width(section_init_swap32),
+            [3, 2, 1, 0, 7, 6, 5, 4, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5]
+        );
+    }
+
+    #[test]
+    #[cfg(assert_align)]
+    #[should_panic]
+    fn section_init_width_swap32_misaligned() {
+        init_width(section_init_swap32);
+    }
+
+    #[test]
+    fn section_init_widen_alignments() {
+        let src: [u8; 24] = core::array::from_fn(|i| i as u8 + 1);
+
+        for offset in 0..4 {
+            for len in 0..=13 {
+                let mut dst = [0xA5A5_A5A5u32; 5];
+                let start = dst.as_mut_ptr();
+                let end = start.cast::<u8>().wrapping_add(len);
+
+                unsafe { section_init_widen(start, end, src[offset..].as_ptr()) };
+
+                // the bytes copied, the final word padded by zeros and the rest untouched
+                let padded = len.next_multiple_of(4);
+                let mut expected = [0xA5; 20];
+                expected[..padded].fill(0);
+                expected[..len].copy_from_slice(&src[offset..offset + len]);
+
+                let bytes: std::vec::Vec<u8> =
+                    dst.iter().flat_map(|word| word.to_ne_bytes()).collect();
+                assert_eq!(bytes, expected, "offset {offset}, len {len}");
+            }
+        }
+    }
+
+    fn fill_width(fill: unsafe fn(*mut Word, *const Word, Word), len: usize) -> std::vec::Vec<u8> {
+        let (mut dst, _) = test_words::<{ 16 / WORD }>();
+        let start = dst.as_mut_ptr();
+        let value = Word::from_le_bytes(core::array::from_fn(|i| i as u8));
+
+        unsafe { fill(start, start.cast::<u8>().wrapping_add(len).cast(), value) };
+
+        word_bytes(&dst)
+    }
+
+    fn filled(len: usize) -> std::vec::Vec<u8> {
+        (0..16)
+            .map(|i| if i < len { (i % WORD) as u8 } else { 0xA5 })
+            .collect()
+    }
+
+    #[test]
+    fn section_fill_width_u32() {
+        assert_eq!(fill_width(section_fill_u32, 12), filled(12));
+    }
+
+    #[test]
+    fn section_fill_width_u64() {
+        assert_eq!(fill_width(section_fill_u64, 16), filled(16));
+    }
+
+    #[test]
+    #[cfg(assert_align)]
+    #[should_panic]
+    fn section_fill_width_u64_partial_word() {
+        fill_width(section_fill_u64, 12);
+    }
+
+    #[test]
+    fn section_init_generic_adjacent() {
+        let mut memory = [0u16, 0, 0, 1, 2, 3];
+        let range = memory.as_mut_ptr_range();
+        let load = range.start.wrapping_add(3);
+
+        // the load data directly following the section does not overlap it
+        unsafe { section_init_generic(range.start, load, load) };
+
+        assert_eq!(memory, [1, 2, 3, 1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(all(assert_overlap, not(feature = "overlap-ok")))]
+    #[should_panic]
+    fn section_init_generic_overlap() {
+        let mut memory = [0u16; 6];
+        let range = memory.as_mut_ptr_range();
+        let load = range.start.wrapping_add(2);
+
+        unsafe { section_init_generic(range.start, range.start.wrapping_add(3), load) };
+    }
+
+    #[test]
+    #[cfg(feature = "overlap-ok")]
+    fn section_init_generic_overlap_downward() {
+        let mut memory = [0u16, 1, 2, 3, 4, 5];
+        let range = memory.as_mut_ptr_range();
+        let load = range.start.wrapping_add(2);
+
+        unsafe { section_init_generic(range.start, range.start.wrapping_add(3), load) };
+
+        assert_eq!(memory, [2, 3, 4, 3, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(assert_overlap)]
+    #[should_panic]
+    fn section_init_generic_dst_inside_src() {
+        let mut memory = [0u16; 6];
+        let range = memory.as_mut_ptr_range();
+        let dst = range.start.wrapping_add(2);
+
+        // copying by ascending addresses would overwrite the source before reading it
+        unsafe { section_init_generic(dst, dst.wrapping_add(3), range.start) };
+    }
+
+    /// Bytes past the last whole word of the overlapping sections, none with `strict-length`
+    /// feature.
+    #[cfg(feature = "overlap-ok")]
+    const OVERLAP_TAIL: usize = if cfg!(feature = "strict-length") {
+        0
+    } else {
+        3
+    };
+
+    /// Copies `len` bytes at offset `src` to offset `dst` within memory holding its byte offsets
+    /// and checks the memory is the same as copied by `copy_within`.
+    fn assert_overlap_copied(dst: usize, src: usize, len: usize) {
+        let (_, mut memory) = test_words::<{ 96 / WORD }>();
+        let mut expected = word_bytes(&memory);
+        expected.copy_within(src..src + len, dst);
+
+        let base = memory.as_mut_ptr() as usize;
+        unsafe { init_region(base + dst, base + dst + len, base + src) };
+
+        assert_eq!(word_bytes(&memory), expected, "{dst} <- {src}, {len} bytes");
+    }
+
+    #[test]
+    #[cfg(feature = "overlap-ok")]
+    fn section_init_overlap_downward() {
+        for len in [WORD - OVERLAP_TAIL, 3 * WORD, 5 * WORD + OVERLAP_TAIL] {
+            assert_overlap_copied(0, WORD, len);
+            assert_overlap_copied(WORD, 3 * WORD, len);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "overlap-ok")]
+    fn section_init_overlap_upward() {
+        for len in [WORD - OVERLAP_TAIL, 3 * WORD, 5 * WORD + OVERLAP_TAIL] {
+            assert_overlap_copied(WORD, 0, len);
+            assert_overlap_copied(3 * WORD, WORD, len);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "overlap-ok", not(feature = "strict-alignment")))]
+    fn section_init_overlap_unaligned() {
+        let offsets = [(1, WORD + 1), (WORD + 1, 1), (1, 6), (6, 1), (3, 4), (4, 3)];
+        // the offsets differing in word alignment are rejected with `assert-align` feature
+        let matched =
+            |&(dst, src): &(usize, usize)| !cfg!(assert_align) || dst % WORD == src % WORD;
+        for (dst, src) in offsets.into_iter().filter(matched) {
+            assert_overlap_copied(dst, src, 5 * WORD + 3);
+        }
+    }
+
+    #[test]
+    fn section_init_aliasing() {
+        let (_, mut memory) = test_words::<4>();
+        let expected = memory;
+        let range = memory.as_mut_ptr_range();
+
+        // the load address equals the run address, nothing is copied nor rejected as an overlap
+        unsafe { section_init(range.start, range.end, range.start) };
+
+        assert_eq!(memory, expected);
+    }
+
+    #[test]
+    fn section_init_overlap_adjacent() {
+        assert_overlap_copied(0, 3 * WORD, 3 * WORD);
+        assert_overlap_copied(3 * WORD, 0, 3 * WORD);
+    }
+
+    #[test]
+    fn section_init_adjacent_regions() {
+        type Init = unsafe fn(*mut Word, *const Word, *const Word);
+        let copiers: [(&str, Init); 4] = [
+            ("section_init", section_init),
+            ("section_init_u32", section_init_u32),
+            ("section_init_with", |dst, end, src| unsafe {
+                section_init_with(&mut CpuCopier, dst, end, src)
+            }),
+            ("section_init_widen", |dst, end, src| unsafe {
+                section_init_widen(dst.cast(), end.cast(), src.cast())
+            }),
+        ];
+
+        for (name, init) in copiers {
+            // the load data right above the section, then right below it
+            for (dst, src) in [(0, 4), (4, 0)] {
+                let (_, mut memory) = test_words::<8>();
+                let mut expected = memory;
+                expected.copy_within(src..src + 4, dst);
+
+                let base = memory.as_mut_ptr();
+                unsafe { init(base.add(dst), base.add(dst + 4), base.add(src)) };
+
+                assert_eq!(memory, expected, "{name}, {dst} <- {src}");
+            }
+        }
+    }
+
+    #[test]
+    fn overlaps_layouts() {
+        let len = 4 * WORD;
+
+        for (offset, expected) in [
+            (0, true),
+            (WORD, true),
+            // a single word shared, missed if the length were taken in words
+            (3 * WORD, true),
+            (3 * WORD + 1, true),
+            (len - 1, true),
+            // adjacent
+            (len, false),
+            (len + 1, false),
+            (4 * len, false),
+        ] {
+            let (low, high) = (0x1000, 0x1000 + offset);
+
+            assert_eq!(
+                overlaps(low, high, len),
+                expected,
+                "dst below src by {offset}"
+            );
+            assert_eq!(
+                overlaps(high, low, len),
+                expected,
+                "src below dst by {offset}"
+            );
+        }
+
+        // an empty region shares no byte
+        assert!(!overlaps(0x1000, 0x1000, 0));
+    }
+
+    #[test]
+    #[cfg(all(assert_overlap, not(feature = "overlap-ok")))]
+    #[should_panic]
+    fn section_init_overlap_single_word() {
+        let (_, mut memory) = test_words::<8>();
+        let range = memory.as_mut_ptr_range();
+
+        // 4-word section sharing its last word with its load data
+        unsafe {
+            section_init(
+                range.start,
+                range.start.wrapping_add(4),
+                range.start.wrapping_add(3),
+            )
+        };
+    }
+
+    #[test]
+    #[cfg(feature = "overlap-ok")]
+    fn section_init_overlap_aliased() {
+        assert_overlap_copied(WORD, WORD, 5 * WORD + OVERLAP_TAIL);
+    }
+
+    /// Copier recording `(dst, src, len)` of each copy, the addresses as offsets from `base`.
+    struct SpyCopier {
+        base: usize,
+        copies: std::vec::Vec<(usize, usize, usize)>,
+    }
+
+    impl SectionCopier for SpyCopier {
+        unsafe fn copy(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+            self.copies
+                .push((dst as usize - self.base, src as usize - self.base, len));
+            unsafe { CpuCopier.copy(dst, src, len) };
+        }
+    }
+
+    /// Copies `len` bytes at offset `src` to offset `dst` within memory holding its byte offsets
+    /// by [`SpyCopier`], checks the memory and returns the copier's copies.
+    fn init_with_copier(
+        dst: usize,
+        src: usize,
+        len: usize,
+    ) -> std::vec::Vec<(usize, usize, usize)> {
+        let (_, mut memory) = test_words::<{ 96 / WORD }>();
+        let mut expected = word_bytes(&memory);
+        expected.copy_within(src..src + len, dst);
+
+        let base = memory.as_mut_ptr().cast::<u8>();
+        let section = Section::new(
+            base.wrapping_add(dst).cast(),
+            base.wrapping_add(dst + len).cast(),
+            base.wrapping_add(src).cast(),
+        );
+        let mut copier = SpyCopier {
+            base: base as usize,
+            copies: std::vec::Vec::new(),
+        };
+
+        unsafe { section.init_with(&mut copier) };
+
+        assert_eq!(word_bytes(&memory), expected);
+        copier.copies
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-length"))]
+    fn section_init_with_copier_body() {
+        // the trailing bytes not forming a word are copied by the CPU
+        assert_eq!(
+            init_with_copier(0, 6 * WORD, 3 * WORD + 3),
+            [(0, 6 * WORD, 3 * WORD)]
+        );
+        assert_eq!(init_with_copier(0, 6 * WORD, 3), []);
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-alignment"))]
+    fn section_init_with_copier_unaligned() {
+        assert_eq!(
+            init_with_copier(1, 6 * WORD + 1, 5 * WORD),
+            [(WORD, 7 * WORD, 4 * WORD)]
+        );
+
+        // the addresses are never word aligned at the same time
+        #[cfg(not(assert_align))]
+        assert_eq!(init_with_copier(1, 6 * WORD + 2, 5 * WORD), []);
+    }
+
+    #[test]
+    #[cfg(feature = "strict-length")]
+    #[should_panic]
+    fn section_init_with_copier_unaligned_length() {
+        init_with_copier(0, 6 * WORD, 3 * WORD + 3);
+    }
+
+    #[test]
+    #[cfg(assert_align)]
+    #[should_panic]
+    fn section_init_with_copier_alignment_mismatch() {
+        init_with_copier(1, 6 * WORD + 2, 5 * WORD);
+    }
+
+    /// Critical section recording the nesting depth of the current thread, so the tests see whether
+    /// the code runs within the critical section.
+    struct DepthCriticalSection;
+
+    critical_section::set_impl!(DepthCriticalSection);
+
+    std::thread_local! {
+        static CRITICAL_DEPTH: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+    }
+
+    unsafe impl critical_section::Impl for DepthCriticalSection {
+        unsafe fn acquire() -> critical_section::RawRestoreState {
+            CRITICAL_DEPTH.set(CRITICAL_DEPTH.get() + 1);
+        }
+
+        unsafe fn release(_restore_state: critical_section::RawRestoreState) {
+            CRITICAL_DEPTH.set(CRITICAL_DEPTH.get() - 1);
+        }
+    }
+
+    /// Copier recording whether each copy runs within the critical section.
+    struct LockProbe(std::vec::Vec<bool>);
+
+    impl SectionCopier for LockProbe {
+        unsafe fn copy(&mut self, dst: *mut u8, src: *const u8, len: usize) {
+            self.0.push(CRITICAL_DEPTH.get() > 0);
+            unsafe { copy_bytes(dst, src, len) };
+        }
+    }
+
+    #[test]
+    fn deferred_init_run_locked() {
+        let (mut dst, src) = test_words::<4>();
+        let start = dst.as_mut_ptr();
+        let load = src.as_ptr();
+        let deferred = DeferredInit::new([
+            Section::new(start, start.wrapping_add(2), load),
+            Section::new(
+                start.wrapping_add(2),
+                start.wrapping_add(4),
+                load.wrapping_add(2),
+            ),
+        ])
+        .with_unlocked([false, true]);
+        let mut probe = LockProbe(std::vec::Vec::new());
+
+        unsafe { deferred.run_with(&mut probe) };
+
+        // the section opted out is copied with the critical section free
+        assert_eq!(probe.0, [cfg!(feature = "critical-section"), false]);
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn deferred_init_run_with_copier() {
+        let (mut dst, src) = test_words::<4>();
+        let start = dst.as_mut_ptr();
+        let load = src.as_ptr();
+        let deferred = DeferredInit::new([
+            Section::new(start, start.wrapping_add(1), load),
+            Section::new(
+                start.wrapping_add(2),
+                start.wrapping_add(4),
+                load.wrapping_add(2),
+            ),
+        ]);
+        let mut copier = SpyCopier {
+            base: 0,
+            copies: std::vec::Vec::new(),
+        };
+
+        unsafe { deferred.run_with(&mut copier) };
+
+        let (start, load) = (start as usize, load as usize);
+        assert_eq!(
+            copier.copies,
+            [
+                (start, load, WORD),
+                (start + 2 * WORD, load + 2 * WORD, 2 * WORD)
+            ]
+        );
+        assert_eq!([dst[0], dst[2], dst[3]], [src[0], src[2], src[3]]);
+        assert_eq!(dst[1], test_words::<4>().0[1]);
+    }
+
+    /// Relocates sections given by `(start, end, load)` byte offsets within memory holding its
+    /// byte offsets, checks each section holds its load data then, the rest of memory is intact,
+    /// and returns the load offsets in the order the sections were moved.
+    fn assert_relocated(layout: &[(usize, usize, usize)]) -> std::vec::Vec<usize> {
+        let (_, mut memory) = test_words::<{ 96 / WORD }>();
+        let image = word_bytes(&memory);
+        let mut expected = image.clone();
+        for &(start, end, load) in layout {
+            expected[start..end].copy_from_slice(&image[load..load + end - start]);
+        }
+
+        let base = memory.as_mut_ptr().cast::<u8>();
+        let mut sections: std::vec::Vec<Section> = layout
+            .iter()
+            .map(|&(start, end, load)| {
+                Section::new(
+                    base.wrapping_add(start).cast(),
+                    base.wrapping_add(end).cast(),
+                    base.wrapping_add(load).cast(),
+                )
+            })
+            .collect();
+
+        unsafe { relocate(&mut sections) };
+
+        assert_eq!(word_bytes(&memory), expected);
+        sections
+            .iter()
+            .map(|section| section.load() as usize - base as usize)
+            .collect()
+    }
+
+    #[test]
+    fn relocate_upward_overlapping_image() {
+        // each section moves up over the load data of the following one
+        let order = assert_relocated(&[(10, 30, 0), (30, 46, 20), (50, 58, 36)]);
+
+        assert_eq!(order, [36, 20, 0]);
+    }
+
+    #[test]
+    fn relocate_downward_overlapping_image() {
+        // the second section moves down over the load data of the first one
+        let order = assert_relocated(&[(51, 67, 60), (30, 50, 40)]);
+
+        assert_eq!(order, [40, 60]);
+    }
+
+    #[test]
+    fn relocate_independent_keep_order() {
+        let order = assert_relocated(&[(0, 8, 64), (8, 40, 8), (48, 60, 44), (72, 80, 88)]);
+
+        assert_eq!(order, [64, 8, 44, 88]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn relocate_cycle() {
+        // two sections swapping places
+        assert_relocated(&[(16, 32, 0), (0, 16, 16)]);
+    }
+
+    std::thread_local! {
+        static SPY_ACCESSES: core::cell::RefCell<std::vec::Vec<usize>> =
+            const { core::cell::RefCell::new(std::vec::Vec::new()) };
+    }
+
+    /// Halfword recording width of each copy it is accessed by.
+    struct SpyHalfword(#[allow(dead_code)] u16);
+
+    impl VolatileCopy for SpyHalfword {
+        unsafe fn copy(dst: *mut Self, src: *const Self) {
+            SPY_ACCESSES.with(|accesses| accesses.borrow_mut().push(core::mem::size_of::<Self>()));
+            unsafe { u16::copy(dst.cast(), src.cast()) };
+        }
+    }
+
+    #[test]
+    fn section_init_width_halfword_accesses() {
+        SPY_ACCESSES.with(|accesses| accesses.borrow_mut().clear());
+
+        let bytes = init_width(|dst, end, src| unsafe {
+            section_init_generic::<SpyHalfword>(dst.cast(), end.cast(), src.cast())
+        });
+
+        // each halfword is copied separately, never merged into wider accesses
+        assert_eq!(SPY_ACCESSES.with(|accesses| accesses.take()), [2; 5]);
+        assert_eq!(
+            bytes,
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5, 0xA5]
+        );
+    }
+
+    std::thread_local! {
+        static FLAKY_WRITES: core::cell::Cell<(usize, usize)> = const { core::cell::Cell::new((0, 0)) };
+    }
+
+    /// Word dropping every `k`-th write set by [`flaky_init`], as memory failing during bring-up.
+    struct FlakyWord(#[allow(dead_code)] Word);
+
+    impl VolatileCopy for FlakyWord {
+        unsafe fn copy(dst: *mut Self, src: *const Self) {
+            let (writes, k) = FLAKY_WRITES.get();
+            FLAKY_WRITES.set((writes + 1, k));
+
+            if (writes + 1) % k != 0 {
+                unsafe { Word::copy(dst.cast(), src.cast()) };
+            }
+        }
+    }
+
+    impl VolatileVerify for FlakyWord {
+        unsafe fn matches(dst: *const Self, src: *const Self) -> bool {
+            unsafe { Word::matches(dst.cast(), src.cast()) }
+        }
+    }
+
+    /// Copies 4 words to flaky memory dropping every `k`-th write, returns the memory, the
+    /// mismatch word index and the number of writes.
+    fn flaky_init(k: usize, retries: usize) -> ([Word; 4], Option<usize>, usize) {
+        let (mut dst, src) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+        FLAKY_WRITES.set((0, k));
+
+        let mismatch = unsafe {
+            section_init_verified::<FlakyWord>(
+                range.start.cast(),
+                range.end.cast(),
+                src.as_ptr().cast(),
+                retries,
+            )
+        };
+        let mismatch = mismatch.map(|address| (address - range.start as usize) / WORD);
+
+        (dst, mismatch, FLAKY_WRITES.get().0)
+    }
+
+    #[test]
+    fn section_init_verified_retries_dropped_writes() {
+        let (dst, mismatch, writes) = flaky_init(2, 1);
+
+        // each even write is dropped, so each word but the first is written twice
+        assert_eq!((mismatch, writes), (None, 7));
+        assert_eq!(dst, test_words::<4>().1);
+    }
+
+    #[test]
+    fn section_init_verified_reports_mismatch() {
+        let (dst, mismatch, writes) = flaky_init(3, 0);
+
+        // the 3rd write is dropped without retry, the following words are left untouched
+        assert_eq!((mismatch, writes), (Some(2), 3));
+        assert_eq!(word_bytes(&dst[..2]), word_bytes(&test_words::<4>().1[..2]));
+        assert_eq!(dst[2..], test_words::<4>().0[2..]);
+    }
+
+    #[test]
+    fn section_init_verified_persistent_mismatch() {
+        let (_, mismatch, writes) = flaky_init(1, VERIFY_WRITE_RETRIES);
+
+        // all the writes are dropped, the first word is retried up to the limit
+        assert_eq!((mismatch, writes), (Some(0), VERIFY_WRITE_RETRIES + 1));
+    }
+
+    /// Copies section of `len` bytes at `dst_offset` from data at `src_offset`, lets `mutate` alter
+    /// the copied memory bytes, returns the comparison with the load data and the section start.
+    #[cfg(feature = "verify")]
+    fn verify_mutated(
+        dst_offset: usize,
+        src_offset: usize,
+        len: usize,
+        mutate: impl FnOnce(&mut [u8]),
+    ) -> (Result<(), InitError>, usize) {
+        let (mut dst, src) = test_words::<{ 48 / WORD }>();
+        let start = dst.as_mut_ptr().cast::<u8>().wrapping_add(dst_offset);
+        let end = start.wrapping_add(len);
+        let load = src.as_ptr().cast::<u8>().wrapping_add(src_offset);
+
+        unsafe { copy_bytes(start, load, len) };
+        mutate(unsafe { core::slice::from_raw_parts_mut(start, len) });
+
+        let result = unsafe { section_compare(start.cast(), end.cast(), load.cast()) };
+        (result, start.addr())
+    }
+
+    #[test]
+    #[cfg(feature = "verify")]
+    fn section_compare_matches() {
+        for (dst_offset, src_offset) in [(0, 0), (1, 1), (WORD - 1, WORD - 1), (0, 1), (3, 2)] {
+            for len in [0, 1, WORD, 4 * WORD + 3] {
+                let (result, _) = verify_mutated(dst_offset, src_offset, len, |_| {});
+                assert_eq!(
+                    result,
+                    Ok(()),
+                    "dst +{dst_offset}, src +{src_offset}, {len} bytes"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "verify")]
+    fn section_compare_detects_mutation() {
+        let len = 4 * WORD + 3;
+        // the load data bytes hold their offsets
+        let word_at =
+            |offset: usize| Word::from_le_bytes(core::array::from_fn(|i| (offset + i) as u8));
+
+        // a word of the body, the first mismatch is reported
+        let (result, start) = verify_mutated(0, 0, len, |bytes| {
+            bytes[WORD + 2] ^= 0x10;
+            bytes[2 * WORD] ^= 0x10;
+        });
+        let expected = word_at(WORD);
+        assert_eq!(
+            result,
+            Err(InitError::VerifyMismatch {
+                address: start + WORD,
+                expected,
+                actual: expected ^ (0x10 << 16)
+            })
+        );
+
+        // the tail bytes
+        let (result, start) = verify_mutated(0, 0, len, |bytes| bytes[len - 1] ^= 0x10);
+        assert_eq!(
+            result,
+            Err(InitError::VerifyMismatch {
+                address: start + len - 1,
+                expected: (len - 1) as Word,
+                actual: (len - 1) as Word ^ 0x10
+            })
+        );
+
+        // the head bytes up to the first word aligned address
+        let (result, start) = verify_mutated(1, 1, len, |bytes| bytes[0] ^= 0x10);
+        assert_eq!(
+            result,
+            Err(InitError::VerifyMismatch {
+                address: start,
+                expected: 1,
+                actual: 1 ^ 0x10
+            })
+        );
+
+        // the addresses never word aligned at the same time are compared byte by byte
+        let (result, start) = verify_mutated(0, 1, len, |bytes| bytes[WORD + 2] ^= 0x10);
+        assert_eq!(
+            result,
+            Err(InitError::VerifyMismatch {
+                address: start + WORD + 2,
+                expected: (WORD + 3) as Word,
+                actual: (WORD + 3) as Word ^ 0x10
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "verify")]
+    fn section_verify_records_flag() {
+        let flag = VerifiedFlag::new();
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+
+        unsafe { section_init(range.start, range.end, src.as_ptr()) };
+        unsafe { section_verify(range.start, range.end, src.as_ptr(), "data", Some(&flag)) };
+        assert!(flag.is_verified());
+
+        // the mismatch clears the flag before it is reported
+        dst[3] ^= 1;
+        let range = dst.as_mut_ptr_range();
+        let failed = fails(|| unsafe {
+            section_verify(range.start, range.end, src.as_ptr(), "data", Some(&flag))
+        });
+        assert!(failed);
+        assert!(!flag.is_verified());
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "crc32-verify"))]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(&[]), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "crc32-verify")]
+    fn section_crc32_round_trip() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+        unsafe { section_init(range.start, range.end, src.as_ptr()) };
+
+        // the CRC of the image the build computes matches the copied section
+        let image: std::vec::Vec<u8> = src.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let crc = crc32(&image);
+        assert!(!fails(|| unsafe {
+            section_crc32_verify(range.start, range.end, &crc, "data")
+        }));
+
+        // the persistent sections compute the same CRC
+        assert_eq!(unsafe { section_crc(range.start, range.end) }, crc);
+
+        // any bit flipped fails
+        dst[5] ^= 0x100;
+        let range = dst.as_ptr_range();
+        assert!(fails(|| unsafe {
+            section_crc32_verify(range.start, range.end, &crc, "data")
+        }));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "fletcher32-verify"))]
+    fn fletcher32_check_values() {
+        assert_eq!(fletcher32(b"abcde"), 0xF04F_C729);
+        assert_eq!(fletcher32(b"abcdef"), 0x5650_2D2A);
+        assert_eq!(fletcher32(b"abcdefgh"), 0xEBE1_9591);
+        assert_eq!(fletcher32(&[]), 0);
+
+        // the sums deferred over blocks equal those reduced by each halfword
+        let data: std::vec::Vec<u8> = (0..4 * FLETCHER32_BLOCK + 3).map(|_| 0xFF).collect();
+        let (sum1, sum2) = data.chunks(2).fold((0, 0), |(sum1, sum2), chunk| {
+            let halfword = u32::from(chunk[0]) | u32::from(*chunk.get(1).unwrap_or(&0)) << 8;
+            let sum1 = (sum1 + halfword) % 65535;
+            (sum1, (sum2 + sum1) % 65535)
+        });
+        assert_eq!(fletcher32(&data), sum2 << 16 | sum1);
+    }
+
+    #[test]
+    #[cfg(feature = "fletcher32-verify")]
+    fn section_fletcher32_round_trip() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+        unsafe { section_init(range.start, range.end, src.as_ptr()) };
+
+        // the Fletcher-32 of the image the build computes matches the copied section
+        let image: std::vec::Vec<u8> = src.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let fletcher = fletcher32(&image);
+        assert!(!fails(|| unsafe {
+            section_fletcher32_verify(range.start, range.end, &fletcher, "data")
+        }));
+
+        // the CRC32 of the same image is told apart
+        let crc = unsafe { section_crc(range.start, range.end) };
+        assert_ne!(crc, fletcher);
+        assert!(fails(|| unsafe {
+            section_fletcher32_verify(range.start, range.end, &crc, "data")
+        }));
+
+        // the unaligned section with an odd tail reads bytes
+        let bytes = dst.as_ptr().cast::<u8>().wrapping_add(1).cast::<Word>();
+        let end = range.end.cast::<u8>().wrapping_sub(2).cast::<Word>();
+        let fletcher = fletcher32(&image[1..image.len() - 2]);
+        assert!(!fails(|| unsafe {
+            section_fletcher32_verify(bytes, end, &fletcher, "data")
+        }));
+
+        // any halfword changed fails
+        dst[5] ^= 0x100;
+        let range = dst.as_ptr_range();
+        assert!(fails(|| unsafe {
+            section_fletcher32_verify(range.start, range.end, &fletcher32(&image), "data")
+        }));
+    }
+
+    /// Initializes the section of the first `N - 1` words of `dst` guarded by the canary in the
+    /// last one, returns whether it was kept.
+    fn canary_boot<const N: usize>(dst: &mut [Word; N], src: &[Word; N]) -> bool {
+        let range = dst.as_mut_ptr_range();
+        let cell = range.end.wrapping_sub(1);
+        let section = Section::new(range.start, cell, src.as_ptr());
+
+        unsafe {
+            section_canary(&section, cell, CANARY, || {
+                section_init(section.start(), section.end(), section.load())
+            })
+        }
+    }
+
+    #[test]
+    fn section_canary_boots() {
+        let (mut dst, src) = test_words::<9>();
+
+        // the cold boot copies the section and writes the canary past it, not copied over
+        dst[8] = 0;
+        assert!(!canary_boot(&mut dst, &src));
+        assert_eq!(dst[..8], src[..8]);
+        assert_eq!(dst[8], CANARY);
+
+        // the warm boot keeps the content, even if changed since
+        dst[3] = 0x5A;
+        assert!(canary_boot(&mut dst, &src));
+        assert_eq!(dst[3], 0x5A);
+        assert_eq!(dst[8], CANARY);
+
+        // the corrupted canary copies the section again
+        dst[8] ^= 0x100;
+        assert!(!canary_boot(&mut dst, &src));
+        assert_eq!(dst[..8], src[..8]);
+        assert_eq!(dst[8], CANARY);
+
+        // the cleared canary as well
+        dst[3] = 0x5A;
+        unsafe { canary_clear(&mut dst[8]) };
+        assert!(!canary_boot(&mut dst, &src));
+        assert_eq!(dst[3], src[3]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", assert_overlap))]
+    fn failure_handler_canary_in_section() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, src.as_ptr());
+
+        // the last word of the section would be overwritten by its copy
+        let cell = range.end.wrapping_sub(1);
+        let error = init_failure(|| unsafe {
+            section_canary(&section, cell, CANARY, || {});
+        });
+        assert_eq!(
+            error,
+            Some(InitError::CanaryInSection {
+                cell: cell.addr(),
+                start: range.start.addr(),
+                end: range.end.addr()
+            })
+        );
+
+        // the words right around the section are fine
+        for cell in [range.start.wrapping_sub(1), range.end] {
+            assert!(!fails(|| unsafe {
+                section_canary(&section, cell, CANARY, || {});
+            }));
+        }
+    }
+
+    #[test]
+    #[cfg(double_init_guard)]
+    fn init_guard_records_sections() {
+        let guard = InitGuard::new();
+
+        // a single init passes, the same section initialized again does not
+        assert!(guard.enter(0x2000_0000));
+        assert!(!guard.enter(0x2000_0000));
+
+        // the sections initialized in turns are recorded each
+        assert!(guard.enter(0x2000_0400));
+        assert!(guard.enter(0x2000_0800));
+        assert!(!guard.enter(0x2000_0400));
+        assert!(!guard.enter(0x2000_0800));
+        assert!(!guard.enter(0x2000_0000));
+
+        // the reset forgets them, as done on every boot
+        guard.reset();
+        assert!(guard.enter(0x2000_0400));
+
+        // the sections past the record are not checked
+        for slot in 1..INIT_GUARD_SLOTS {
+            assert!(guard.enter(0x3000_0000 + slot * 4));
+        }
+        assert!(guard.enter(0x2000_0800));
+        assert!(guard.enter(0x2000_0800));
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", double_init_guard))]
+    fn failure_handler_double_init() {
+        // statics, so no other test reuses the addresses recorded by the global guard
+        static SECTION_A: [Word; 2] = [0; 2];
+        static SECTION_B: [Word; 2] = [0; 2];
+        let (a, b) = (SECTION_A.as_ptr_range(), SECTION_B.as_ptr_range());
+        let (a, b) = (a.start.addr()..a.end.addr(), b.start.addr()..b.end.addr());
+
+        section_init_guard("section_a", a.start, a.end);
+        section_init_guard("section_b", b.start, b.end);
+
+        let error = init_failure(|| section_init_guard("section_a", a.start, a.end));
+        assert_eq!(error, Some(InitError::DoubleInit(a.start)));
+        let error = init_failure(|| section_init_guard("section_b", b.start, b.end));
+        assert_eq!(error, Some(InitError::DoubleInit(b.start)));
+
+        // the empty sections, e.g. the missing optional ones, are not recorded
+        assert!(!fails(|| {
+            section_init_guard("empty", b.end, b.end);
+            section_init_guard("empty", b.end, b.end);
+        }));
+    }
+
+    #[test]
+    fn section_init_strided_two() {
+        let mut dst = [0xA5A5u16; 10];
+        let src = [0x0100u16, 0x0302, 0x0504, 0x0706, 0x0908];
+        let range = dst.as_mut_ptr_range();
+
+        unsafe {
+            section_init_u16_strided(range.start.cast(), range.end.cast(), src.as_ptr().cast(), 2)
+        };
+
+        assert_eq!(
+            dst,
+            [0x0100, 0xA5A5, 0x0302, 0xA5A5, 0x0504, 0xA5A5, 0x0706, 0xA5A5, 0x0908, 0xA5A5]
+        );
+    }
+
+    #[test]
+    fn section_init_strided_partial_slot() {
+        let mut dst = [0xA5A5u16; 7];
+        let src = [1u16, 2, 3];
+        let range = dst.as_mut_ptr_range();
+
+        // the last halfword fits into the section even without the rest of its slot
+        unsafe {
+            section_init_u16_strided(range.start.cast(), range.end.cast(), src.as_ptr().cast(), 3)
+        };
+
+        assert_eq!(dst, [1, 0xA5A5, 0xA5A5, 2, 0xA5A5, 0xA5A5, 3]);
+    }
+
+    #[test]
+    #[cfg(assert_bounds)]
+    #[should_panic]
+    fn section_init_strided_overflow() {
+        let mut dst = [0u16; 6];
+        let src = [0u16; 4];
+        let range = dst.as_mut_ptr_range();
+
+        unsafe {
+            section_init_strided(
+                range.start.cast(),
+                range.end.cast(),
+                src.as_ptr().cast(),
+                4,
+                2,
+            )
+        };
+    }
+
+    #[test]
+    #[cfg(feature = "strict-alignment")]
+    #[should_panic]
+    fn section_init_unaligned_strict() {
+        let mut dst = [0; 2];
+        let src = [0; 2];
+        let start = dst.as_mut_ptr().cast::<u8>().wrapping_add(2);
+
+        let section = Section::new(start.cast(), dst.as_ptr_range().end, src.as_ptr());
+        unsafe { section.init() };
+    }
+
+    #[test]
+    fn section_reinit_restores_load_data() {
+        let mut dst = [0; 4];
+        let src = [5, 6, 7, 8];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+        unsafe { section.init() };
+        unsafe { range.start.write(0) };
+        let restored = unsafe { section.reinit() };
+
+        assert_eq!(restored, 4 * WORD);
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn section_is_copy() {
+        let mut dst = [0; 2];
+        let src = [3, 4];
+        let range = dst.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.end, src.as_ptr());
+        let copy = section;
+        unsafe { copy.init() };
+
+        assert_eq!(section, copy);
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn once_guard_double_acquire() {
+        let guard = OnceGuard::new();
+
+        assert!(guard.acquire());
+        assert!(!guard.acquire());
+        assert!(!guard.acquire());
+    }
+
+    #[test]
+    fn once_guard_outside_section() {
+        #[unsafe(link_section = ".uninit.linker_sections_once")]
+        static GUARD: OnceGuard = OnceGuard::new();
+
+        let mut dst = [0; 4];
+        let src = [1; 4];
+        let range = dst.as_mut_ptr_range();
+        let section = Section::new(range.start, range.end, src.as_ptr());
+
+        let guard = core::ptr::addr_of!(GUARD) as usize;
+        assert!(guard < range.start as usize || guard >= range.end as usize);
+
+        assert!(GUARD.acquire());
+        unsafe { section.init() };
+        assert!(!GUARD.acquire());
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn deferred_init_runs_all_sections() {
+        let mut dst_a = [0; 2];
+        let mut dst_b = [0; 3];
+        let src_a = [1, 2];
+        let src_b = [3, 4, 5];
+        let range_a = dst_a.as_mut_ptr_range();
+        let range_b = dst_b.as_mut_ptr_range();
+
+        let deferred = DeferredInit::new([
+            Section::new(range_a.start, range_a.end, src_a.as_ptr()),
+            Section::new(range_b.start, range_b.end, src_b.as_ptr()),
+        ]);
+        unsafe { deferred.run() };
+
+        assert_eq!(dst_a, src_a);
+        assert_eq!(dst_b, src_b);
+    }
+
+    #[test]
+    fn deferred_init_does_not_touch_memory() {
+        let mut dst = [0; 2];
+        let src = [1, 2];
+        let range = dst.as_mut_ptr_range();
+
+        let deferred = DeferredInit::new([Section::new(range.start, range.end, src.as_ptr())]);
+        assert_eq!(dst, [0; 2]);
+
+        unsafe { deferred.run() };
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "deferred sections dropped without being initialized")]
+    fn deferred_init_dropped() {
+        let _ = DeferredInit::new([Section::new(
+            core::ptr::null_mut(),
+            core::ptr::null(),
+            core::ptr::null(),
+        )]);
+    }
+
+    #[test]
+    fn optional_section_missing() {
+        let section = section!(optional linker_sections_missing);
+
+        assert!(section.is_empty());
+        assert_eq!(section.start(), core::ptr::null_mut());
+
+        init_sections!(optional linker_sections_missing);
+        init_sections!(@inline_always; optional linker_sections_missing);
+    }
+
+    #[test]
+    fn section_table() {
+        let mut dst_a = [0; 2];
+        let mut dst_b = [0; 3];
+        let src_a = [1, 2];
+        let src_b = [3, 4, 5];
+        let range_a = dst_a.as_mut_ptr_range();
+        let range_b = dst_b.as_mut_ptr_range();
+
+        let table = [
+            SectionInitEntry {
+                start: range_a.start,
+                end: range_a.end,
+                load: src_a.as_ptr(),
+            },
+            SectionInitEntry {
+                start: range_b.start,
+                end: range_b.end,
+                load: src_b.as_ptr(),
+            },
+        ];
+
+        let table = table.as_ptr_range();
+        unsafe { section_table_init(table.start, table.end) };
+
+        assert_eq!(dst_a, src_a);
+        assert_eq!(dst_b, src_b);
+    }
+
+    /// Aligns CMSIS test regions to [`Word`] as the linker aligns the sections on the target.
+    #[repr(C, align(8))]
+    struct Aligned<T>(T);
+
+    #[test]
+    fn cmsis_tables_words() {
+        let mut data = Aligned([0u32; 4]);
+        let mut bss = [0xFFFF_FFFFu32; 3];
+        let load = Aligned([1u32, 2, 3, 4]);
+
+        let copy_table = [CopyTableEntry {
+            src: load.0.as_ptr(),
+            dst: data.0.as_mut_ptr(),
+            len: 4,
+        }];
+        let zero_table = [ZeroTableEntry {
+            dst: bss.as_mut_ptr(),
+            len: 3,
+        }];
+
+        let copy = copy_table.as_ptr_range();
+        let zero = zero_table.as_ptr_range();
+        unsafe {
+            copy_table_init(copy.start, copy.end, LengthUnit::Words);
+            zero_table_init(zero.start, zero.end, LengthUnit::Words);
+        }
+
+        assert_eq!(data.0, load.0);
+        assert_eq!(bss, [0; 3]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-length"))]
+    fn cmsis_tables_bytes() {
+        let mut data_a = Aligned([0u32; 2]);
+        let mut data_b = Aligned([0u32; 2]);
+        let mut bss = [0xFFFF_FFFFu32; 2];
+        let load_a = Aligned([1u32, 2]);
+        let load_b = Aligned([3u32, 4]);
+
+        let copy_table = [
+            CopyTableEntry {
+                src: load_a.0.as_ptr(),
+                dst: data_a.0.as_mut_ptr(),
+                len: 8,
+            },
+            CopyTableEntry {
+                src: load_b.0.as_ptr(),
+                dst: data_b.0.as_mut_ptr(),
+                len: 4,
+            },
+        ];
+        let zero_table = [ZeroTableEntry {
+            dst: bss.as_mut_ptr(),
+            len: 4,
+        }];
+
+        let copy = copy_table.as_ptr_range();
+        let zero = zero_table.as_ptr_range();
+        unsafe {
+            copy_table_init(copy.start, copy.end, LengthUnit::Bytes);
+            zero_table_init(zero.start, zero.end, LengthUnit::Bytes);
+        }
+
+        assert_eq!(data_a.0, load_a.0);
+        assert_eq!(data_b.0, [3, 0]);
+        assert_eq!(bss, [0, 0xFFFF_FFFF]);
+    }
+
+    #[test]
+    fn cmsis_tables_empty() {
+        let copy_table: [CopyTableEntry; 0] = [];
+        let zero_table: [ZeroTableEntry; 0] = [];
+
+        let copy = copy_table.as_ptr_range();
+        let zero = zero_table.as_ptr_range();
+        unsafe {
+            copy_table_init(copy.start, copy.end, LengthUnit::Words);
+            zero_table_init(zero.start, zero.end, LengthUnit::Words);
+        }
+    }
+
+    #[test]
+    fn section_in_static() {
+        static SECTION: Section =
+            Section::new(core::ptr::null_mut(), core::ptr::null(), core::ptr::null());
+
+        assert_eq!(SECTION.start(), core::ptr::null_mut());
+        assert_eq!(SECTION.len_bytes(), 0);
+    }
+
+    #[test]
+    fn take_guard_once() {
+        let guard = TakeGuard::new();
+
+        assert!(guard.take());
+        assert!(!guard.take());
+    }
+
+    #[test]
+    fn noinit_check_fits() {
+        let memory = [0u32; 4];
+        let range = memory.as_ptr_range();
+
+        noinit_check::<[u32; 4]>(range.start.cast(), range.end.cast());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "does not fit")]
+    fn noinit_check_too_small() {
+        let memory = [0u32; 4];
+        let range = memory.as_ptr_range();
+
+        noinit_check::<[u32; 5]>(range.start.cast(), range.end.cast());
+    }
+
+    /// Returns persistent section over `memory`, the last word being the CRC trailer.
+    fn persistent(memory: &mut [Word], load: &[Word]) -> PersistentSection {
+        let flag = std::boxed::Box::leak(std::boxed::Box::new(PersistentFlag::new()));
+
+        let range = memory.as_mut_ptr_range();
+        PersistentSection::new(Section::new(range.start, range.end, load.as_ptr()), flag)
+    }
+
+    #[test]
+    fn section_crc_matches_crc32() {
+        // CRC32 of ASCII "1234" followed by "5678"
+        let mut memory: [Word; 8 / WORD] = core::array::from_fn(|i| {
+            Word::from_le_bytes(core::array::from_fn(|j| b"12345678"[i * WORD + j]))
+        });
+        let range = memory.as_mut_ptr_range();
+
+        assert_eq!(unsafe { section_crc(range.start, range.end) }, 0x9AE0_DAAF);
+    }
+
+    #[test]
+    fn persistent_section_first_boot() {
+        let mut memory = [0x1234_5678, 0x9ABC_DEF0, 0xFFFF_FFFF];
+        let load = [1, 2, 0];
+
+        let section = persistent(&mut memory, &load);
+        let loaded = unsafe { section.init() };
+
+        assert!(loaded);
+        assert!(section.defaults_loaded());
+        assert!(unsafe { section.is_valid() });
+        assert_eq!(memory[..2], [1, 2]);
+    }
+
+    #[test]
+    fn persistent_section_valid() {
+        let mut memory = [0; 3];
+        let load = [1, 2, 0];
+
+        let section = persistent(&mut memory, &load);
+        unsafe { section.init() };
+        memory[0] = 7;
+        let section = persistent(&mut memory, &load);
+        unsafe { section.update_crc() };
+        let loaded = unsafe { section.init() };
+
+        assert!(!loaded);
+        assert!(!section.defaults_loaded());
+        assert_eq!(memory[..2], [7, 2]);
+    }
+
+    #[test]
+    fn persistent_section_corrupt() {
+        let mut memory = [0; 3];
+        let load = [1, 2, 0];
+
+        let section = persistent(&mut memory, &load);
+        unsafe { section.init() };
+        memory[1] = 9;
+        let section = persistent(&mut memory, &load);
+        let loaded = unsafe { section.init() };
+
+        assert!(loaded);
+        assert_eq!(memory[..2], [1, 2]);
+    }
+
+    #[test]
+    fn section_name_validity() {
+        assert!(section_name_valid(".custom_data"));
+        assert!(section_name_valid(".custom_data.buffers"));
+        assert!(!section_name_valid("custom_data"));
+        assert!(!section_name_valid("."));
+        assert!(!section_name_valid(".custom data"));
+    }
+
+    #[test]
+    fn heap_region_bounds() {
+        let memory = [0u32; 8];
+        let range = memory.as_ptr_range();
+
+        assert_eq!(
+            heap_region(range.start, range.end),
+            (range.start as usize, 32)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "empty")]
+    fn heap_region_empty() {
+        let memory = [0u32; 0];
+        let range = memory.as_ptr_range();
+
+        heap_region(range.start, range.end);
+    }
+
+    #[test]
+    #[should_panic(expected = "aligned")]
+    fn heap_region_misaligned() {
+        let memory = [0u32; 8];
+        let range = memory.as_ptr_range();
+
+        heap_region(range.start, range.end.cast::<u8>().wrapping_sub(2).cast());
+    }
+
+    #[test]
+    fn stack_paint_stops_below_stack_pointer() {
+        let mut stack = [0u32; 32];
+        let range = stack.as_mut_ptr_range();
+        let sp = range.start.wrapping_add(24);
+
+        let painted = unsafe { stack_paint(range.start, range.end, sp, 0xCCCC_CCCC) };
+
+        assert_eq!(painted, 32);
+        assert_eq!(stack[..8], [0xCCCC_CCCC; 8]);
+        assert_eq!(stack[8..], [0; 24]);
+    }
+
+    #[test]
+    fn stack_paint_limited_by_top() {
+        let mut stack = [0u32; 8];
+        let range = stack.as_mut_ptr_range();
+        let sp = range.end.wrapping_add(32);
+
+        let painted = unsafe { stack_paint(range.start, range.end, sp, 0xCCCC_CCCC) };
+
+        assert_eq!(painted, 32);
+        assert_eq!(stack, [0xCCCC_CCCC; 8]);
+    }
+
+    #[test]
+    fn stack_watermark_partial() {
+        let stack = [0xCCCC_CCCC, 0xCCCC_CCCC, 0xCCCC_CCCC, 7, 0xCCCC_CCCC, 1];
+        let range = stack.as_ptr_range();
+
+        assert_eq!(
+            unsafe { stack_watermark(range.start, range.end, 0xCCCC_CCCC) },
+            12
+        );
+    }
+
+    #[test]
+    fn stack_watermark_untouched_and_full() {
+        let untouched = [0xCCCC_CCCC; 4];
+        let full = [0u32; 4];
+
+        let range = untouched.as_ptr_range();
+        assert_eq!(
+            unsafe { stack_watermark(range.start, range.end, 0xCCCC_CCCC) },
+            0
+        );
+
+        let range = full.as_ptr_range();
+        assert_eq!(
+            unsafe { stack_watermark(range.start, range.end, 0xCCCC_CCCC) },
+            16
+        );
+    }
+
+    #[test]
+    fn dcache_lines_rounded() {
+        assert_eq!(dcache_lines(0x2000_0040, 0x2000_0080), (0x2000_0040, 0x40));
+        assert_eq!(dcache_lines(0x2000_0044, 0x2000_0081), (0x2000_0040, 0x60));
+        assert_eq!(dcache_lines(0x2000_005C, 0x2000_0060), (0x2000_0040, 0x20));
+        assert_eq!(dcache_lines(0x2000_0040, 0x2000_0040), (0x2000_0040, 0));
+    }
+
+    #[test]
+    fn section_zero_tail_after_copy() {
+        let mut region = [Word::MAX; 8];
+        let src = [1, 2, 3];
+        let range = region.as_mut_ptr_range();
+
+        let section = Section::new(range.start, range.start.wrapping_add(3), src.as_ptr());
+        unsafe {
+            section.init();
+            section_zero_tail(section.end(), range.end);
+        }
+
+        assert_eq!(region, [1, 2, 3, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[cfg(assert_order)]
+    #[should_panic]
+    fn section_zero_tail_preceding_section() {
+        let region = [0; 8];
+        let range = region.as_ptr_range();
+
+        unsafe { section_zero_tail(range.end, range.start) };
+    }
+
+    #[test]
+    fn checks_follow_features() {
+        // with `asserts-debug-only` feature, all the checks compile in the debug builds only
+        let debug_only = cfg!(all(feature = "asserts-debug-only", debug_assertions));
+
+        assert_eq!(
+            cfg!(assert_align),
+            cfg!(feature = "assert-align") || debug_only
+        );
+        assert_eq!(
+            cfg!(assert_bounds),
+            cfg!(feature = "assert-bounds") || debug_only
+        );
+        assert_eq!(
+            cfg!(assert_order),
+            cfg!(feature = "assert-order") || debug_only
+        );
+        assert_eq!(
+            cfg!(assert_overlap),
+            cfg!(feature = "assert-overlap") || debug_only
+        );
+        assert_eq!(
+            cfg!(asserts),
+            cfg!(any(
+                assert_align,
+                assert_bounds,
+                assert_order,
+                assert_overlap
+            ))
+        );
+    }
+
+    /// Returns whether `init` fails a check.
+    fn fails(init: impl FnOnce()) -> bool {
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(init)).is_err()
+    }
+
+    #[test]
+    fn check_align_selected() {
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+        let end = range.end.cast::<u8>().wrapping_sub(1).cast::<Word>();
+
+        let failed = fails(|| {
+            unsafe { section_len(range.start, end) };
+        });
+        assert_eq!(failed, cfg!(assert_align));
+    }
+
+    #[test]
+    fn check_bounds_selected() {
+        let mut dst = [0; 6];
+        let (head, tail) = ([1, 2], [3, 4, 5]);
+        let range = dst.as_mut_ptr_range();
+
+        // the fragments are one word short of the section
+        let failed = fails(|| unsafe {
+            section_init_fragments(range.start, range.end, &[fragment(&head), fragment(&tail)])
+        });
+        assert_eq!(failed, cfg!(assert_bounds));
+    }
+
+    #[test]
+    fn check_order_selected() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+
+        let failed = fails(|| unsafe { section_init(range.end, range.start, src.as_ptr()) });
+        assert_eq!(failed, cfg!(assert_order));
+        assert_eq!(
+            section_check(range.end, range.start, src.as_ptr(), Checks::ENABLED).is_err(),
+            cfg!(assert_order)
+        );
+    }
+
+    #[test]
+    fn check_overlap_selected() {
+        let (_, memory) = test_words::<8>();
+        let range = memory.as_ptr_range();
+
+        // checked only, the overlapping copy is not done
+        let result = section_check(
+            range.start,
+            range.end,
+            range.start.wrapping_add(1),
+            Checks::ENABLED,
+        );
+        assert_eq!(
+            result.is_err(),
+            cfg!(all(assert_overlap, not(feature = "overlap-ok")))
+        );
+    }
+
+    #[test]
+    #[cfg(not(assert_order))]
+    fn section_reversed_untouched() {
+        let (mut dst, src) = test_words::<8>();
+        let expected = dst;
+        let range = dst.as_mut_ptr_range();
+
+        // the end symbol resolved below the start symbol, e.g. by a linker script bug
+        let (start, end) = (range.end, range.start.cast_const());
+        let section = Section::new(start, end, src.as_ptr());
+        assert_eq!(section.len_bytes(), 0);
+
+        unsafe {
+            section_init(start, end, src.as_ptr());
+            section_init_in_place(section);
+            section_init_u16_strided(start, end, src.as_ptr(), 2);
+            section_fill(start, end, 0);
+        }
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    #[cfg(assert_order)]
+    #[should_panic(expected = "precedes its start")]
+    fn section_reversed_reported() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+
+        unsafe { section_init(range.end, range.start, src.as_ptr()) };
+    }
+
+    #[test]
+    fn checked_section_init_copies() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+
+        let result = unsafe { checked_section_init(range.start, range.end, src.as_ptr()) };
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(dst, src);
+    }
+
+    /// Runs [`checked_section_init`] over the words `dst..end` of 8-word memory holding the load
+    /// data at `src`, returns the result and whether the memory is left untouched.
+    fn checked_init(dst: usize, end: usize, src: usize) -> (Result<(), InitError>, bool) {
+        let (_, mut memory) = test_words::<8>();
+        let expected = memory;
+        let base = memory.as_mut_ptr();
+
+        let (dst, end, src) = (
+            base.wrapping_add(dst),
+            base.wrapping_add(end),
+            base.wrapping_add(src),
+        );
+        let result = unsafe { checked_section_init(dst, end, src) };
+
+        (result, memory == expected)
+    }
+
+    #[test]
+    fn checked_section_init_reversed() {
+        let (result, untouched) = checked_init(4, 0, 4);
+
+        let Err(InitError::Reversed { start, end }) = result else {
+            panic!("{result:?}");
+        };
+        assert_eq!(start - end, 4 * WORD);
+        assert!(untouched);
+        assert_eq!(
+            std::format!(
+                "{}",
+                InitError::Reversed {
+                    start: 0x20,
+                    end: 0x10
+                }
+            ),
+            "section end 0x10 precedes its start 0x20"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "overlap-ok"))]
+    fn checked_section_init_overlap() {
+        for (dst, src) in [(0, 3), (3, 0)] {
+            let (result, untouched) = checked_init(dst, dst + 4, src);
+
+            let Err(InitError::Overlap {
+                dst: at,
+                src: from,
+                len,
+            }) = result
+            else {
+                panic!("{dst} <- {src}: {result:?}");
+            };
+            assert_eq!(
+                (from as isize - at as isize) / WORD as isize,
+                src as isize - dst as isize
+            );
+            assert_eq!(len, 4 * WORD);
+            assert!(untouched);
+        }
+
+        // adjacent and aliased load data are fine
+        assert_eq!(checked_init(0, 4, 4).0, Ok(()));
+        assert_eq!(checked_init(0, 4, 0).0, Ok(()));
+    }
+
+    #[test]
+    fn checked_section_init_alignment_mismatch() {
+        for (dst_offset, src_offset) in [(1, 1), (WORD - 1, WORD - 1), (0, 2), (3, 1)] {
+            let (mut dst, src) = test_words::<8>();
+            let range = dst.as_mut_ptr_range();
+            let (start, load) = (
+                range.start.wrapping_byte_add(dst_offset),
+                src.as_ptr().wrapping_byte_add(src_offset),
+            );
+            let (end, len) = (start.wrapping_byte_add(6 * WORD), 6 * WORD);
+            let expected = dst;
+
+            let result = unsafe { checked_section_init(start, end, load) };
+            if cfg!(feature = "strict-alignment") {
+                // checked to be word aligned before
+                assert!(result.is_err());
+            } else if dst_offset == src_offset {
+                // matched but unaligned, the head bytes are copied one by one
+                assert_eq!(result, Ok(()));
+                assert_eq!(
+                    word_bytes(&dst)[dst_offset..][..len],
+                    word_bytes(&src)[src_offset..][..len]
+                );
+            } else {
+                assert_eq!(
+                    result,
+                    Err(InitError::AlignmentMismatch {
+                        src: load.addr(),
+                        dst: start.addr()
+                    })
+                );
+                assert_eq!(dst, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn checked_section_init_unaligned_length() {
+        // the exact multiple first, then the remainders of 1 up to 3 bytes on 32-bit targets
+        for remainder in 0..WORD {
+            let (mut dst, src) = test_words::<8>();
+            let start = dst.as_mut_ptr();
+            let end = start.wrapping_byte_add(4 * WORD + remainder);
+            let expected = dst;
+
+            let result = unsafe { checked_section_init(start, end, src.as_ptr()) };
+            if cfg!(feature = "strict-length") && remainder != 0 {
+                assert_eq!(
+                    result,
+                    Err(InitError::UnalignedLength {
+                        start: start.addr(),
+                        remainder
+                    })
+                );
+                assert_eq!(dst, expected);
+            } else {
+                // the tail bytes are copied one by one
+                assert_eq!(result, Ok(()));
+                let len = 4 * WORD + remainder;
+                assert_eq!(word_bytes(&dst)[..len], word_bytes(&src)[..len]);
+                assert_eq!(word_bytes(&dst)[len..], word_bytes(&expected)[len..]);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "strict-length")]
+    #[should_panic(expected = "ends 3 bytes past its last whole word")]
+    fn section_init_named_unaligned_length() {
+        let (mut dst, src) = test_words::<8>();
+        let start = dst.as_mut_ptr();
+        let end = start.wrapping_byte_add(4 * WORD + 3);
+
+        unsafe { section_init_named(start, end, src.as_ptr(), "data") };
+    }
+
+    #[test]
+    #[cfg(feature = "strict-alignment")]
+    fn checked_section_init_misaligned() {
+        let (mut dst, src) = test_words::<8>();
+        let range = dst.as_mut_ptr_range();
+        let (start, load) = (
+            range.start.wrapping_byte_add(1),
+            src.as_ptr().wrapping_byte_add(1),
+        );
+
+        let result = unsafe { checked_section_init(start, range.end, src.as_ptr()) };
+        assert_eq!(result, Err(InitError::MisalignedDst(start.addr())));
+
+        let result = unsafe { checked_section_init(range.start, range.end, load) };
+        assert_eq!(result, Err(InitError::MisalignedSrc(load.addr())));
+
+        // the end need not be aligned, the tail bytes are copied one by one
+        let end = range.end.wrapping_byte_sub(1);
+        let result = unsafe { checked_section_init(range.start, end, src.as_ptr()) };
+        assert_eq!(result.is_ok(), cfg!(not(feature = "strict-length")));
+    }
+
+    #[test]
+    fn init_error_cell_keeps_first() {
+        let cell = InitErrorCell::new();
+        assert_eq!(cell.get(), None);
+
+        cell.record(InitError::Reversed { start: 8, end: 4 });
+        cell.record(InitError::MisalignedSrc(1));
+        assert_eq!(cell.get(), Some(InitError::Reversed { start: 8, end: 4 }));
+
+        cell.clear();
+        assert_eq!(cell.get(), None);
+    }
+
+    #[test]
+    fn init_error_code_and_address() {
+        let errors = [
+            InitError::MisalignedDst(0x11),
+            InitError::MisalignedEnd(0x12),
+            InitError::MisalignedSrc(0x13),
+            InitError::Reversed {
+                start: 0x20,
+                end: 0x10,
+            },
+            InitError::Overlap {
+                dst: 0x30,
+                src: 0x34,
+                len: 8,
+            },
+            InitError::LengthMismatch {
+                expected: 8,
+                actual: 12,
+            },
+            InitError::OutOfRange {
+                address: 0x40,
+                end: 0x40,
+            },
+            InitError::OutOfFlashBounds(0x50),
+            InitError::OutOfRamBounds {
+                start: 0x58,
+                address: 0x60,
+            },
+        ];
+
+        let codes = errors.map(|error| error.code());
+        let addresses = errors.map(|error| error.address());
+        assert_eq!(codes, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(
+            addresses,
+            [0x11, 0x12, 0x13, 0x20, 0x30, 0, 0x40, 0x50, 0x60]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "semihosting-errors")]
+    fn failure_message_names_section() {
+        let error = InitError::Reversed {
+            start: 0x20,
+            end: 0x10,
+        };
+        let mut buffer = [0; FAILURE_MESSAGE_LEN];
+
+        let len = failure_message(&mut buffer, Some("sram2"), &error);
+        assert_eq!(
+            core::str::from_utf8(&buffer[..len]),
+            Ok("linker-sections: section `sram2` failed: section end 0x10 precedes its start 0x20\n")
+        );
+
+        let len = failure_message(&mut buffer, None, &error);
+        assert!(buffer[..len].starts_with(b"linker-sections: section failed: section end"));
+
+        // the remainder past the last word of a section missing its trailing `ALIGN`
+        let error = InitError::UnalignedLength {
+            start: 0x20,
+            remainder: 2,
+        };
+        let len = failure_message(&mut buffer, Some("sram2"), &error);
+        assert_eq!(
+            core::str::from_utf8(&buffer[..len]),
+            Ok("linker-sections: section `sram2` failed: section 0x20 ends 2 bytes past its last whole word\n")
+        );
+
+        // truncated, the nul terminator kept
+        let mut buffer = [0; 24];
+        let len = failure_message(&mut buffer, Some("sram2"), &error);
+        assert_eq!(len, 23);
+        assert_eq!(&buffer[..len], b"linker-sections: sectio");
+        assert_eq!(buffer[23], 0);
+    }
+
+    #[cfg(feature = "failure-handler")]
+    std::thread_local! {
+        static FAILURE: core::cell::Cell<Option<InitError>> = const { core::cell::Cell::new(None) };
+    }
+
+    /// Failure handler of the tests, records `error` and panics the same as the default does.
+    #[cfg(feature = "failure-handler")]
+    fn record_init_error(error: &InitError) -> ! {
+        FAILURE.set(Some(*error));
+        panic!("{error}");
+    }
+
+    /// Runs `init` expected to fail, returns the error received by the failure handler.
+    #[cfg(all(
+        feature = "failure-handler",
+        any(
+            assert_order,
+            assert_bounds,
+            assert_overlap,
+            feature = "memory-bounds",
+            feature = "verify",
+            feature = "crc32-verify",
+            feature = "fletcher32-verify",
+            double_init_guard
+        )
+    ))]
+    fn init_failure(init: impl FnOnce()) -> Option<InitError> {
+        FAILURE.set(None);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(init));
+
+        assert!(result.is_err());
+        FAILURE.take()
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", assert_order))]
+    fn failure_handler_reversed() {
+        let (_, mut memory) = test_words::<8>();
+        let range = memory.as_mut_ptr_range();
+        let (start, end) = (range.start.wrapping_add(4), range.start);
+
+        let error = init_failure(|| unsafe { section_init(start, end, start) });
+        assert_eq!(
+            error,
+            Some(InitError::Reversed {
+                start: start.addr(),
+                end: end.addr()
+            })
+        );
+
+        let error = init_failure(|| unsafe { zero_words(start.cast(), end.cast()) });
+        assert!(matches!(error, Some(InitError::Reversed { .. })));
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", feature = "verify"))]
+    fn failure_handler_verify_mismatch() {
+        let (mut dst, src) = test_words::<8>();
+        dst[..5].copy_from_slice(&src[..5]);
+        let range = dst.as_ptr_range();
+
+        // the word not copied is reported with both values
+        let error = init_failure(|| unsafe {
+            section_verify(range.start, range.end, src.as_ptr(), "data", None)
+        });
+        assert_eq!(
+            error,
+            Some(InitError::VerifyMismatch {
+                address: range.start.wrapping_add(5).addr(),
+                expected: src[5],
+                actual: dst[5]
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", feature = "crc32-verify"))]
+    fn failure_handler_crc_mismatch() {
+        let (dst, _) = test_words::<8>();
+        let range = dst.as_ptr_range();
+
+        // the bytes past the last whole word are covered as well
+        let end = range.end.cast::<u8>().wrapping_sub(3).cast::<Word>();
+        let error = init_failure(|| unsafe {
+            section_crc32_verify(range.start, end, &0x1234_5678, "data")
+        });
+        let Some(InitError::CrcMismatch {
+            start,
+            expected,
+            actual,
+        }) = error
+        else {
+            panic!("{error:?}");
+        };
+        assert_eq!((start, expected), (range.start.addr(), 0x1234_5678));
+        let bytes = dst.as_ptr().cast::<u8>();
+        let len = size_of_val(&dst) - 3;
+        let crc = (0..len).fold(!0, |crc, index| {
+            crc32_update(crc, unsafe { bytes.add(index).read() })
+        });
+        assert_eq!(actual, !crc, "{actual:#x}");
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", feature = "fletcher32-verify"))]
+    fn failure_handler_fletcher_mismatch() {
+        let (dst, _) = test_words::<4>();
+        let range = dst.as_ptr_range();
+
+        let error = init_failure(|| unsafe {
+            section_fletcher32_verify(range.start, range.end, &0, "data")
+        });
+        assert!(matches!(
+            error,
+            Some(InitError::FletcherMismatch { start, expected: 0, actual })
+                if start == range.start.addr() && actual != 0
+        ));
+        assert_eq!(error.map(|error| error.code()), Some(14));
+    }
+
+    #[test]
+    #[cfg(feature = "crc32-verify")]
+    fn image_verify_corrupted() {
+        let mut image: std::vec::Vec<u8> = (0..1000).map(|index| (index * 7 % 251) as u8).collect();
+        let crc = crc32(&image);
+        let verify = |image: &[u8]| {
+            let range = image.as_ptr_range();
+            fails(|| unsafe { image_verify(range.start, range.end, &crc) })
+        };
+        assert!(!verify(&image));
+
+        // a single bit flipped
+        image[517] ^= 0x04;
+        assert!(verify(&image));
+        image[517] ^= 0x04;
+
+        // programming stopped short, the rest reads erased
+        let mut partial = image.clone();
+        partial[768..].fill(0xFF);
+        assert!(verify(&partial));
+
+        // the image bounds given short
+        assert!(verify(&image[..996]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", feature = "crc32-verify"))]
+    fn failure_handler_image_crc_mismatch() {
+        let image = [0xFFu8; 64];
+        let range = image.as_ptr_range();
+
+        // reported with no section, before any is initialized
+        let error = init_failure(|| unsafe { image_verify(range.start, range.end, &0) });
+        let Some(
+            error @ InitError::ImageCrcMismatch {
+                start,
+                end,
+                expected: 0,
+                ..
+            },
+        ) = error
+        else {
+            panic!("{error:?}");
+        };
+        assert_eq!((start, end), (range.start.addr(), range.end.addr()));
+        assert_eq!((error.code(), error.address()), (15, start));
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", assert_bounds))]
+    fn failure_handler_out_of_range() {
+        let (mut dst, src) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+
+        // the halfwords spaced by 2 fill the section, one more is written at its end
+        let halfwords = 4 * WORD / 4 + 1;
+        let error = init_failure(|| unsafe {
+            section_init_strided(range.start, range.end, src.as_ptr(), halfwords, 2)
+        });
+        assert_eq!(
+            error,
+            Some(InitError::OutOfRange {
+                address: range.end.addr(),
+                end: range.end.addr()
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", feature = "sparse-init", assert_bounds))]
+    fn failure_handler_sparse_out_of_range() {
+        let (mut dst, _) = test_words::<4>();
+        let range = dst.as_mut_ptr_range();
+        let words = 4 * WORD / 4;
+
+        // the word at the section end
+        let table = sparse_table(&[0, 0x5A, words as u32, 0xA5, sparse::END, 0]);
+        let error =
+            init_failure(|| unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) });
+        assert_eq!(
+            error,
+            Some(InitError::OutOfRange {
+                address: range.end.addr(),
+                end: range.end.addr()
+            })
+        );
+
+        // the raw image one word shorter than the section
+        let table = sparse_table(&[sparse::RAW, words as u32 - 1, 1, 2, 3, 4, 5, 6, 7]);
+        let error =
+            init_failure(|| unsafe { section_init_sparse(range.start, range.end, table.as_ptr()) });
+        assert_eq!(
+            error,
+            Some(InitError::LengthMismatch {
+                expected: words * 4,
+                actual: (words - 1) * 4
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "failure-handler", feature = "memory-bounds"))]
+    fn failure_handler_out_of_flash_bounds() {
+        let error = init_failure(|| out_of_flash_bounds(0x800));
+        assert_eq!(error, Some(InitError::OutOfFlashBounds(0x800)));
+    }
+
+    /// Runs [`section_init_partial`] over 4-word section, returns bytes copied and the section.
+    fn init_partial(len_bytes: usize) -> (usize, [Word; 4]) {
+        let mut dst = [0; 4];
+        let src = [1, 2, 3, 4];
+        let range = dst.as_mut_ptr_range();
+
+        let copied =
+            unsafe { section_init_partial(range.start, range.end, src.as_ptr(), len_bytes) };
+
+        (copied, dst)
+    }
+
+    #[test]
+    fn section_init_partial_zero_len() {
+        assert_eq!(init_partial(0), (0, [0; 4]));
+    }
+
+    #[test]
+    fn section_init_partial_rounds_up_to_word() {
+        assert_eq!(init_partial(1), (WORD, [1, 0, 0, 0]));
+        assert_eq!(init_partial(WORD + 1), (2 * WORD, [1, 2, 0, 0]));
+        assert_eq!(init_partial(2 * WORD), (2 * WORD, [1, 2, 0, 0]));
+    }
+
+    #[test]
+    fn section_init_partial_full_capacity() {
+        assert_eq!(init_partial(4 * WORD), (4 * WORD, [1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn section_init_partial_clamped_to_capacity() {
+        assert_eq!(init_partial(4 * WORD + 1), (4 * WORD, [1, 2, 3, 4]));
+        assert_eq!(init_partial(usize::MAX), (4 * WORD, [1, 2, 3, 4]));
+    }
+
+    /// Runs [`section_init_chunked`] over 16-word section, returns the yields and the section.
+    fn init_chunked(words: usize, chunk_bytes: usize) -> (usize, [Word; 16]) {
+        let (mut dst, src) = test_words::<16>();
+        let start = dst.as_mut_ptr();
+        let end = start.wrapping_add(words);
+        let mut yields = 0;
+
+        unsafe { section_init_chunked(start, end, src.as_ptr(), chunk_bytes, || yields += 1) };
+
+        let expected: [Word; 16] =
+            core::array::from_fn(|i| if i < words { src[i] } else { dst[i] });
+        assert_eq!(dst, expected);
+
+        (yields, dst)
+    }
+
+    #[test]
+    fn section_init_chunked_yields() {
+        assert_eq!(init_chunked(16, 4 * WORD).0, 3);
+        assert_eq!(init_chunked(16, 5 * WORD).0, 3);
+        assert_eq!(init_chunked(15, 5 * WORD).0, 2);
+        assert_eq!(init_chunked(16, 16 * WORD).0, 0);
+        assert_eq!(init_chunked(16, usize::MAX).0, 0);
+        assert_eq!(init_chunked(0, 4 * WORD).0, 0);
+    }
+
+    #[test]
+    fn section_init_chunked_size_clamped() {
+        // the chunk is rounded down to whole words, but is at least one word
+        assert_eq!(init_chunked(16, 4 * WORD + 1).0, 3);
+        assert_eq!(init_chunked(16, 1).0, 15);
+        assert_eq!(init_chunked(16, 0).0, 15);
+    }
+
+    #[test]
+    fn section_init_chunked_copied() {
+        let (_, src) = test_words::<16>();
+
+        assert_eq!(init_chunked(16, 3 * WORD).1, src);
+    }
+
+    /// Polls [`SectionInitFsm`] over 7-word and 5-word sections by `budget` words until done,
+    /// returns the statuses.
+    fn poll_fsm(budget: usize) -> std::vec::Vec<InitStatus> {
+        let (mut first, src) = test_words::<7>();
+        let (mut second, _) = test_words::<5>();
+        let (first_range, second_range) = (first.as_mut_ptr_range(), second.as_mut_ptr_range());
+        let mut fsm = SectionInitFsm::new([
+            Section::new(first_range.start, first_range.end, src.as_ptr()),
+            Section::new(second_range.start, second_range.start, src.as_ptr()),
+            Section::new(second_range.start, second_range.end, src[2..].as_ptr()),
+        ]);
+
+        let mut statuses = std::vec::Vec::new();
+        while statuses.last() != Some(&InitStatus::Done) {
+            statuses.push(unsafe { fsm.poll(budget) });
+        }
+
+        assert_eq!(first, src);
+        assert_eq!(second, src[2..]);
+        statuses
+    }
+
+    #[test]
+    fn section_init_fsm_resumed() {
+        for budget in [1, 2, 3, 5, 11, 12, 100] {
+            let statuses = poll_fsm(budget);
+
+            assert_eq!(statuses.len(), 12usize.div_ceil(budget), "budget {budget}");
+            for (i, status) in statuses[..statuses.len() - 1].iter().enumerate() {
+                let done = (i + 1) * budget * WORD;
+                assert_eq!(
+                    *status,
+                    InitStatus::InProgress {
+                        done,
+                        total: 12 * WORD
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn section_init_fsm_zero_budget() {
+        let (mut dst, src) = test_words::<2>();
+        let range = dst.as_mut_ptr_range();
+        let mut fsm = SectionInitFsm::new([Section::new(range.start, range.end, src.as_ptr())]);
+
+        let status = InitStatus::InProgress {
+            done: 0,
+            total: 2 * WORD,
+        };
+        assert_eq!(unsafe { fsm.poll(0) }, status);
+        assert_eq!(fsm.status(), status);
+        assert_eq!(unsafe { fsm.poll(2) }, InitStatus::Done);
+        assert_eq!(dst, src);
+    }
+
+    #[test]
+    fn deferred_init_run_with_progress() {
+        let (mut first, src) = test_words::<4>();
+        let (mut second, _) = test_words::<2>();
+        let (first_range, second_range) = (first.as_mut_ptr_range(), second.as_mut_ptr_range());
+        let deferred = DeferredInit::new([
+            Section::new(first_range.start, first_range.end, src.as_ptr()),
+            Section::new(second_range.start, second_range.start, src.as_ptr()),
+            Section::new(second_range.start, second_range.end, src.as_ptr()),
+        ]);
+
+        let mut reports = std::vec::Vec::new();
+        unsafe { deferred.run_with_progress(3 * WORD, |progress| reports.push(progress)) };
+
+        // the last chunk of a section is shorter, the empty section is not reported
+        let report = |section, section_done, section_total, done| InitProgress {
+            section,
+            section_done: section_done * WORD,
+            section_total: section_total * WORD,
+            done: done * WORD,
+            total: 6 * WORD,
+        };
+        assert_eq!(
+            reports,
+            [report(0, 3, 4, 3), report(0, 4, 4, 4), report(2, 2, 2, 6)]
+        );
+        assert_eq!(first, src);
+        assert_eq!(second, src[..2]);
+    }
+
+    std::thread_local! {
+        static FEEDS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+    }
+
+    fn count_feed() {
+        FEEDS.set(FEEDS.get() + 1);
+    }
+
+    /// Runs [`section_init_fed`] over section of `words` feeding every `every` words, returns the
+    /// feeds.
+    fn init_fed(words: usize, every: usize) -> usize {
+        let (mut dst, src) = test_words::<16>();
+        let start = dst.as_mut_ptr();
+
+        FEEDS.set(0);
+        unsafe {
+            section_init_fed(
+                start,
+                start.wrapping_add(words),
+                src.as_ptr(),
+                every,
+                count_feed,
+            )
+        };
+
+        assert_eq!(dst[..words], src[..words]);
+        FEEDS.get()
+    }
+
+    #[test]
+    fn section_init_fed_cadence() {
+        assert_eq!(init_fed(16, 4), 4);
+        assert_eq!(init_fed(16, 5), 4);
+        assert_eq!(init_fed(16, 1), 16);
+        assert_eq!(init_fed(16, 0), 16);
+    }
+
+    #[test]
+    fn section_init_fed_tiny_section() {
+        // the feed is called at least once, so the timing does not depend on the section size
+        assert_eq!(init_fed(3, 4096), 1);
+        assert_eq!(init_fed(16, usize::MAX), 1);
+        assert_eq!(init_fed(0, 4), 1);
+    }
+
+    #[test]
+    #[cfg(any(feature = "bench-dwt", feature = "bench-systick"))]
+    fn bench_report_cleared() {
+        for i in 0..bench::CAPACITY + 2 {
+            bench::push("custom_data", i as u32);
+        }
+
+        // the records beyond the capacity are dropped
+        let report = bench::report();
+        assert_eq!(report.entries().len(), bench::CAPACITY);
+        assert_eq!(report.entries()[1], ("custom_data", 1));
+        assert!(bench::report().entries().is_empty());
+
+        bench::push("other", 7);
+        assert_eq!(bench::report().entries(), [("other", 7)]);
+
+        // a checksum is recorded only within a measured section
+        bench::record_split("custom_data:crc32", bench::split(None));
+        bench::record_split("custom_data:fletcher32", Some(3));
+        assert_eq!(bench::report().entries(), [("custom_data:fletcher32", 3)]);
+    }
+
+    /// Fragment start and end of `words`.
+    fn fragment(words: &[Word]) -> (*const Word, *const Word) {
+        let range = words.as_ptr_range();
+
+        (range.start, range.end)
+    }
+
+    #[test]
+    fn section_init_fragments_gathered() {
+        let mut dst = [0; 6];
+        let (head, middle, tail) = ([1, 2], [3, 4, 5], [6]);
+        let range = dst.as_mut_ptr_range();
+        let fragments = [fragment(&head), fragment(&middle), fragment(&tail)];
+
+        unsafe { section_init_fragments(range.start, range.end, &fragments) };
+
+        assert_eq!(dst, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    #[cfg(assert_bounds)]
+    #[should_panic]
+    fn section_init_fragments_short() {
+        let mut dst = [0; 6];
+        let (head, tail) = ([1, 2], [3, 4, 5]);
+        let range = dst.as_mut_ptr_range();
+
+        unsafe {
+            section_init_fragments(range.start, range.end, &[fragment(&head), fragment(&tail)])
+        };
+    }
+
+    // the expansions shall compile next to user items named the same as the section or as the
+    // expansion internals, the function is only type checked, never called
+    #[allow(dead_code, non_upper_case_globals)]
+    mod user_items {
+        fn custom_data() {}
+        fn __pre_init_sections() {}
+        fn __init_sections_placed() {}
+        mod __linker_sections {}
+        static __scustom_data: u32 = 0;
+        static section: u32 = 0;
+        static dst: u32 = 0;
+        static end: u32 = 0;
+        static src: u32 = 0;
+        static len: u32 = 0;
+        static len_bytes: u32 = 0;
+        static table: u32 = 0;
+        static pattern: u32 = 0;
+        static first: u32 = 0;
+        static GUARD: u32 = 0;
+        const VALUE: u32 = 0;
+        const FILL: crate::Word = 0xAAAA_AAAA;
+
+        crate::declare_noinit_section!(counter: u32);
+        crate::persistent_section!(settings);
+        crate::keep_section_symbols!(custom_data);
+        #[cfg(feature = "memory-bounds")]
+        crate::set_flash_bounds!(0, usize::MAX);
+        #[cfg(feature = "memory-bounds")]
+        crate::set_ram_bounds!(0x1000, usize::MAX / 2; usize::MAX / 2, usize::MAX);
+        #[cfg(feature = "failure-handler")]
+        crate::set_failure_handler!(super::record_init_error);
+        #[cfg(any(
+            feature = "assert-align",
+            feature = "assert-bounds",
+            feature = "assert-order",
+            feature = "assert-overlap",
+            feature = "asserts-debug-only",
+            feature = "memory-bounds"
+        ))]
+        crate::set_failure_scratch!(0x2000_7FF8);
+
+        static INIT_ERROR: crate::InitErrorCell = crate::InitErrorCell::new();
+        #[cfg(feature = "verify")]
+        static VERIFIED: crate::VerifiedFlag = crate::VerifiedFlag::new();
+        fn failed(_address: usize) {}
+        fn feed() {}
+        #[cfg(feature = "compress-lz4")]
+        fn corrupted(_error: crate::lz4::Error) {}
+        #[cfg(feature = "compress-rle")]
+        fn rle_corrupted(_error: crate::rle::Error) {}
+        #[cfg(feature = "compress-heatshrink")]
+        fn heatshrink_corrupted(_error: crate::heatshrink::Error) {}
+
+        fn expansions(cold: bool, len_valid: usize) {
+            crate::init_sections!(custom_data zero_to __eregion, optional other);
+            crate::init_sections!(in ramcode; custom_data);
+            crate::init_sections!(custom_data: pma, optional other: u8);
+            crate::init_sections!(custom_data: pma stride 2 zero_to __eregion);
+            crate::init_sections!(in ramcode; optional custom_data: u16 stride 2);
+            crate::init_sections!(@inline_always; custom_data);
+            crate::init_sections!(@inline_never; custom_data);
+            let _: bool = crate::init_sections_once!(custom_data);
+            let _: usize = crate::reinit_section!(custom_data);
+            let _: usize = crate::reinit_section!(custom_data chunked(1024, || {}));
+            let _: usize = crate::reinit_section!(custom_data no_critical_section);
+            let _: usize = crate::reinit_section!(other(__s, __e, __si) no_critical_section);
+            let deferred = crate::defer_sections_init!(custom_data no_critical_section, other(__s, __e, __si), #[cfg(all())] third no_critical_section);
+            unsafe { deferred.run() };
+            let _: usize = crate::reinit_section!(other(__s, __e, __si) chunked(len_valid, || {}));
+            let _: crate::Section = crate::section!(other(__s, __e));
+            crate::init_sections_if!(custom_data if cold);
+            crate::zero_sections!(custom_data);
+            crate::fill_sections!(FILL; custom_data);
+            crate::init_sections!(custom_data: ecc64, optional other: ecc zero_to __eregion);
+            crate::zero_sections!(custom_data: ecc, other);
+            crate::fill_sections!(FILL; custom_data other: ecc64);
+            crate::zero_sections_with_prefixes!(custom_data(__s, __e): ecc64);
+            crate::init_sections!(custom_data verify_writes(failed), optional other: ecc verify_writes(failed, 1));
+            crate::init_sections!(custom_data: u8 verify_writes(self::failed) zero_to __eregion);
+            #[cfg(feature = "verify")]
+            crate::init_sections!(custom_data zero_to __eregion verify(VERIFIED) clean_dcache, optional other: ecc verify);
+            #[cfg(feature = "verify")]
+            crate::init_sections!((__s, __e, __si): custom_data: u32 verify(self::VERIFIED), other verify no_barrier);
+            #[cfg(feature = "crc32-verify")]
+            crate::init_sections!(custom_data zero_to __eregion crc32_verify clean_dcache, optional other: ecc crc32_verify);
+            #[cfg(feature = "crc32-verify")]
+            crate::verify_image!(__simage, __eimage, __image_crc);
+            #[cfg(feature = "crc32-verify")]
+            crate::init_sections!(verify_image(__simage, __eimage, __image_crc); in ramcode; custom_data, optional other);
+            #[cfg(feature = "crc32-verify")]
+            crate::init_sections!(@inline_never; verify_image(__simage, __eimage, __image_crc,); custom_data);
+            crate::init_sections!(custom_data canary, optional other: u8 zero_to __eregion canary(at __other_canary) clean_dcache);
+            crate::init_sections!(custom_data: ecc verify_writes(failed) canary(magic = 0x1234_5678), other canary(at __other_canary, magic = crate::CANARY ^ 1));
+            #[cfg(feature = "compress-lz4")]
+            crate::init_sections!((__s, __e, __si): custom_data compressed(lz4) canary(), other canary no_barrier);
+            crate::clear_canary!(custom_data, at __other_canary,);
+            crate::clear_canary!(at __other_canary);
+            #[cfg(feature = "fletcher32-verify")]
+            crate::init_sections!(custom_data: ecc64 verify(fletcher32), optional other zero_to __eregion verify(fletcher32) code);
+            #[cfg(all(feature = "crc32-verify", feature = "compress-rle"))]
+            crate::init_sections!((__s, __e, __si): custom_data: u32 crc32_verify, other compressed(rle) crc32_verify no_barrier);
+            #[cfg(feature = "compress-lz4")]
+            crate::init_sections!(custom_data compressed(lz4), optional other compressed(lz4, corrupted) zero_to __eregion);
+            #[cfg(feature = "compress-rle")]
+            crate::init_sections!((__s, __e, __si): custom_data compressed(rle, rle_corrupted), other compressed(rle) clean_dcache);
+            #[cfg(feature = "sparse-init")]
+            crate::init_sections!(custom_data sparse, optional other sparse zero_to __eregion);
+            #[cfg(feature = "compress-heatshrink")]
+            crate::init_sections!(custom_data compressed(heatshrink), optional other compressed(heatshrink, heatshrink_corrupted));
+            #[cfg(feature = "nor-flash")]
+            {
+                let mut flash = super::VecFlash::<1>::new(0);
+                let _: Result<(), u32> = crate::init_sections_from_nor!(&mut flash; custom_data at 0x100, other(__s, __e) at len_valid as u32);
+            }
+            crate::init_sections!(custom_data clean_dcache, optional other: ecc zero_to __eregion clean_dcache);
+            crate::init_sections!(in ramcode; custom_data: u16 clean_dcache, other);
+            crate::init_sections!(ramfunc code, itcm_code zero_to __eitcm clean_dcache code);
+            crate::init_sections!(custom_data no_barrier, other: ecc zero_to __eregion code no_barrier);
+            crate::init_sections!(custom_data: swap32, optional other: swap16 zero_to __eregion);
+            crate::init_sections!(custom_data: widen8to32, optional other: widen8to32);
+            crate::init_sections_fragments!(custom_data[__si0..__ei0, __si1..__ei1], other(__s, __e)[__si2..__ei2]);
+            crate::init_sections!(in ramcode; custom_data no_barrier, other);
+            crate::init_sections_with_len!(custom_data(__s, __l, __si));
+            crate::init_cmsis_tables!();
+            let _: usize = crate::init_section_partial!(custom_data, len_valid);
+            crate::relocate_sections!(custom_data, other(__start_, __stop_, __load_));
+            crate::init_sections_with_copier!(crate::CpuCopier; custom_data, other(__s, __e, __si));
+            crate::init_sections_with_feed!(every 4096 words with feed; custom_data, other(__s, __e, __si));
+            crate::try_init_sections!(INIT_ERROR; custom_data, other(__s, __e, __si));
+            crate::try_init_sections!(self::INIT_ERROR; custom_data);
+            crate::init_sections_with_feed!(every len_valid words with self::feed; custom_data);
+            let mut copier = crate::CpuCopier;
+            crate::init_sections_with_copier!(&mut copier; custom_data);
+            unsafe { crate::defer_sections_init!(custom_data).run_with(&mut copier) };
+            let _: usize = crate::paint_stack!(_stack_end, _stack_start, 0xCCCC_CCCC);
+            let _ = (counter(), settings());
+        }
+    }
+
+    keep_section_symbols!(keep_test_kept => KEEP_TEST_KEPT, keep_test_other);
+
+    // both statics are referenced by an exported function nobody calls, so only the anchor could
+    // keep their sections when the test binary is linked with `--gc-sections`
+    #[unsafe(link_section = ".keep_test_kept")]
+    static KEPT: u32 = 1;
+    #[unsafe(link_section = ".keep_test_dropped")]
+    static DROPPED: u32 = 2;
+
+    #[unsafe(no_mangle)]
+    extern "C" fn linker_sections_keep_test_unused(kept: bool) -> *const u32 {
+        if kept {
+            &KEPT
+        } else {
+            &DROPPED
+        }
+    }
+
+    /// Returns names of the sections of the running test binary.
+    #[cfg(all(
+        target_os = "linux",
+        target_pointer_width = "64",
+        target_endian = "little"
+    ))]
+    fn binary_section_names() -> std::vec::Vec<std::string::String> {
+        let elf = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+        let read = |offset: usize, len: usize| {
+            let mut bytes = [0u8; 8];
+            bytes[..len].copy_from_slice(&elf[offset..offset + len]);
+            u64::from_le_bytes(bytes) as usize
+        };
+
+        let (shoff, shentsize, shnum, shstrndx) =
+            (read(0x28, 8), read(0x3A, 2), read(0x3C, 2), read(0x3E, 2));
+        let names = read(shoff + shstrndx * shentsize + 0x18, 8);
+
+        (0..shnum)
+            .map(|index| {
+                let name = &elf[names + read(shoff + index * shentsize, 4)..];
+                let len = name.iter().position(|&byte| byte == 0).unwrap();
+                std::string::String::from_utf8_lossy(&name[..len]).into_owned()
+            })
+            .collect()
+    }
+
+    #[test]
+    #[cfg(all(
+        target_os = "linux",
+        target_pointer_width = "64",
+        target_endian = "little"
+    ))]
+    fn keep_section_symbols_survive_gc_sections() {
+        let names = binary_section_names();
+
+        assert!(names.iter().any(|name| name == ".keep_test_kept"));
+        assert!(names.iter().any(|name| name == ".keep_test_other"));
+        assert!(!names.iter().any(|name| name == ".keep_test_dropped"));
+    }
+
+    #[test]
+    fn keep_section_symbols_guidance() {
+        assert_eq!(
+            KEEP_TEST_KEPT,
+            "KEEP(*(.keep_test_kept .keep_test_kept.*));"
+        );
+    }
 }
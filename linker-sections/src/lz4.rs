@@ -0,0 +1,323 @@
+//! LZ4 compressed section data (`compress-lz4` feature).
+//!
+//! A section marked `compressed(lz4)` is initialized by decompressing its load data, a blob made
+//! of a header and a raw LZ4 block (no frame):
+//!
+//! | offset | size | content                                      |
+//! |--------|------|----------------------------------------------|
+//! | 0      | 4    | uncompressed length, little endian `u32`     |
+//! | 4      | 4    | compressed block length, little endian `u32` |
+//! | 8      |      | LZ4 block                                    |
+//!
+//! The uncompressed length must equal the section length. The decompression never reads past the
+//! block nor writes past the section, a malformed blob is reported by [`Error`] to the handler
+//! given by `compressed(lz4, <handler>)`, or it panics if no handler is given.
+//!
+//! The blob is produced on the host by [`compress_blob`], e.g. by a build script depending on
+//! this crate with `compress-lz4` feature:
+//!
+//! ```
+//! let assets = std::fs::read("assets/ui.bin").unwrap();
+//! let mut blob = vec![0; linker_sections::lz4::blob_bound(assets.len())];
+//! let len = linker_sections::lz4::compress_blob(&assets, &mut blob).unwrap();
+//!
+//! let out = std::path::PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+//! std::fs::write(out.join("ui.lz4"), &blob[..len]).unwrap();
+//! ```
+//!
+//! The firmware places the blob into its own flash section and the uncompressed data into a
+//! `NOLOAD` RAM section, so only the blob takes flash:
+//!
+//! ```
+//! const UI_LEN: usize = 48 * 1024;
+//!
+//! #[unsafe(link_section = ".ui_assets_lz4")]
+//! #[used]
+//! static UI_BLOB: [u8; include_bytes!(concat!(env!("OUT_DIR"), "/ui.lz4")).len()] =
+//!     *include_bytes!(concat!(env!("OUT_DIR"), "/ui.lz4"));
+//!
+//! #[unsafe(link_section = ".ui_assets")]
+//! static mut UI_ASSETS: core::mem::MaybeUninit<[u8; UI_LEN]> = core::mem::MaybeUninit::uninit();
+//!
+//! init_sections!(ui_assets compressed(lz4));
+//! ```
+//!
+//! ```text
+//! SECTIONS
+//! {
+//!     .ui_assets_lz4 : ALIGN(4)
+//!     {
+//!         __siui_assets = .;
+//!         KEEP(*(.ui_assets_lz4 .ui_assets_lz4.*));
+//!     } > FLASH
+//!
+//!     .ui_assets (NOLOAD) : ALIGN(4)
+//!     {
+//!         __sui_assets = .;
+//!         *(.ui_assets .ui_assets.*);
+//!         __eui_assets = .;
+//!     } > RAM
+//! } INSERT AFTER .uninit;
+//! ```
+
+/// Blob header size in bytes.
+pub const HEADER_LEN: usize = 8;
+
+/// Shortest match encoded by a sequence.
+const MIN_MATCH: usize = 4;
+
+/// Bytes at the block end always encoded as literals, as required by the LZ4 block format.
+const LAST_LITERALS: usize = 5;
+
+/// Last match shall start at least this many bytes before the block end.
+const MATCH_LIMIT: usize = 12;
+
+/// Hash table size of [`compress`] as a power of two.
+const HASH_LOG: u32 = 12;
+
+/// Malformed LZ4 blob or block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The block or its header ends in the middle of a sequence.
+    Truncated,
+    /// A match refers to data before the decompressed start or its offset is zero.
+    InvalidOffset,
+    /// The decompressed data does not fit the destination.
+    OutputOverrun,
+    /// The uncompressed length differs from the section length or the data decompressed.
+    LengthMismatch,
+}
+
+/// Decompresses raw LZ4 `block` into `dst`, returns the number of bytes decompressed.
+///
+/// Neither the block is read past its end nor `dst` written past its end, whatever the block
+/// holds.
+pub fn decompress(dst: &mut [u8], block: &[u8]) -> Result<usize, Error> {
+    let mut input = 0;
+    let mut output = 0;
+
+    loop {
+        let token = *block.get(input).ok_or(Error::Truncated)?;
+        input += 1;
+
+        let literals = length(block, &mut input, usize::from(token >> 4))?;
+        if literals > block.len() - input {
+            return Err(Error::Truncated);
+        }
+        if literals > dst.len() - output {
+            return Err(Error::OutputOverrun);
+        }
+        dst[output..output + literals].copy_from_slice(&block[input..input + literals]);
+        input += literals;
+        output += literals;
+
+        // the last sequence holds literals only
+        if input == block.len() {
+            return Ok(output);
+        }
+
+        if block.len() - input < 2 {
+            return Err(Error::Truncated);
+        }
+        let offset = usize::from(u16::from_le_bytes([block[input], block[input + 1]]));
+        input += 2;
+        if offset == 0 || offset > output {
+            return Err(Error::InvalidOffset);
+        }
+
+        let matched =
+            length(block, &mut input, usize::from(token & 0x0F))?.saturating_add(MIN_MATCH);
+        if matched > dst.len() - output {
+            return Err(Error::OutputOverrun);
+        }
+
+        // byte by byte, the match may overlap the bytes it produces
+        let end = output + matched;
+        while output < end {
+            dst[output] = dst[output - offset];
+            output += 1;
+        }
+    }
+}
+
+/// Returns a sequence length starting by its token `nibble`, followed by the extension bytes at
+/// `input` if the nibble is saturated.
+fn length(block: &[u8], input: &mut usize, nibble: usize) -> Result<usize, Error> {
+    let mut length = nibble;
+
+    if nibble == 0x0F {
+        loop {
+            let byte = *block.get(*input).ok_or(Error::Truncated)?;
+            *input += 1;
+            length = length.saturating_add(usize::from(byte));
+
+            if byte != 0xFF {
+                break;
+            }
+        }
+    }
+
+    Ok(length)
+}
+
+/// Decompresses a blob of `compress_blob` into `dst`, its uncompressed length must equal the
+/// `dst` length.
+pub fn decompress_blob(dst: &mut [u8], blob: &[u8]) -> Result<(), Error> {
+    let (uncompressed, block) = blob_header(blob)?;
+    let block_end = HEADER_LEN.checked_add(block).ok_or(Error::Truncated)?;
+    let block = blob.get(HEADER_LEN..block_end).ok_or(Error::Truncated)?;
+
+    if uncompressed != dst.len() || decompress(dst, block)? != dst.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    Ok(())
+}
+
+/// Returns the uncompressed and the compressed block length held by `blob` header.
+pub(crate) fn blob_header(blob: &[u8]) -> Result<(usize, usize), Error> {
+    let header = blob.get(..HEADER_LEN).ok_or(Error::Truncated)?;
+    let word = |offset: usize| {
+        u32::from_le_bytes([
+            header[offset],
+            header[offset + 1],
+            header[offset + 2],
+            header[offset + 3],
+        ]) as usize
+    };
+
+    Ok((word(0), word(4)))
+}
+
+/// Returns the greatest raw block length [`compress`] produces for `len` bytes.
+pub const fn block_bound(len: usize) -> usize {
+    len + len / 255 + 16
+}
+
+/// Returns the greatest blob length [`compress_blob`] produces for `len` bytes.
+pub const fn blob_bound(len: usize) -> usize {
+    HEADER_LEN + block_bound(len)
+}
+
+/// Compresses `src` into raw LZ4 block `dst`, returns the block length or `None` if `dst` is too
+/// short, see [`block_bound`].
+///
+/// It is a plain greedy compressor meant for the host, e.g. a build script, it takes 16 KiB of
+/// stack for its hash table.
+pub fn compress(src: &[u8], dst: &mut [u8]) -> Option<usize> {
+    let mut table = [u32::MAX; 1 << HASH_LOG];
+    let mut anchor = 0;
+    let mut position = 0;
+    let mut output = 0;
+
+    if src.len() > MATCH_LIMIT {
+        let match_limit = src.len() - MATCH_LIMIT;
+        let extend_limit = src.len() - LAST_LITERALS;
+
+        while position < match_limit {
+            let sequence = read_u32(src, position);
+            let hash = (sequence.wrapping_mul(2_654_435_761) >> (32 - HASH_LOG)) as usize;
+            let candidate = table[hash] as usize;
+            table[hash] = position as u32;
+
+            if table_hit(src, candidate, position, sequence) {
+                let mut matched = MIN_MATCH;
+                while position + matched < extend_limit
+                    && src[candidate + matched] == src[position + matched]
+                {
+                    matched += 1;
+                }
+
+                let sequence = (position - candidate, matched);
+                output = write_sequence(dst, output, &src[anchor..position], Some(sequence))?;
+                position += matched;
+                anchor = position;
+            } else {
+                position += 1;
+            }
+        }
+    }
+
+    write_sequence(dst, output, &src[anchor..], None)
+}
+
+/// Tells whether the hash table `candidate` matches `sequence` at `position` within reach.
+fn table_hit(src: &[u8], candidate: usize, position: usize, sequence: u32) -> bool {
+    candidate < position
+        && position - candidate <= usize::from(u16::MAX)
+        && read_u32(src, candidate) == sequence
+}
+
+/// Compresses `src` into a blob `dst` of header and raw LZ4 block, returns the blob length or
+/// `None` if `dst` is too short, see [`blob_bound`].
+pub fn compress_blob(src: &[u8], dst: &mut [u8]) -> Option<usize> {
+    let (header, block) = dst.split_at_mut_checked(HEADER_LEN)?;
+    let len = compress(src, block)?;
+
+    header[..4].copy_from_slice(&u32::try_from(src.len()).ok()?.to_le_bytes());
+    header[4..].copy_from_slice(&u32::try_from(len).ok()?.to_le_bytes());
+
+    Some(HEADER_LEN + len)
+}
+
+/// Writes a sequence of `literals` followed by a match of `(offset, length)`, if any, at `output`,
+/// returns the output position after it.
+fn write_sequence(
+    dst: &mut [u8],
+    mut output: usize,
+    literals: &[u8],
+    matched: Option<(usize, usize)>,
+) -> Option<usize> {
+    let match_nibble = matched.map_or(0, |(_, length)| (length - MIN_MATCH).min(0x0F));
+    let token = (literals.len().min(0x0F) << 4) | match_nibble;
+
+    *dst.get_mut(output)? = token as u8;
+    output += 1;
+
+    output = write_length(dst, output, literals.len())?;
+    dst.get_mut(output..output + literals.len())?
+        .copy_from_slice(literals);
+    output += literals.len();
+
+    if let Some((offset, length)) = matched {
+        dst.get_mut(output..output + 2)?
+            .copy_from_slice(&(offset as u16).to_le_bytes());
+        output += 2;
+        output = write_length(dst, output, length - MIN_MATCH)?;
+    }
+
+    Some(output)
+}
+
+/// Writes the extension bytes of `length` whose token nibble is saturated, if any.
+fn write_length(dst: &mut [u8], mut output: usize, length: usize) -> Option<usize> {
+    if length >= 0x0F {
+        let mut rest = length - 0x0F;
+        while rest >= 0xFF {
+            *dst.get_mut(output)? = 0xFF;
+            output += 1;
+            rest -= 0xFF;
+        }
+        *dst.get_mut(output)? = rest as u8;
+        output += 1;
+    }
+
+    Some(output)
+}
+
+/// Reads native endian `u32` at `position`, only compared with other reads.
+fn read_u32(src: &[u8], position: usize) -> u32 {
+    u32::from_ne_bytes([
+        src[position],
+        src[position + 1],
+        src[position + 2],
+        src[position + 3],
+    ])
+}
+
+/// Default handler of `compressed(lz4)` sections given no handler.
+#[doc(hidden)]
+pub fn failed(error: Error) {
+    let _ = error;
+    panic!("malformed lz4 section data");
+}
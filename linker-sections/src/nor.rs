@@ -0,0 +1,281 @@
+//! Section load data in an external NOR flash (`nor-flash` feature).
+//!
+//! A NOR flash behind SPI or a similar bus is not memory mapped, so the section LMA cannot be
+//! given by an `__si<section>` symbol. The section is initialized by [`init_section_from_nor`]
+//! reading its load data from a flash offset through [`ReadNorFlash`] instead, or by
+//! [`init_sections_from_nor`](crate::init_sections_from_nor) giving the offset by `at <offset>`
+//! marker following the section name.
+//!
+//! The flash is read through a driver, so the sections are initialized in `main` once the driver
+//! is up, the same way as the deferred sections, see
+//! [`defer_sections_init`](crate::defer_sections_init).
+//!
+//! [`ReadNorFlash`] has the shape of `embedded_storage::nor_flash::ReadNorFlash`, so a driver
+//! implementing the latter is adapted by a newtype forwarding `read` and `capacity`.
+//!
+//! ```
+//! struct Nor<'a, F>(&'a mut F);
+//!
+//! impl<F: embedded_storage::nor_flash::ReadNorFlash> linker_sections::nor::ReadNorFlash for Nor<'_, F> {
+//!     type Error = F::Error;
+//!     const READ_SIZE: usize = F::READ_SIZE;
+//!
+//!     fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+//!         self.0.read(offset, bytes)
+//!     }
+//!
+//!     fn capacity(&self) -> usize {
+//!         self.0.capacity()
+//!     }
+//! }
+//! ```
+//!
+//! With `async` feature enabled, the load data could be read by an async driver, e.g. sharing its
+//! SPI bus with other tasks, through [`AsyncReadNorFlash`], the same as
+//! `embedded_storage_async::nor_flash::ReadNorFlash`, by [`init_section_from_nor_async`] or by
+//! [`SectionInitFsm::poll_nor_async`] reporting the progress.
+
+use crate::{section_init_unbounded, Section, Word, WORD};
+#[cfg(feature = "async")]
+use crate::{InitStatus, SectionInitFsm};
+
+/// Bytes read from the flash at once, the stack buffer size of [`init_section_from_nor`].
+pub const READ_CHUNK: usize = 64;
+
+/// Readable NOR flash, the same as `embedded_storage::nor_flash::ReadNorFlash`.
+pub trait ReadNorFlash {
+    /// Read error of the flash driver.
+    type Error;
+
+    /// Read granularity in bytes, the read offsets and lengths are multiples of it.
+    const READ_SIZE: usize;
+
+    /// Reads `bytes.len()` bytes starting at `offset`.
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Returns the flash size in bytes.
+    fn capacity(&self) -> usize;
+}
+
+impl<T: ReadNorFlash + ?Sized> ReadNorFlash for &mut T {
+    type Error = T::Error;
+    const READ_SIZE: usize = T::READ_SIZE;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        T::read(self, offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        T::capacity(self)
+    }
+}
+
+/// Initializes `section` memory with its load data read from `flash` at `flash_offset`.
+///
+/// The data is read in chunks of [`READ_CHUNK`] bytes into a stack buffer and copied into the
+/// section by the same code as [`Section::init`] does, word by word if the section start is word
+/// aligned. The load address of `section` is not used. The last read is rounded up to
+/// `F::READ_SIZE`, so the flash is read up to `READ_SIZE - 1` bytes past the load data.
+///
+/// A read error is returned as soon as it happens, the section is then initialized partially.
+///
+/// With `asserts` or `assert-bounds` feature enabled, the load data is checked to fit the flash
+/// capacity.
+///
+/// # Panics
+///
+/// Panics if `F::READ_SIZE` does not divide [`READ_CHUNK`].
+///
+/// # Safety
+///
+/// - The section addresses must point to memory with write access, the section start must be less
+///   or equal to the section end.
+/// - No reference to any variable placed in the section shall be alive during the call.
+pub unsafe fn init_section_from_nor<F: ReadNorFlash>(
+    flash: &mut F,
+    flash_offset: u32,
+    section: Section,
+) -> Result<(), F::Error> {
+    assert!(
+        READ_CHUNK.is_multiple_of(F::READ_SIZE),
+        "flash read size does not divide the read chunk"
+    );
+
+    let len = section.len_bytes();
+
+    #[cfg(assert_bounds)]
+    if flash_offset as usize + len > flash.capacity() {
+        crate::init_failed(crate::InitError::OutOfRange {
+            address: flash_offset as usize + len,
+            end: flash.capacity(),
+        });
+    }
+
+    let mut buffer = [0 as Word; READ_CHUNK / WORD];
+    let mut done = 0;
+
+    while done < len {
+        let chunk = (len - done).min(READ_CHUNK);
+        let read = chunk.next_multiple_of(F::READ_SIZE);
+
+        // SAFETY: The buffer is `READ_CHUNK` bytes long and `read` does not exceed it
+        let bytes = unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast(), read) };
+        flash.read(flash_offset + done as u32, bytes)?;
+
+        let dst = section.start().wrapping_byte_add(done);
+        let end = dst.wrapping_byte_add(chunk);
+        unsafe { section_init_unbounded(dst, end, buffer.as_ptr()) };
+
+        done += chunk;
+    }
+
+    Ok(())
+}
+
+/// Initializes `sections` each with its load data read from `flash` at its offset, see
+/// [`init_sections_from_nor`](crate::init_sections_from_nor).
+#[doc(hidden)]
+pub unsafe fn init_sections_at<F: ReadNorFlash>(
+    flash: &mut F,
+    sections: &[(u32, Section)],
+) -> Result<(), F::Error> {
+    for &(flash_offset, section) in sections {
+        unsafe { init_section_from_nor(flash, flash_offset, section)? };
+    }
+
+    Ok(())
+}
+
+/// Readable async NOR flash, the same as `embedded_storage_async::nor_flash::ReadNorFlash`.
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncReadNorFlash {
+    /// Read error of the flash driver.
+    type Error;
+
+    /// Read granularity in bytes, the read offsets and lengths are multiples of it.
+    const READ_SIZE: usize;
+
+    /// Reads `bytes.len()` bytes starting at `offset`.
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Returns the flash size in bytes.
+    fn capacity(&self) -> usize;
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncReadNorFlash + ?Sized> AsyncReadNorFlash for &mut T {
+    type Error = T::Error;
+    const READ_SIZE: usize = T::READ_SIZE;
+
+    async fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        T::read(self, offset, bytes).await
+    }
+
+    fn capacity(&self) -> usize {
+        T::capacity(self)
+    }
+}
+
+/// Initializes `section` memory with its load data read from async `flash` at `flash_offset`.
+///
+/// The data is read in chunks of [`READ_CHUNK`] bytes the same way as by
+/// [`init_section_from_nor`], awaiting each read, so other tasks run while the flash is read. The
+/// progress is not reported, see [`SectionInitFsm::poll_nor_async`] for that.
+///
+/// # Panics
+///
+/// Panics if `F::READ_SIZE` does not divide [`READ_CHUNK`].
+///
+/// # Safety
+///
+/// Same as for [`init_section_from_nor`], moreover no other context shall access the section
+/// until the returned future completes. If the future is dropped before, the section is
+/// initialized partially.
+#[cfg(feature = "async")]
+pub async unsafe fn init_section_from_nor_async<F: AsyncReadNorFlash>(
+    flash: &mut F,
+    flash_offset: u32,
+    section: Section,
+) -> Result<(), F::Error> {
+    let mut fsm = SectionInitFsm::new([section]);
+    unsafe { fsm.poll_nor_async(flash, &[flash_offset], usize::MAX) }.await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+impl<const N: usize> SectionInitFsm<N> {
+    /// Reads at most `reads` chunks of [`READ_CHUNK`] bytes from async `flash` into the
+    /// sections, continuing where the previous call stopped, possibly across section
+    /// boundaries, returns the status after the reads.
+    ///
+    /// The load data of each section is read from `flash` at its offset in `flash_offsets`, the
+    /// section LMAs are not used. The offsets shall be multiples of `F::READ_SIZE`. A read error
+    /// is returned as soon as it happens, the chunk failed is read again by the next call.
+    ///
+    /// ```
+    /// let mut assets = SectionInitFsm::new([section!(sdram_assets)]);
+    ///
+    /// loop {
+    ///     match unsafe { assets.poll_nor_async(&mut spi_nor, &[ASSETS_OFFSET], 4) }.await? {
+    ///         InitStatus::InProgress { done, total } => splash.progress(done, total),
+    ///         InitStatus::Done => break,
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F::READ_SIZE` does not divide [`READ_CHUNK`].
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`SectionInitFsm::poll`].
+    pub async unsafe fn poll_nor_async<F: AsyncReadNorFlash>(
+        &mut self,
+        flash: &mut F,
+        flash_offsets: &[u32; N],
+        reads: usize,
+    ) -> Result<InitStatus, F::Error> {
+        assert!(
+            READ_CHUNK.is_multiple_of(F::READ_SIZE),
+            "flash read size does not divide the read chunk"
+        );
+
+        let mut buffer = [0 as Word; READ_CHUNK / WORD];
+        let mut reads = reads;
+
+        while reads > 0 && self.section < N {
+            let section = self.sections[self.section];
+            let len = section.len_bytes();
+            let chunk = (len - self.offset).min(READ_CHUNK);
+
+            if chunk > 0 {
+                let read = chunk.next_multiple_of(F::READ_SIZE);
+                let offset = flash_offsets[self.section] + self.offset as u32;
+
+                // SAFETY: The buffer is `READ_CHUNK` bytes long and `read` does not exceed it
+                let bytes =
+                    unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast(), read) };
+                flash.read(offset, bytes).await?;
+
+                let dst = section.start().wrapping_byte_add(self.offset);
+                let end = dst.wrapping_byte_add(chunk);
+                unsafe { section_init_unbounded(dst, end, buffer.as_ptr()) };
+
+                reads -= 1;
+            }
+
+            self.offset += chunk;
+            self.done += chunk;
+
+            if self.offset == len {
+                self.section += 1;
+                self.offset = 0;
+            }
+        }
+
+        Ok(self.status())
+    }
+}
@@ -0,0 +1,199 @@
+//! Run-length encoded section data (`compress-rle` feature).
+//!
+//! A section marked `compressed(rle)` is initialized by decoding its load data, a blob made of
+//! a header and the encoded runs:
+//!
+//! | offset | size | content                                   |
+//! |--------|------|-------------------------------------------|
+//! | 0      | 4    | decoded length, little endian `u32`       |
+//! | 4      | 4    | encoded runs length, little endian `u32`  |
+//! | 8      |      | encoded runs                              |
+//!
+//! Each run starts by a control byte:
+//!  - `0x00..=0x7F` is followed by `control + 1` literal bytes,
+//!  - `0x80..=0xFF` is followed by a low count byte and the repeated byte, the byte is repeated
+//!    `((control & 0x7F) << 8 | low) + 3` times, i.e. 3 to 32770 times.
+//!
+//! So a zeroed struct with a few fields set takes a few bytes per field and 3 bytes per up to
+//! 32 KiB of zeros, decoded by a loop of some tens of instructions. The decoded length must equal
+//! the section length. The decoding never reads past the runs nor writes past the section end
+//! `__e<section>`, a malformed blob is reported by [`Error`] to the handler given by
+//! `compressed(rle, <handler>)`, or it panics if no handler is given.
+//!
+//! The blob is produced on the host by `encode_blob`, available with `std` feature, e.g. by a
+//! build script of the `rle-sections` example:
+//!
+//! ```
+//! let blob = linker_sections::rle::encode_blob(&table);
+//! std::fs::write(out_dir.join("table.rle"), blob).unwrap();
+//! ```
+//!
+//! The blob is placed into a flash section and the section itself into a `NOLOAD` RAM section,
+//! the same way as described for the `lz4` module.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Blob header size in bytes.
+pub const HEADER_LEN: usize = 8;
+
+/// Longest literal run.
+const MAX_LITERALS: usize = 0x80;
+
+/// Shortest repeated run, shorter ones are encoded as literals.
+const MIN_REPEAT: usize = 3;
+
+/// Longest repeated run.
+#[cfg(feature = "std")]
+const MAX_REPEAT: usize = 0x7FFF + MIN_REPEAT;
+
+/// Malformed RLE blob.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The runs or their header end in the middle of a run.
+    Truncated,
+    /// The decoded data does not fit the destination.
+    OutputOverrun,
+    /// The decoded length differs from the section length or the data decoded.
+    LengthMismatch,
+}
+
+/// Decodes `runs` into `dst`, returns the number of bytes decoded.
+///
+/// Neither the runs are read past their end nor `dst` written past its end, whatever the runs
+/// hold.
+pub fn decode(dst: &mut [u8], runs: &[u8]) -> Result<usize, Error> {
+    let mut input = 0;
+    let mut output = 0;
+
+    while input < runs.len() {
+        let control = usize::from(runs[input]);
+        input += 1;
+
+        if control < 0x80 {
+            let len = control + 1;
+            if len > runs.len() - input {
+                return Err(Error::Truncated);
+            }
+            if len > dst.len() - output {
+                return Err(Error::OutputOverrun);
+            }
+
+            dst[output..output + len].copy_from_slice(&runs[input..input + len]);
+            input += len;
+            output += len;
+        } else {
+            if runs.len() - input < 2 {
+                return Err(Error::Truncated);
+            }
+            let len = ((control & 0x7F) << 8 | usize::from(runs[input])) + MIN_REPEAT;
+            if len > dst.len() - output {
+                return Err(Error::OutputOverrun);
+            }
+
+            dst[output..output + len].fill(runs[input + 1]);
+            input += 2;
+            output += len;
+        }
+    }
+
+    Ok(output)
+}
+
+/// Decodes a blob of `encode_blob` into `dst`, its decoded length must equal the `dst` length.
+pub fn decode_blob(dst: &mut [u8], blob: &[u8]) -> Result<(), Error> {
+    let (decoded, runs) = blob_header(blob)?;
+    let runs_end = HEADER_LEN.checked_add(runs).ok_or(Error::Truncated)?;
+    let runs = blob.get(HEADER_LEN..runs_end).ok_or(Error::Truncated)?;
+
+    if decoded != dst.len() || decode(dst, runs)? != dst.len() {
+        return Err(Error::LengthMismatch);
+    }
+
+    Ok(())
+}
+
+/// Returns the decoded and the encoded runs length held by `blob` header.
+pub(crate) fn blob_header(blob: &[u8]) -> Result<(usize, usize), Error> {
+    let header = blob.get(..HEADER_LEN).ok_or(Error::Truncated)?;
+    let word = |offset: usize| {
+        u32::from_le_bytes([
+            header[offset],
+            header[offset + 1],
+            header[offset + 2],
+            header[offset + 3],
+        ]) as usize
+    };
+
+    Ok((word(0), word(4)))
+}
+
+/// Returns the greatest encoded runs length of `len` bytes, all of them literals.
+pub const fn runs_bound(len: usize) -> usize {
+    len + len.div_ceil(MAX_LITERALS)
+}
+
+/// Encodes `data` into runs.
+#[cfg(feature = "std")]
+pub fn encode(data: &[u8]) -> Vec<u8> {
+    let mut runs = Vec::with_capacity(runs_bound(data.len()));
+    let mut literal_start = 0;
+    let mut position = 0;
+
+    while position < data.len() {
+        let byte = data[position];
+        let repeat = data[position..]
+            .iter()
+            .take(MAX_REPEAT)
+            .take_while(|&&other| other == byte)
+            .count();
+
+        if repeat >= MIN_REPEAT {
+            flush_literals(&mut runs, &data[literal_start..position]);
+
+            let count = repeat - MIN_REPEAT;
+            runs.extend([0x80 | (count >> 8) as u8, count as u8, byte]);
+            position += repeat;
+            literal_start = position;
+        } else {
+            position += 1;
+        }
+    }
+    flush_literals(&mut runs, &data[literal_start..]);
+
+    runs
+}
+
+/// Appends `literals` as literal runs.
+#[cfg(feature = "std")]
+fn flush_literals(runs: &mut Vec<u8>, literals: &[u8]) {
+    for chunk in literals.chunks(MAX_LITERALS) {
+        runs.push((chunk.len() - 1) as u8);
+        runs.extend_from_slice(chunk);
+    }
+}
+
+/// Encodes `data` into a blob of header and runs.
+///
+/// # Panics
+///
+/// Panics if `data` is 4 GiB long or longer.
+#[cfg(feature = "std")]
+pub fn encode_blob(data: &[u8]) -> Vec<u8> {
+    let runs = encode(data);
+    let len = |len: usize| u32::try_from(len).expect("data too long").to_le_bytes();
+
+    let mut blob = Vec::with_capacity(HEADER_LEN + runs.len());
+    blob.extend(len(data.len()));
+    blob.extend(len(runs.len()));
+    blob.extend(runs);
+
+    blob
+}
+
+/// Default handler of `compressed(rle)` sections given no handler.
+#[doc(hidden)]
+pub fn failed(error: Error) {
+    let _ = error;
+    panic!("malformed rle section data");
+}
@@ -0,0 +1,121 @@
+//! Sparse section init tables (`sparse-init` feature).
+//!
+//! A section marked `sparse` is initialized by zeroing it and writing the nonzero words listed by
+//! its load data, a table of little endian `u32` pairs terminated by the pair of offset [`END`]:
+//!
+//! | offset | size | content                                      |
+//! |--------|------|----------------------------------------------|
+//! | 0      | 4    | word offset from the section start, in words |
+//! | 4      | 4    | word value                                   |
+//!
+//! So a 16 KiB section of some hundreds nonzero words takes 8 bytes per word in the flash, and it
+//! is initialized by a zeroing loop and a store per word, with no decoding. A table starting by
+//! the pair of offset [`RAW`] holds the whole section image instead, the pair value being its
+//! length in words followed by the words, copied as they are, so a dense section costs 8 bytes
+//! more than its image only.
+//!
+//! The words are `u32` whatever the [`Word`](crate::Word) is, so the section start shall be
+//! aligned to [`Word`](crate::Word) and its length shall be whole words, as of the zeroed
+//! sections. With `assert-bounds` feature enabled, an offset past the section end is reported as
+//! [`InitError::OutOfRange`](crate::InitError::OutOfRange) and a raw image of other length than
+//! the section as [`InitError::LengthMismatch`](crate::InitError::LengthMismatch), otherwise the
+//! words past the section are skipped.
+//!
+//! The table is produced on the host by `encode_blob`, available with `std` feature, which picks
+//! the sparse table or the raw image by the share of the nonzero words, e.g. by a build script:
+//!
+//! ```
+//! use linker_sections::sparse;
+//!
+//! let blob = sparse::encode_blob(&state, sparse::DEFAULT_THRESHOLD_PERCENT);
+//! std::fs::write(out_dir.join("state.sparse"), blob).unwrap();
+//! ```
+//!
+//! The table is placed into a flash section and the section itself into a `NOLOAD` RAM section,
+//! the same way as described for the `lz4` module.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Offset of the pair terminating the sparse table.
+pub const END: u32 = 0xFFFF_FFFF;
+
+/// Offset of the pair starting the raw image, its value being the image length in words.
+pub const RAW: u32 = 0xFFFF_FFFE;
+
+/// Share of the nonzero words in percent up to which `encode_blob` picks the sparse table.
+///
+/// A pair takes two words, so the sparse table is shorter than the raw image up to a half of
+/// the words being nonzero, the rest of the margin pays for the zeroing of the whole section.
+pub const DEFAULT_THRESHOLD_PERCENT: usize = 40;
+
+/// Returns the words of `image`.
+///
+/// # Panics
+///
+/// Panics if `image` is not whole words long.
+#[cfg(feature = "std")]
+fn words(image: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    assert!(image.len().is_multiple_of(4), "image not whole words long");
+
+    image
+        .chunks_exact(4)
+        .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+}
+
+/// Encodes `image` into a sparse table of its nonzero words.
+///
+/// # Panics
+///
+/// Panics if `image` is not whole words long or it is 16 GiB long or longer.
+#[cfg(feature = "std")]
+pub fn encode(image: &[u8]) -> Vec<u8> {
+    let mut table = Vec::new();
+
+    for (offset, word) in words(image).enumerate().filter(|&(_, word)| word != 0) {
+        let offset = u32::try_from(offset)
+            .ok()
+            .filter(|&offset| offset < RAW)
+            .expect("image too long");
+        table.extend(offset.to_le_bytes());
+        table.extend(word.to_le_bytes());
+    }
+    table.extend(END.to_le_bytes());
+    table.extend(0u32.to_le_bytes());
+
+    table
+}
+
+/// Encodes `image` into a raw image table.
+///
+/// # Panics
+///
+/// Panics if `image` is not whole words long or it is 16 GiB long or longer.
+#[cfg(feature = "std")]
+pub fn encode_raw(image: &[u8]) -> Vec<u8> {
+    let len = u32::try_from(words(image).count()).expect("image too long");
+
+    let mut table = Vec::with_capacity(8 + image.len());
+    table.extend(RAW.to_le_bytes());
+    table.extend(len.to_le_bytes());
+    table.extend_from_slice(image);
+
+    table
+}
+
+/// Encodes `image` into a sparse table if at most `threshold_percent` of its words are nonzero,
+/// into a raw image table otherwise.
+///
+/// # Panics
+///
+/// Panics if `image` is not whole words long or it is 16 GiB long or longer.
+#[cfg(feature = "std")]
+pub fn encode_blob(image: &[u8], threshold_percent: usize) -> Vec<u8> {
+    let nonzero = words(image).filter(|&word| word != 0).count();
+
+    if nonzero * 100 <= image.len() / 4 * threshold_percent {
+        encode(image)
+    } else {
+        encode_raw(image)
+    }
+}
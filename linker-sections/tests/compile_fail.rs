@@ -0,0 +1,6 @@
+//! Compile errors of the section macros, checked against the `.stderr` snapshots in `tests/ui`.
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
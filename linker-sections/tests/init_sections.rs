@@ -0,0 +1,163 @@
+//! Host tests of the section macros, the sections are defined in global assembly instead of a
+//! linker script.
+
+#![cfg(target_os = "linux")]
+
+use linker_sections::{init_sections, init_sections_with_prefixes};
+
+// the items the features require the firmware to give
+#[cfg(feature = "memory-bounds")]
+linker_sections::set_flash_bounds!(0, usize::MAX);
+#[cfg(feature = "memory-bounds")]
+linker_sections::set_ram_bounds!(0, usize::MAX);
+#[cfg(feature = "failure-handler")]
+linker_sections::set_failure_handler!(panic_on_error);
+
+#[cfg(feature = "failure-handler")]
+fn panic_on_error(error: &linker_sections::InitError) -> ! {
+    panic!("{error:?}")
+}
+
+/// Defines the section `section_name` of 32 zeroed bytes by `beg`, `end` and `src` prefixed
+/// symbols, its load data being the bytes 1 to 32, and the `alias` static at its start.
+macro_rules! test_section {
+    ($alias:ident = $section_name:ident($beg:ident, $end:ident, $src:ident)) => {
+        core::arch::global_asm!(concat!(
+            ".pushsection .data.",
+            stringify!($section_name),
+            ",\"aw\"\n",
+            ".balign 8\n",
+            ".globl ",
+            stringify!($alias),
+            "\n",
+            ".globl ",
+            stringify!($beg),
+            stringify!($section_name),
+            "\n",
+            ".globl ",
+            stringify!($end),
+            stringify!($section_name),
+            "\n",
+            stringify!($alias),
+            ":\n",
+            stringify!($beg),
+            stringify!($section_name),
+            ":\n",
+            ".zero 32\n",
+            stringify!($end),
+            stringify!($section_name),
+            ":\n",
+            ".popsection\n",
+            ".pushsection .rodata.",
+            stringify!($section_name),
+            ",\"a\"\n",
+            ".balign 8\n",
+            ".globl ",
+            stringify!($src),
+            stringify!($section_name),
+            "\n",
+            stringify!($src),
+            stringify!($section_name),
+            ":\n",
+            ".byte 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16\n",
+            ".byte 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32\n",
+            ".popsection\n",
+        ));
+
+        unsafe extern "C" {
+            static mut $alias: [u8; 32];
+        }
+    };
+}
+
+/// Returns the bytes of the section at `section` and zeroes them.
+fn take(section: *mut [u8; 32]) -> [u8; 32] {
+    unsafe { section.replace([0; 32]) }
+}
+
+/// Returns the load data of the test sections.
+fn load_data() -> [u8; 32] {
+    core::array::from_fn(|index| index as u8 + 1)
+}
+
+test_section!(SCOPE_A = scope_a(__s, __e, __si));
+test_section!(SCOPE_B = scope_b(__s, __e, __si));
+test_section!(SCOPE_HELPER = scope_helper(__s, __e, __si));
+test_section!(SCOPE_PLACED_A = scope_placed_a(__s, __e, __si));
+test_section!(SCOPE_PLACED_B = scope_placed_b(__s, __e, __si));
+
+fn init_scope_helper() {
+    init_sections!(scope_helper);
+}
+
+#[test]
+fn init_sections_twice_in_scope() {
+    init_sections!(scope_a);
+    init_sections!(scope_b);
+    init_scope_helper();
+    // each call placed in a section generates a function of its own
+    init_sections!(in ramcode; scope_placed_a);
+    init_sections!(in ramcode; scope_placed_b);
+
+    assert_eq!(take(&raw mut SCOPE_A), load_data());
+    assert_eq!(take(&raw mut SCOPE_B), load_data());
+    assert_eq!(take(&raw mut SCOPE_HELPER), load_data());
+    assert_eq!(take(&raw mut SCOPE_PLACED_A), load_data());
+    assert_eq!(take(&raw mut SCOPE_PLACED_B), load_data());
+}
+
+test_section!(LISTED_A = listed_a(_beg, _end, _rom));
+test_section!(LISTED_B = listed_b(_beg, _end, _rom));
+test_section!(LISTED_C = listed_c(__s, __e, __si));
+test_section!(GROUPED_A = grouped_a(_beg, _end, _rom));
+test_section!(GROUPED_B = grouped_b(_beg, _end, _rom));
+test_section!(GROUPED_C = grouped_c(__s, __e, __si));
+
+#[test]
+fn init_sections_with_prefixes_groups() {
+    init_sections_with_prefixes!(
+        listed_a(_beg, _end, _rom),
+        listed_b(_beg, _end, _rom),
+        listed_c(__s, __e, __si)
+    );
+    // the groups expand to the same sections with the same prefixes
+    init_sections_with_prefixes!((_beg, _end, _rom): grouped_a, grouped_b, (__s, __e, __si): grouped_c);
+
+    let listed = [&raw mut LISTED_A, &raw mut LISTED_B, &raw mut LISTED_C].map(take);
+    let grouped = [&raw mut GROUPED_A, &raw mut GROUPED_B, &raw mut GROUPED_C].map(take);
+
+    assert_eq!(grouped, listed);
+    assert_eq!(listed, [load_data(); 3]);
+}
+
+test_section!(MIXED_DEFAULT = mixed_default(__s, __e, __si));
+test_section!(MIXED_CUSTOM = mixed_custom(_beg, _end, _rom));
+test_section!(MIXED_GROUPED = mixed_grouped(_beg, _end, _rom));
+
+#[test]
+fn init_sections_mixed_prefixes() {
+    init_sections!(mixed_default, mixed_custom(_beg, _end, _rom), (_beg, _end, _rom): mixed_grouped);
+
+    assert_eq!(take(&raw mut MIXED_DEFAULT), load_data());
+    assert_eq!(take(&raw mut MIXED_CUSTOM), load_data());
+    assert_eq!(take(&raw mut MIXED_GROUPED), load_data());
+}
+
+test_section!(CFG_KEPT = cfg_kept(__s, __e, __si));
+test_section!(CFG_DROPPED = cfg_dropped(__s, __e, __si));
+
+#[test]
+fn section_cfg_attributes() {
+    // `cfg_missing` has no symbols, so the test links only if its code is dropped entirely
+    init_sections!(
+        #[cfg(all())]
+        cfg_kept,
+        #[cfg(any())]
+        cfg_dropped,
+        #[cfg(any())]
+        cfg_missing
+    );
+
+    assert_eq!(take(&raw mut CFG_KEPT), load_data());
+    assert_eq!(take(&raw mut CFG_DROPPED), [0; 32]);
+}
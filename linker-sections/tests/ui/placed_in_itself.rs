@@ -0,0 +1,5 @@
+use linker_sections::init_sections;
+
+fn main() {
+    init_sections!(in ramcode; data, ramcode);
+}